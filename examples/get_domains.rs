@@ -0,0 +1,28 @@
+mod common;
+
+use shyft_rs_sdk::{Pubkey, ShyftApi};
+
+#[tokio::main]
+async fn main() {
+    common::setup();
+
+    // Retrieve the API key from environment variables
+    let api_key = std::env::var("SHYFT_API_KEY").expect("SHYFT_API_KEY must be set");
+
+    // Sample wallet address
+    let wallet: Pubkey = "8R5brRqNa1CDMtcQRaLPQfJeLBrtyqpjDPTSKbBvmsna"
+        .parse()
+        .expect("Invalid address");
+
+    // Create ShyftApi instance
+    let shyft_api =
+        ShyftApi::new(&api_key, None, None, None, None, None).expect("Failed to create ShyftApi");
+
+    // Fetch the domains owned by the wallet
+    let domains = shyft_api.get_domains(&wallet).await;
+    println!("{:?}", domains);
+
+    // Resolve the wallet to its primary domain
+    let primary_domain = shyft_api.resolve_address(&wallet).await;
+    println!("{:?}", primary_domain);
+}
@@ -12,9 +12,11 @@ async fn main() {
     // Sample transaction signatures
     let transaction_signatures = [
         "2WjUUuMHciuwLXPgSmddyHF9qPfZ15wcja9G1hprSKfk3JLhrhsyGL2VjVfA8apEMmpskvfwBX34nJ3btihGn2rH"
-            .to_owned(),
+            .parse()
+            .expect("Invalid transaction signature"),
         "3uYZVwazovut7WrgLC7VXbhfcysX1VDo8ugDfx2SJ4YHBZMkmieh4A7b2hPZghqDJr15ZUeCpVvBUEr8FE3nzz4U"
-            .to_owned(),
+            .parse()
+            .expect("Invalid transaction signature"),
     ];
 
     // Create ShyftApi instance
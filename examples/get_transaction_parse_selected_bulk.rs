@@ -0,0 +1,33 @@
+mod common;
+
+use shyft_rs_sdk::{ShyftApi, Signature};
+
+#[tokio::main]
+async fn main() {
+    common::setup();
+
+    // Retrieve the API key from environment variables
+    let api_key = std::env::var("SHYFT_API_KEY").expect("SHYFT_API_KEY must be set");
+
+    // Sample transaction signatures
+    let transaction_signatures: Vec<Signature> = [
+        "2WjUUuMHciuwLXPgSmddyHF9qPfZ15wcja9G1hprSKfk3JLhrhsyGL2VjVfA8apEMmpskvfwBX34nJ3btihGn2rH",
+        "3uYZVwazovut7WrgLC7VXbhfcysX1VDo8ugDfx2SJ4YHBZMkmieh4A7b2hPZghqDJr15ZUeCpVvBUEr8FE3nzz4U",
+    ]
+    .iter()
+    .map(|signature| signature.parse().expect("Invalid transaction signature"))
+    .collect();
+
+    // Create ShyftApi instance
+    let shyft_api =
+        ShyftApi::new(&api_key, None, None, None, None, None).expect("Failed to create ShyftApi");
+
+    // Fetch parsed transaction details in batches, 4 batches at a time
+    let results = shyft_api
+        .get_transaction_parse_selected_bulk(transaction_signatures, 4, None, None)
+        .await;
+
+    for (signature, result) in results {
+        println!("{}: {:?}", signature, result);
+    }
+}
@@ -0,0 +1,44 @@
+mod common;
+
+use futures::StreamExt;
+use shyft_rs_sdk::{
+    pubsub::{LogsFilter, PubSubClient},
+    Commitment, ShyftApi,
+};
+
+#[tokio::main]
+async fn main() {
+    common::setup();
+
+    // Retrieve the API key from environment variables
+    let api_key = std::env::var("SHYFT_API_KEY").expect("SHYFT_API_KEY must be set");
+
+    // Sample program
+    let jupiter = "JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4"
+        .parse()
+        .expect("Invalid program");
+
+    // Create ShyftApi and PubSubClient instances
+    let shyft_api =
+        ShyftApi::new(&api_key, None, None, None, None, None).expect("Failed to create ShyftApi");
+    let pubsub = PubSubClient::connect(&api_key, None)
+        .await
+        .expect("Failed to connect to the WebSocket RPC");
+
+    // Print the parsed details of the successful transactions mentioning the program
+    let mut transactions = Box::pin(pubsub.parsed_transactions(
+        &shyft_api,
+        LogsFilter::Mentions(jupiter),
+        Some(Commitment::Confirmed),
+        |logs| logs.err.is_none(),
+    ));
+    while let Some(transaction) = transactions.next().await {
+        match transaction {
+            Ok(transaction) => println!(
+                "{} {}",
+                transaction.transaction_type, transaction.signatures[0]
+            ),
+            Err(error) => eprintln!("{}", error),
+        }
+    }
+}
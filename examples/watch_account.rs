@@ -0,0 +1,41 @@
+mod common;
+
+use std::time::Duration;
+
+use futures::StreamExt;
+use shyft_rs_sdk::{Plan, Pubkey, RateLimiter, ShyftApi};
+
+#[tokio::main]
+async fn main() {
+    common::setup();
+
+    // Retrieve the API key from environment variables
+    let api_key = std::env::var("SHYFT_API_KEY").expect("SHYFT_API_KEY must be set");
+
+    // Sample accounts
+    let accounts: Vec<Pubkey> = [
+        "JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4",
+        "8R5brRqNa1CDMtcQRaLPQfJeLBrtyqpjDPTSKbBvmsna",
+    ]
+    .iter()
+    .map(|account| account.parse().expect("Invalid account"))
+    .collect();
+
+    // Create ShyftApi instance, rate limited for the free plan
+    let shyft_api = ShyftApi::builder(&api_key)
+        .rate_limiter(RateLimiter::for_plan(Plan::Free))
+        .build()
+        .expect("Failed to create ShyftApi");
+
+    // Print the new transactions of the accounts as they are made
+    let mut transactions = shyft_api.watch_accounts(accounts, Duration::from_secs(5), None, None);
+    while let Some((account, transaction)) = transactions.next().await {
+        match transaction {
+            Ok(transaction) => println!(
+                "{}: {} {}",
+                account, transaction.transaction_type, transaction.signatures[0]
+            ),
+            Err(error) => eprintln!("{}: {}", account, error),
+        }
+    }
+}
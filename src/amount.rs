@@ -0,0 +1,54 @@
+//! This module provides helpers for converting between raw and UI amounts.
+//!
+//! Raw amounts are the integer values stored on-chain (lamports for SOL, base units for SPL
+//! tokens), while UI amounts are the human-readable values scaled down by the token's decimals.
+//!
+//! # Examples
+//!
+//! ```
+//! use shyft_rs_sdk::{amount, Decimal};
+//!
+//! let ui_amount = amount::to_ui_amount(80_249_704_804, 9).unwrap();
+//! assert_eq!(ui_amount.to_string(), "80.249704804");
+//! assert_eq!(amount::to_raw_amount(ui_amount, 9), Some(80_249_704_804));
+//! assert_eq!(amount::lamports_to_sol(5_000), Decimal::new(5_000, 9));
+//! ```
+
+use rust_decimal::Decimal;
+
+/// Number of lamports in one SOL.
+pub const LAMPORTS_PER_SOL: u64 = 1_000_000_000;
+
+/// Number of decimals used by native SOL and wrapped SOL.
+pub const SOL_DECIMALS: u8 = 9;
+
+/// Converts a raw amount into a UI amount given the token's decimals.
+///
+/// Returns `None` if `decimals` exceeds the precision supported by [`Decimal`].
+pub fn to_ui_amount(raw_amount: u64, decimals: u8) -> Option<Decimal> {
+    Decimal::try_from_i128_with_scale(raw_amount.into(), decimals.into())
+        .ok()
+        .map(|amount| amount.normalize())
+}
+
+/// Converts a UI amount into a raw amount given the token's decimals.
+///
+/// Any precision beyond `decimals` is truncated. Returns `None` if the amount is negative or
+/// does not fit into a `u64`.
+pub fn to_raw_amount(ui_amount: Decimal, decimals: u8) -> Option<u64> {
+    let scale = Decimal::try_from_i128_with_scale(10i128.checked_pow(decimals.into())?, 0).ok()?;
+    let raw_amount = ui_amount.checked_mul(scale)?.trunc();
+    u64::try_from(raw_amount).ok()
+}
+
+/// Converts lamports into SOL.
+pub fn lamports_to_sol(lamports: u64) -> Decimal {
+    Decimal::from_i128_with_scale(lamports.into(), SOL_DECIMALS.into()).normalize()
+}
+
+/// Converts SOL into lamports, truncating anything below one lamport.
+///
+/// Returns `None` if the amount is negative or does not fit into a `u64`.
+pub fn sol_to_lamports(sol: Decimal) -> Option<u64> {
+    to_raw_amount(sol, SOL_DECIMALS)
+}
@@ -0,0 +1,171 @@
+/*!
+This module computes balance changes from raw transactions.
+
+SOL changes are computed per account from the pre and post lamport balances, while token
+changes are aggregated per (owner, mint) from the pre and post token balances. Token balances
+without an owner, as recorded by old transactions, are left out. Both require the transaction to
+be fetched with `enable_raw` set.
+
+# Examples
+
+```no_run
+# #[tokio::main]
+# async fn main() -> Result<(), shyft_rs_sdk::Error> {
+# use shyft_rs_sdk::ShyftApi;
+#
+# let api_key = "your_api_key";
+# let client = ShyftApi::new(api_key, None, None, None, None, None).unwrap();
+let account = "your_account_address".parse()?;
+let history = client
+    .get_transaction_history(&account, Some(10), None, None, Some(true), None)
+    .await?;
+
+for transaction in &history {
+    if let Some(changes) = transaction.balance_changes() {
+        for change in changes.tokens.iter().filter(|change| change.owner == account) {
+            println!("{}: {:?}", change.mint, change.ui_change());
+        }
+    }
+}
+# Ok(())
+# }
+```
+*/
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    models::raw_transaction::{RawTransaction, TokenBalance},
+    Decimal, Pubkey,
+};
+
+/// Struct representing the balance changes of a transaction.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct BalanceChanges {
+    /// SOL balance changes, in the order of the transaction's account keys.
+    pub sol: Vec<SolBalanceChange>,
+    /// Token balance changes, ordered by owner and mint.
+    pub tokens: Vec<TokenBalanceChange>,
+}
+
+/// Struct representing the SOL balance change of an account.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct SolBalanceChange {
+    /// Address of the account.
+    pub account: Pubkey,
+    /// Balance before the transaction, in lamports.
+    pub pre_balance: u64,
+    /// Balance after the transaction, in lamports.
+    pub post_balance: u64,
+    /// Change of the balance, in lamports.
+    pub change: i128,
+}
+
+impl SolBalanceChange {
+    /// Returns the change of the balance in SOL.
+    pub fn ui_change(&self) -> Decimal {
+        let change = crate::amount::lamports_to_sol(self.change.unsigned_abs() as u64);
+        if self.change < 0 {
+            -change
+        } else {
+            change
+        }
+    }
+}
+
+/// Struct representing the token balance change of an owner for a given mint.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct TokenBalanceChange {
+    /// Owner of the token accounts.
+    pub owner: Pubkey,
+    /// Address of the token.
+    pub mint: Pubkey,
+    /// Decimals of the token.
+    pub decimals: u8,
+    /// Raw balance before the transaction, summed over the owner's token accounts.
+    pub pre_balance: u64,
+    /// Raw balance after the transaction, summed over the owner's token accounts.
+    pub post_balance: u64,
+    /// Raw change of the balance.
+    pub change: i128,
+}
+
+impl TokenBalanceChange {
+    /// Returns the change of the balance as a UI amount.
+    ///
+    /// Returns `None` if the change cannot be represented given the token's decimals.
+    pub fn ui_change(&self) -> Option<Decimal> {
+        let change = crate::amount::to_ui_amount(
+            u64::try_from(self.change.unsigned_abs()).ok()?,
+            self.decimals,
+        )?;
+        Some(if self.change < 0 { -change } else { change })
+    }
+}
+
+/// Computes the balance changes of a raw transaction.
+///
+/// Accounts and (owner, mint) pairs whose balance did not change are left out, as well as token
+/// balances without an owner.
+/// Returns `None` if the transaction has no status metadata.
+pub fn balance_changes(raw: &RawTransaction) -> Option<BalanceChanges> {
+    let meta = raw.meta.as_ref()?;
+    let account_keys = raw.account_keys();
+
+    let sol = account_keys
+        .iter()
+        .zip(meta.pre_balances.iter().zip(&meta.post_balances))
+        .filter(|(_, (pre, post))| pre != post)
+        .map(
+            |(account, (&pre_balance, &post_balance))| SolBalanceChange {
+                account: *account,
+                pre_balance,
+                post_balance,
+                change: i128::from(post_balance) - i128::from(pre_balance),
+            },
+        )
+        .collect();
+
+    let mut token_balances: BTreeMap<(Pubkey, Pubkey), (u8, u64, u64)> = BTreeMap::new();
+    let mut add_token_balances = |balances: &Option<Vec<TokenBalance>>, is_post: bool| {
+        for balance in balances.iter().flatten() {
+            // The account key of a balance is its token account, not its owner
+            let Some(owner) = balance.owner else {
+                continue;
+            };
+
+            let entry = token_balances.entry((owner, balance.mint)).or_insert((
+                balance.ui_token_amount.decimals,
+                0,
+                0,
+            ));
+            let amount = balance.ui_token_amount.amount;
+            if is_post {
+                entry.2 = entry.2.saturating_add(amount);
+            } else {
+                entry.1 = entry.1.saturating_add(amount);
+            }
+        }
+    };
+    add_token_balances(&meta.pre_token_balances, false);
+    add_token_balances(&meta.post_token_balances, true);
+
+    let tokens = token_balances
+        .into_iter()
+        .filter(|(_, (_, pre, post))| pre != post)
+        .map(
+            |((owner, mint), (decimals, pre_balance, post_balance))| TokenBalanceChange {
+                owner,
+                mint,
+                decimals,
+                pre_balance,
+                post_balance,
+                change: i128::from(post_balance) - i128::from(pre_balance),
+            },
+        )
+        .collect();
+
+    Some(BalanceChanges { sol, tokens })
+}
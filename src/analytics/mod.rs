@@ -0,0 +1,8 @@
+/*!
+Contains analytics computed from the models returned by the Shyft API.
+
+These helpers work entirely on data already fetched and never send requests.
+*/
+
+pub mod balance_changes;
+pub mod pnl;
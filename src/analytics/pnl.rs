@@ -0,0 +1,314 @@
+/*!
+This module computes the profit and loss (PnL) of a wallet from its swaps.
+
+Swaps are read from the [`Swap`](crate::models::parsed_transaction_details::Swap) information
+of `SWAP` actions. Buying a token with SOL opens a lot at the SOL spent, and selling a token for
+SOL closes lots according to the chosen [`CostBasisMethod`]. Transaction fees paid by the wallet
+are added to the cost of buys and deducted from the proceeds of sells. All values are in SOL.
+
+Transactions must be processed in chronological order. Note that
+[`ShyftApi::get_transaction_history`](crate::ShyftApi::get_transaction_history) returns the most
+recent transactions first.
+
+<div class="warning">
+Swaps between two tokens other than SOL cannot be priced and are skipped, as are swaps whose
+information cannot be deserialized and transfers.
+Tokens sold without a matching buy are treated as having a zero cost basis.
+</div>
+
+# Examples
+
+```no_run
+# #[tokio::main]
+# async fn main() -> Result<(), shyft_rs_sdk::Error> {
+# use std::collections::HashMap;
+# use shyft_rs_sdk::ShyftApi;
+use shyft_rs_sdk::analytics::pnl::{CostBasisMethod, PnlTracker};
+#
+# let api_key = "your_api_key";
+# let client = ShyftApi::new(api_key, None, None, None, None, None).unwrap();
+
+let wallet = "your_wallet_address".parse()?;
+let history = client
+    .get_transaction_history(&wallet, Some(100), None, None, None, None)
+    .await?;
+
+let mut tracker = PnlTracker::new(wallet, CostBasisMethod::Fifo);
+tracker.process_all(history.iter().rev())?;
+
+let prices = HashMap::new(); // Current token prices in SOL
+let report = tracker.report(&prices);
+println!("Realized PnL: {} SOL", report.realized_pnl);
+# Ok(())
+# }
+```
+*/
+
+use std::collections::{BTreeMap, HashMap, VecDeque};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    constants,
+    models::parsed_transaction_details::{Info, ParsedTransactionDetails, TxType},
+    Decimal, Pubkey, Signature,
+};
+
+/// Enum representing the method used to match sells against previous buys.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub enum CostBasisMethod {
+    /// First in, first out: sells consume the oldest lots first.
+    Fifo,
+    /// Last in, first out: sells consume the most recent lots first.
+    Lifo,
+    /// Average cost: all lots of a token share the same average cost.
+    Average,
+}
+
+/// Struct representing a quantity of a token bought at a given cost.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Lot {
+    /// Quantity of tokens remaining in the lot.
+    pub quantity: Decimal,
+    /// Cost of the remaining tokens, in SOL.
+    pub cost: Decimal,
+}
+
+/// Struct representing the position of the wallet in a token.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct Position {
+    /// Open lots, oldest first.
+    pub lots: VecDeque<Lot>,
+    /// PnL realized by selling the token, in SOL.
+    pub realized_pnl: Decimal,
+}
+
+impl Position {
+    /// Returns the quantity of tokens held.
+    pub fn quantity(&self) -> Decimal {
+        self.lots.iter().map(|lot| lot.quantity).sum()
+    }
+
+    /// Returns the cost basis of the tokens held, in SOL.
+    pub fn cost_basis(&self) -> Decimal {
+        self.lots.iter().map(|lot| lot.cost).sum()
+    }
+
+    /// Opens a lot.
+    fn buy(&mut self, quantity: Decimal, cost: Decimal, method: CostBasisMethod) {
+        match (method, self.lots.front_mut()) {
+            (CostBasisMethod::Average, Some(lot)) => {
+                lot.quantity += quantity;
+                lot.cost += cost;
+            }
+            _ => self.lots.push_back(Lot { quantity, cost }),
+        }
+    }
+
+    /// Closes lots and realizes the PnL of the sale.
+    fn sell(&mut self, mut quantity: Decimal, proceeds: Decimal, method: CostBasisMethod) {
+        let mut cost = Decimal::ZERO;
+
+        while quantity > Decimal::ZERO {
+            let lot = match method {
+                CostBasisMethod::Lifo => self.lots.back_mut(),
+                CostBasisMethod::Fifo | CostBasisMethod::Average => self.lots.front_mut(),
+            };
+            let Some(lot) = lot else {
+                break;
+            };
+
+            if lot.quantity <= quantity {
+                quantity -= lot.quantity;
+                cost += lot.cost;
+                match method {
+                    CostBasisMethod::Lifo => self.lots.pop_back(),
+                    CostBasisMethod::Fifo | CostBasisMethod::Average => self.lots.pop_front(),
+                };
+            } else {
+                let consumed_cost = lot.cost * quantity / lot.quantity;
+                lot.quantity -= quantity;
+                lot.cost -= consumed_cost;
+                cost += consumed_cost;
+                quantity = Decimal::ZERO;
+            }
+        }
+
+        self.realized_pnl += proceeds - cost;
+    }
+}
+
+/// Struct tracking the positions and PnL of a wallet.
+#[derive(Debug, Clone)]
+pub struct PnlTracker {
+    /// Address of the tracked wallet.
+    wallet: Pubkey,
+    /// Method used to match sells against previous buys.
+    method: CostBasisMethod,
+    /// Address of the wrapped SOL mint.
+    sol_mint: Pubkey,
+    /// Positions of the wallet, by token.
+    positions: BTreeMap<Pubkey, Position>,
+    /// Fees paid in transactions without a priced swap, in SOL.
+    unallocated_fees: Decimal,
+    /// Signatures of the transactions containing swaps that could not be priced or parsed.
+    skipped: Vec<Signature>,
+}
+
+impl PnlTracker {
+    /// Creates a new tracker for the given wallet.
+    pub fn new(wallet: Pubkey, method: CostBasisMethod) -> Self {
+        Self {
+            wallet,
+            method,
+            sol_mint: constants::WRAPPED_SOL_MINT
+                .parse()
+                .expect("WRAPPED_SOL_MINT is a valid address"),
+            positions: BTreeMap::new(),
+            unallocated_fees: Decimal::ZERO,
+            skipped: Vec::new(),
+        }
+    }
+
+    /// Processes a transaction, updating the positions with the wallet's swaps.
+    ///
+    /// Swaps whose information cannot be deserialized are recorded in
+    /// [`skipped`](Self::skipped) rather than failing the whole report.
+    ///
+    /// # Errors
+    ///
+    /// This function does not currently return an error, malformed swaps being skipped.
+    pub fn process(
+        &mut self,
+        transaction: &ParsedTransactionDetails,
+    ) -> Result<(), crate::error::Error> {
+        let mut fee = if transaction.fee_payer == self.wallet {
+            transaction.fee
+        } else {
+            Decimal::ZERO
+        };
+
+        if transaction.is_success() {
+            for action in &transaction.actions {
+                // Other actions are not parsed, so their information can't fail the report
+                if action.action_type != TxType::Swap {
+                    continue;
+                }
+                let Ok(Some(Info::PumpfunSwap(swap))) = action.typed_info() else {
+                    self.skipped.extend(transaction.signatures.first());
+                    continue;
+                };
+                if swap.swapper != self.wallet {
+                    continue;
+                }
+
+                let token_in = &swap.tokens_swapped.token_in;
+                let token_out = &swap.tokens_swapped.token_out;
+                let position_method = self.method;
+
+                if token_in.token_address == self.sol_mint {
+                    self.positions
+                        .entry(token_out.token_address)
+                        .or_default()
+                        .buy(token_out.amount, token_in.amount + fee, position_method);
+                } else if token_out.token_address == self.sol_mint {
+                    self.positions
+                        .entry(token_in.token_address)
+                        .or_default()
+                        .sell(token_in.amount, token_out.amount - fee, position_method);
+                } else {
+                    self.skipped.extend(transaction.signatures.first());
+                    continue;
+                }
+                fee = Decimal::ZERO;
+            }
+        }
+
+        self.unallocated_fees += fee;
+        Ok(())
+    }
+
+    /// Processes transactions in order, updating the positions with the wallet's swaps.
+    ///
+    /// # Errors
+    ///
+    /// This function does not currently return an error, malformed swaps being skipped.
+    pub fn process_all<'a>(
+        &mut self,
+        transactions: impl IntoIterator<Item = &'a ParsedTransactionDetails>,
+    ) -> Result<(), crate::error::Error> {
+        transactions
+            .into_iter()
+            .try_for_each(|transaction| self.process(transaction))
+    }
+
+    /// Returns the position of the wallet in a token.
+    pub fn position(&self, mint: &Pubkey) -> Option<&Position> {
+        self.positions.get(mint)
+    }
+
+    /// Returns the signatures of the transactions containing swaps that could not be priced or
+    /// parsed.
+    pub fn skipped(&self) -> &[Signature] {
+        &self.skipped
+    }
+
+    /// Builds a PnL report, valuing open positions at the given prices.
+    ///
+    /// # Arguments
+    ///
+    /// * `prices` - Current price of each token in SOL. Tokens without a price have no
+    ///   unrealized PnL.
+    pub fn report(&self, prices: &HashMap<Pubkey, Decimal>) -> PnlReport {
+        let tokens: Vec<TokenPnl> = self
+            .positions
+            .iter()
+            .map(|(mint, position)| {
+                let quantity = position.quantity();
+                let cost_basis = position.cost_basis();
+                TokenPnl {
+                    mint: *mint,
+                    quantity,
+                    cost_basis,
+                    realized_pnl: position.realized_pnl,
+                    unrealized_pnl: prices.get(mint).map(|price| price * quantity - cost_basis),
+                }
+            })
+            .collect();
+
+        PnlReport {
+            realized_pnl: tokens.iter().map(|token| token.realized_pnl).sum(),
+            unrealized_pnl: tokens.iter().filter_map(|token| token.unrealized_pnl).sum(),
+            unallocated_fees: self.unallocated_fees,
+            tokens,
+        }
+    }
+}
+
+/// Struct representing the PnL of a wallet.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct PnlReport {
+    /// PnL of each token, ordered by mint.
+    pub tokens: Vec<TokenPnl>,
+    /// Total realized PnL, in SOL.
+    pub realized_pnl: Decimal,
+    /// Total unrealized PnL of the priced tokens, in SOL.
+    pub unrealized_pnl: Decimal,
+    /// Fees paid in transactions without a priced swap, in SOL.
+    pub unallocated_fees: Decimal,
+}
+
+/// Struct representing the PnL of a wallet in a token.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct TokenPnl {
+    /// Address of the token.
+    pub mint: Pubkey,
+    /// Quantity of tokens held.
+    pub quantity: Decimal,
+    /// Cost basis of the tokens held, in SOL.
+    pub cost_basis: Decimal,
+    /// Realized PnL, in SOL.
+    pub realized_pnl: Decimal,
+    /// Unrealized PnL, in SOL, if a price was provided.
+    pub unrealized_pnl: Option<Decimal>,
+}
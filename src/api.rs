@@ -15,14 +15,26 @@
 //! You can customize the retry strategy by providing optional parameters for minimum retry interval,
 //! maximum retry interval, and maximum number of retries.
 
-use std::{collections::HashMap, time::Duration};
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+    time::Duration,
+};
 
-use reqwest::header;
+use futures::StreamExt;
+use serde::de::DeserializeOwned;
 
 use crate::{
+    cache::CachePolicy,
     constants,
-    models::{self, parsed_transaction_details::ParsedTransactionDetails},
+    key_pool::ApiKeyInQuery,
+    models::{self, domain::Domain, parsed_transaction_details::ParsedTransactionDetails},
+    requests::{
+        DomainsRequest, ParseSelectedRequest, ParsedTransactionRequest, ResolveAddressRequest,
+        ShyftRequest, TransactionHistoryRequest,
+    },
     reqwest_ext::get_retry_strategy,
+    ApiKeyPool, Cache, Pubkey, RateLimiter, Signature,
 };
 
 #[derive(Debug, Clone)]
@@ -38,12 +50,111 @@ pub struct ShyftApi {
     client: reqwest_middleware::ClientWithMiddleware,
     /// A map of default parameters to be included in each API request.
     default_params: HashMap<String, String>,
+    /// An optional cache for API responses.
+    cache: Option<Arc<dyn Cache>>,
+    /// Time to live of cached mutable responses, which are not cached if `None`.
+    cache_ttl: Option<Duration>,
+    /// The HTTP client without middleware, shared with clients created by [`ShyftApi::with_options`].
+    http_client: reqwest::Client,
+    /// Minimum retry interval in milliseconds.
+    min_retry_interval: u64,
+    /// Maximum retry interval in milliseconds.
+    max_retry_interval: u64,
+    /// Maximum number of retries.
+    max_retries: u32,
+    /// Client-side rate limiter.
+    rate_limiter: Option<RateLimiter>,
+    /// The API keys, shared with clients created by [`ShyftApi::with_options`].
+    api_keys: ApiKeyPool,
+    /// Timeout of each request, overriding [`constants::TIMEOUT`] if set.
+    timeout: Option<Duration>,
+    /// Maximum number of history pages fetched by each poll of a watcher, overriding
+    /// [`constants::MAX_WATCH_PAGES`] if set.
+    pub(crate) max_watch_pages: Option<u32>,
+    /// Base URL of the Shyft API, ending with a slash.
+    base_url: String,
+    /// URL of the JSON-RPC API, overriding the Shyft RPC of the network if set.
+    rpc_url: Option<String>,
+}
+
+/// Struct representing per-call overrides of the client configuration, used with
+/// [`ShyftApi::with_options`].
+///
+/// Every option not set keeps the value of the client it is applied to.
+#[derive(Debug, Clone, Default)]
+pub struct RequestOptions {
+    /// Network to interact with.
+    network: Option<Network>,
+    /// Commitment level for transactions.
+    commitment: Option<Commitment>,
+    /// Timeout of each request.
+    timeout: Option<Duration>,
+    /// Minimum retry interval in milliseconds.
+    min_retry_interval: Option<u64>,
+    /// Maximum retry interval in milliseconds.
+    max_retry_interval: Option<u64>,
+    /// Maximum number of retries.
+    max_retries: Option<u32>,
+    /// Maximum number of history pages fetched by each poll of a watcher.
+    max_watch_pages: Option<u32>,
+}
+
+impl RequestOptions {
+    /// Creates new options overriding nothing.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Overrides the network to interact with.
+    pub fn network(mut self, network: Network) -> Self {
+        self.network = Some(network);
+        self
+    }
+
+    /// Overrides the commitment level for transactions.
+    pub fn commitment(mut self, commitment: Commitment) -> Self {
+        self.commitment = Some(commitment);
+        self
+    }
+
+    /// Overrides the timeout of each request, retries excluded.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Overrides the minimum retry interval in milliseconds.
+    pub fn min_retry_interval(mut self, min_retry_interval: u64) -> Self {
+        self.min_retry_interval = Some(min_retry_interval);
+        self
+    }
+
+    /// Overrides the maximum retry interval in milliseconds.
+    pub fn max_retry_interval(mut self, max_retry_interval: u64) -> Self {
+        self.max_retry_interval = Some(max_retry_interval);
+        self
+    }
+
+    /// Overrides the maximum number of retries.
+    pub fn max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = Some(max_retries);
+        self
+    }
+
+    /// Overrides the maximum number of history pages fetched by each poll of the watchers,
+    /// [`constants::MAX_WATCH_PAGES`] by default.
+    ///
+    /// See [`ShyftApi::watch_account`] for what happens when a poll reaches it.
+    pub fn max_watch_pages(mut self, max_watch_pages: u32) -> Self {
+        self.max_watch_pages = Some(max_watch_pages);
+        self
+    }
 }
 
 /// Enum representing different Shyft API networks.
 ///
 /// This enum is used to specify the network to interact with when making API requests.
-#[derive(strum_macros::Display, Debug, Clone)]
+#[derive(strum_macros::Display, strum_macros::EnumString, Debug, Clone)]
 #[strum(serialize_all = "kebab-case")]
 pub enum Network {
     /// Mainnet Beta network
@@ -54,7 +165,7 @@ pub enum Network {
     Testnet,
 }
 
-#[derive(strum_macros::Display, Debug, Clone)]
+#[derive(strum_macros::Display, strum_macros::EnumString, Debug, Clone)]
 #[strum(serialize_all = "snake_case")]
 /// Enum representing the commitment level for transactions.
 ///
@@ -115,41 +226,124 @@ impl ShyftApi {
         network: Option<Network>,
         commitment: Option<Commitment>,
     ) -> Result<Self, crate::error::Error> {
-        let mut headers = header::HeaderMap::new();
-
-        let mut auth_value = header::HeaderValue::from_str(api_key).unwrap();
-        auth_value.set_sensitive(true);
-        headers.insert("x-api-key", auth_value);
+        let mut builder = Self::builder(api_key);
+        builder.min_retry_interval = min_retry_interval;
+        builder.max_retry_interval = max_retry_interval;
+        builder.max_retries = max_retries;
+        builder.network = network;
+        builder.commitment = commitment;
+        builder.build()
+    }
 
-        let client = reqwest::Client::builder()
-            .default_headers(headers)
-            .timeout(Duration::from_secs(10))
-            .build()?;
+    /// Creates a builder to configure a new instance of the Shyft API client.
+    ///
+    /// # Arguments
+    ///
+    /// * `api_key` - A string slice that holds the API key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use shyft_rs_sdk::{Network, RateLimiter, ShyftApi};
+    ///
+    /// let client = ShyftApi::builder("your_api_key")
+    ///     .network(Network::Devnet)
+    ///     .max_retries(5)
+    ///     .rate_limiter(RateLimiter::new(10))
+    ///     .build()?;
+    /// # Ok::<(), shyft_rs_sdk::Error>(())
+    /// ```
+    pub fn builder(api_key: &str) -> ShyftApiBuilder {
+        ShyftApiBuilder::new(ApiKeyPool::new([api_key]).ok())
+    }
 
-        let mut default_params = HashMap::new();
-        default_params.insert(
-            "network".to_string(),
-            network.unwrap_or(Network::MainnetBeta).to_string(),
-        );
-        default_params.insert(
-            "commitment".to_string(),
-            commitment.unwrap_or(Commitment::Confirmed).to_string(),
-        );
+    /// Creates a builder to configure a new instance of the Shyft API client, rotating among a
+    /// pool of API keys.
+    ///
+    /// # Arguments
+    ///
+    /// * `api_keys` - The pool of API keys, shared with the client.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use shyft_rs_sdk::{ApiKeyPool, ShyftApi};
+    ///
+    /// let keys = ApiKeyPool::new(["first_api_key", "second_api_key"])?;
+    /// let client = ShyftApi::builder_with_api_key_pool(keys).build()?;
+    /// # Ok::<(), shyft_rs_sdk::Error>(())
+    /// ```
+    pub fn builder_with_api_key_pool(api_keys: ApiKeyPool) -> ShyftApiBuilder {
+        ShyftApiBuilder::new(Some(api_keys))
+    }
 
-        let retry_s = get_retry_strategy(
-            min_retry_interval.unwrap_or(constants::MIN_RETRY_INTERVAL),
-            max_retry_interval.unwrap_or(constants::MAX_RETRY_INTERVAL),
-            max_retries.unwrap_or(constants::MAX_RETRIES),
-        );
+    /// Returns a client with some of its configuration overridden, to be used for one or more
+    /// calls.
+    ///
+    /// The returned client shares the connection pool, rate limiter and cache of this client, so
+    /// it is cheap to create for a single call.
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - The options to override.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), shyft_rs_sdk::Error> {
+    /// use std::time::Duration;
+    ///
+    /// use shyft_rs_sdk::{Commitment, Network, RequestOptions, ShyftApi};
+    ///
+    /// let client = ShyftApi::new("your_api_key", None, None, None, None, None)?;
+    /// let signature = "your_transaction_signature".parse()?;
+    /// let transaction = client
+    ///     .with_options(
+    ///         RequestOptions::new()
+    ///             .network(Network::Devnet)
+    ///             .commitment(Commitment::Finalized)
+    ///             .timeout(Duration::from_secs(30))
+    ///             .max_retries(0),
+    ///     )
+    ///     .get_transaction_parsed(&signature)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_options(&self, options: RequestOptions) -> ShyftApi {
+        let mut client = self.clone();
 
-        let client = reqwest_middleware::ClientBuilder::new(client)
-            .with(retry_s)
-            .build();
+        if let Some(network) = options.network {
+            client
+                .default_params
+                .insert("network".to_string(), network.to_string());
+        }
+        if let Some(commitment) = options.commitment {
+            client
+                .default_params
+                .insert("commitment".to_string(), commitment.to_string());
+        }
+        client.timeout = options.timeout.or(self.timeout);
+        client.max_watch_pages = options.max_watch_pages.or(self.max_watch_pages);
+        client.min_retry_interval = options
+            .min_retry_interval
+            .unwrap_or(self.min_retry_interval);
+        client.max_retry_interval = options
+            .max_retry_interval
+            .unwrap_or(self.max_retry_interval);
+        client.max_retries = options.max_retries.unwrap_or(self.max_retries);
 
-        Ok(Self {
-            client,
-            default_params,
-        })
+        client.client = middleware_client(
+            client.http_client.clone(),
+            client.min_retry_interval,
+            client.max_retry_interval,
+            client.max_retries,
+            client.rate_limiter.clone(),
+            client.api_keys.clone(),
+            &client.default_params,
+        );
+        client
     }
 
     /// Fetches the transaction history for a given account. Equivalent to [GET /transaction/history]
@@ -167,7 +361,8 @@ impl ShyftApi {
     ///
     /// # Errors
     ///
-    /// This function will return an error if the request fails or the response status is not 200.
+    /// This function will return an error if the request is invalid, the request fails or the
+    /// response status is not 200.
     ///
     /// # Example
     ///
@@ -178,58 +373,38 @@ impl ShyftApi {
     /// #
     /// # let api_key = "your_api_key";
     /// # let client = ShyftApi::new(api_key, None, None, None, None, None).unwrap();
-    /// let history = client.get_transaction_history("account_address", Some(10), None, None, Some(true), None).await?;
+    /// let account = "8R5brRqNa1CDMtcQRaLPQfJeLBrtyqpjDPTSKbBvmsna".parse()?;
+    /// let history = client.get_transaction_history(&account, Some(10), None, None, Some(true), None).await?;
     /// # Ok(())
     /// # }
     /// ```
     pub async fn get_transaction_history(
         &self,
-        account: &str,
+        account: &Pubkey,
         tx_num: Option<u32>,
-        before_tx_signature: Option<&str>,
-        until_tx_signature: Option<&str>,
+        before_tx_signature: Option<&Signature>,
+        until_tx_signature: Option<&Signature>,
         enable_raw: Option<bool>,
         enable_events: Option<bool>,
     ) -> Result<Vec<ParsedTransactionDetails>, crate::error::Error> {
-        let url = format!("{}transaction/history", constants::URL);
-
-        let mut request = self
-            .client
-            .get(url)
-            .query(&self.default_params)
-            .query(&[("account", account)]);
-
+        let mut request = TransactionHistoryRequest::new(*account);
         if let Some(tx_num) = tx_num {
-            request = request.query(&[("tx_num", tx_num.to_string())]);
+            request = request.tx_num(tx_num);
         }
-
         if let Some(before_tx_signature) = before_tx_signature {
-            request = request.query(&[("before_tx_signature", before_tx_signature)]);
+            request = request.before_tx_signature(*before_tx_signature);
         }
-
         if let Some(until_tx_signature) = until_tx_signature {
-            request = request.query(&[("until_tx_signature", until_tx_signature)]);
+            request = request.until_tx_signature(*until_tx_signature);
         }
-
         if let Some(enable_raw) = enable_raw {
-            request = request.query(&[("enable_raw", enable_raw.to_string())]);
+            request = request.enable_raw(enable_raw);
         }
-
         if let Some(enable_events) = enable_events {
-            request = request.query(&[("enable_events", enable_events.to_string())]);
-        }
-
-        let response = request.send().await?;
-
-        if !response.status().is_success() {
-            return Err(crate::error::Error::StatusNot200(response.text().await?));
+            request = request.enable_events(enable_events);
         }
 
-        let parsed_response = response
-            .json::<models::Response<Vec<ParsedTransactionDetails>>>()
-            .await?
-            .result;
-        Ok(parsed_response)
+        self.execute(&request).await
     }
 
     /// Retrieves parsed transaction details for a given transaction signature. Equivalent to [GET /transaction/parsed]
@@ -238,11 +413,12 @@ impl ShyftApi {
     ///
     /// # Arguments
     ///
-    /// * `tx_signature` - A string slice that holds the transaction signature.
+    /// * `tx_signature` - The transaction signature.
     ///
     /// # Errors
     ///
-    /// This function will return an error if the request fails or the response status is not 200.
+    /// This function will return an error if the request is invalid, the request fails or the
+    /// response status is not 200.
     ///
     /// # Example
     ///
@@ -253,32 +429,17 @@ impl ShyftApi {
     /// #
     /// # let api_key = "your_api_key";
     /// # let client = ShyftApi::new(api_key, None, None, None, None, None).unwrap();
-    /// let transaction_details = client.get_transaction_parsed("transaction_signature").await?;
+    /// let tx_signature = "uZz2qDvLzndsTEY31YkxgRe1rYQ8MsCtS3DwhPYP1Md7u7dnUK4HW3vYzsxE6GSxFhSG5zpvqSQnSUn1sPmzTBu".parse()?;
+    /// let transaction_details = client.get_transaction_parsed(&tx_signature).await?;
     /// # Ok(())
     /// # }
     /// ```
     pub async fn get_transaction_parsed(
         &self,
-        tx_signature: &str,
+        tx_signature: &Signature,
     ) -> Result<ParsedTransactionDetails, crate::error::Error> {
-        let url = format!("{}transaction/parsed", constants::URL);
-        let response = self
-            .client
-            .get(url)
-            .query(&self.default_params)
-            .query(&[("txn_signature", tx_signature)])
-            .send()
-            .await?;
-
-        if !response.status().is_success() {
-            return Err(crate::error::Error::StatusNot200(response.text().await?));
-        }
-
-        let parsed_response = response
-            .json::<models::Response<ParsedTransactionDetails>>()
-            .await?
-            .result;
-        Ok(parsed_response)
+        self.execute(&ParsedTransactionRequest::new(*tx_signature))
+            .await
     }
 
     /// Retrieves parsed transaction details for given transaction signatures. Equivalent to [POST /transaction/parse_selected]
@@ -287,13 +448,14 @@ impl ShyftApi {
     ///
     /// # Arguments
     ///
-    /// * `transaction_signatures` - A slice of strings that holds the transaction signatures.
+    /// * `transaction_signatures` - A slice that holds the transaction signatures.
     /// * `enable_raw` - An optional boolean to include raw transaction details.
     /// * `enable_events` - An optional boolean to include events in the transaction details.
     ///
     /// # Errors
     ///
-    /// This function will return an error if the request fails or the response status is not 200.
+    /// This function will return an error if the request is invalid, the request fails or the
+    /// response status is not 200.
     ///
     /// # Example
     ///
@@ -304,43 +466,630 @@ impl ShyftApi {
     /// #
     /// # let api_key = "your_api_key";
     /// # let client = ShyftApi::new(api_key, None, None, None, None, None).unwrap();
-    /// let transaction_signatures = vec!["transaction_signature1".to_string(), "transaction_signature2".to_string()];
+    /// let transaction_signatures = vec![
+    ///     "2WjUUuMHciuwLXPgSmddyHF9qPfZ15wcja9G1hprSKfk3JLhrhsyGL2VjVfA8apEMmpskvfwBX34nJ3btihGn2rH".parse()?,
+    ///     "3uYZVwazovut7WrgLC7VXbhfcysX1VDo8ugDfx2SJ4YHBZMkmieh4A7b2hPZghqDJr15ZUeCpVvBUEr8FE3nzz4U".parse()?,
+    /// ];
     /// let transaction_details = client.get_transaction_parse_selected(&transaction_signatures, Some(true), Some(true)).await?;
     /// # Ok(())
     /// # }
     /// ```
     pub async fn get_transaction_parse_selected(
         &self,
-        transaction_signatures: &[String],
+        transaction_signatures: &[Signature],
         enable_raw: Option<bool>,
         enable_events: Option<bool>,
     ) -> Result<Vec<ParsedTransactionDetails>, crate::error::Error> {
-        let url = format!("{}transaction/parse_selected", constants::URL);
-        let request = self
-            .client
-            .post(url)
-            .header(header::CONTENT_TYPE, "application/json")
-            .body(
-                serde_json::to_string(&serde_json::json!({
-                    "network": self.default_params.get("network").unwrap(),
-                    "transaction_signatures": transaction_signatures,
-                    "enable_raw": enable_raw.unwrap_or(false),
-                    "enable_events": enable_events.unwrap_or(false),
-                    "commitment": self.default_params.get("commitment").unwrap(),
-                }))
-                .unwrap(),
-            );
-
-        let response = request.send().await?;
+        let request = ParseSelectedRequest::new(transaction_signatures.iter().copied())
+            .enable_raw(enable_raw.unwrap_or(false))
+            .enable_events(enable_events.unwrap_or(false));
+
+        self.execute(&request).await
+    }
+
+    /// Retrieves parsed transaction details for any number of transaction signatures.
+    ///
+    /// The signatures are split into batches of up to 100, the maximum accepted by
+    /// [`ShyftApi::get_transaction_parse_selected`], and up to `concurrency` batches are
+    /// requested at once. Results are returned in the order of the input signatures, each
+    /// with its own result, so a failure only affects the signatures it concerns:
+    ///
+    /// * [`Error::Batch`](crate::Error::Batch) if the request for the signature's batch failed.
+    /// * [`Error::TransactionNotFound`](crate::Error::TransactionNotFound) if the batch
+    ///   succeeded but did not include the transaction.
+    ///
+    /// # Arguments
+    ///
+    /// * `transaction_signatures` - The transaction signatures.
+    /// * `concurrency` - Maximum number of batches requested at once, at least 1.
+    /// * `enable_raw` - An optional boolean to include raw transaction details.
+    /// * `enable_events` - An optional boolean to include events in the transaction details.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), shyft_rs_sdk::Error> {
+    /// # use shyft_rs_sdk::{ShyftApi, Signature};
+    /// #
+    /// # let api_key = "your_api_key";
+    /// # let client = ShyftApi::new(api_key, None, None, None, None, None).unwrap();
+    /// # let transaction_signatures: Vec<Signature> = Vec::new();
+    /// let results = client
+    ///     .get_transaction_parse_selected_bulk(transaction_signatures, 4, None, None)
+    ///     .await;
+    /// for (signature, result) in results {
+    ///     match result {
+    ///         Ok(transaction) => println!("{}: {}", signature, transaction.transaction_type),
+    ///         Err(error) => eprintln!("{}: {}", signature, error),
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_transaction_parse_selected_bulk(
+        &self,
+        transaction_signatures: impl IntoIterator<Item = Signature>,
+        concurrency: usize,
+        enable_raw: Option<bool>,
+        enable_events: Option<bool>,
+    ) -> Vec<(
+        Signature,
+        Result<ParsedTransactionDetails, crate::error::Error>,
+    )> {
+        let transaction_signatures: Vec<Signature> = transaction_signatures.into_iter().collect();
+
+        let batches = futures::stream::iter(
+            transaction_signatures.chunks(constants::MAX_PARSE_SELECTED_SIGNATURES),
+        )
+        .map(|batch| async move {
+            let result = self
+                .get_transaction_parse_selected(batch, enable_raw, enable_events)
+                .await;
+            (batch, result)
+        })
+        .buffered(concurrency.max(1))
+        .collect::<Vec<_>>()
+        .await;
+
+        let mut results = Vec::with_capacity(transaction_signatures.len());
+        for (batch, result) in batches {
+            match result {
+                Ok(transactions) => {
+                    let transactions: HashMap<Signature, ParsedTransactionDetails> = transactions
+                        .into_iter()
+                        .filter_map(|transaction| {
+                            Some((*transaction.signatures.first()?, transaction))
+                        })
+                        .collect();
+                    results.extend(batch.iter().map(|signature| {
+                        let result = transactions
+                            .get(signature)
+                            .cloned()
+                            .ok_or(crate::error::Error::TransactionNotFound(*signature));
+                        (*signature, result)
+                    }));
+                }
+                Err(error) => {
+                    let error = Arc::new(error);
+                    results.extend(batch.iter().map(|signature| {
+                        let error = crate::error::Error::Batch(error.clone());
+                        (*signature, Err(error))
+                    }));
+                }
+            }
+        }
+        results
+    }
+
+    /// Fetches all the `.sol` domains owned by a wallet. Equivalent to [GET /wallet/get_domains]
+    ///
+    /// [GET /wallet/get_domains]: https://docs.shyft.to/solana-apis/wallet/wallet-apis#get-domains
+    ///
+    /// # Arguments
+    ///
+    /// * `wallet` - The wallet address.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the request is invalid, the request fails or the
+    /// response status is not 200.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), shyft_rs_sdk::Error> {
+    /// # use shyft_rs_sdk::ShyftApi;
+    /// #
+    /// # let api_key = "your_api_key";
+    /// # let client = ShyftApi::new(api_key, None, None, None, None, None).unwrap();
+    /// let wallet = "8R5brRqNa1CDMtcQRaLPQfJeLBrtyqpjDPTSKbBvmsna".parse()?;
+    /// let domains = client.get_domains(&wallet).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_domains(&self, wallet: &Pubkey) -> Result<Vec<Domain>, crate::error::Error> {
+        self.execute(&DomainsRequest::new(*wallet)).await
+    }
+
+    /// Resolves an address to its primary `.sol` domain name. Equivalent to [GET /wallet/resolve_address]
+    ///
+    /// [GET /wallet/resolve_address]: https://docs.shyft.to/solana-apis/wallet/wallet-apis#resolve-address
+    ///
+    /// Returns `None` if the address has no primary domain.
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - The address to resolve.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the request is invalid, the request fails or the
+    /// response status is not 200.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), shyft_rs_sdk::Error> {
+    /// # use shyft_rs_sdk::ShyftApi;
+    /// #
+    /// # let api_key = "your_api_key";
+    /// # let client = ShyftApi::new(api_key, None, None, None, None, None).unwrap();
+    /// let address = "8R5brRqNa1CDMtcQRaLPQfJeLBrtyqpjDPTSKbBvmsna".parse()?;
+    /// let domain = client.resolve_address(&address).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn resolve_address(
+        &self,
+        address: &Pubkey,
+    ) -> Result<Option<String>, crate::error::Error> {
+        self.execute(&ResolveAddressRequest::new(*address)).await
+    }
+
+    /// Resolves the primary `.sol` domains of the fee payer and signers of a transaction.
+    ///
+    /// Each distinct address is resolved with [`ShyftApi::resolve_address`]. Addresses
+    /// without a primary domain are left out of the returned map.
+    ///
+    /// # Arguments
+    ///
+    /// * `transaction` - The parsed transaction whose fee payer and signers should be resolved.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if any of the underlying requests fail.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), shyft_rs_sdk::Error> {
+    /// # use shyft_rs_sdk::ShyftApi;
+    /// #
+    /// # let api_key = "your_api_key";
+    /// # let client = ShyftApi::new(api_key, None, None, None, None, None).unwrap();
+    /// let tx_signature = "uZz2qDvLzndsTEY31YkxgRe1rYQ8MsCtS3DwhPYP1Md7u7dnUK4HW3vYzsxE6GSxFhSG5zpvqSQnSUn1sPmzTBu".parse()?;
+    /// let transaction = client.get_transaction_parsed(&tx_signature).await?;
+    /// let domains = client.resolve_transaction_domains(&transaction).await?;
+    /// if let Some(name) = domains.get(&transaction.fee_payer) {
+    ///     println!("Paid by {}", name);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn resolve_transaction_domains(
+        &self,
+        transaction: &ParsedTransactionDetails,
+    ) -> Result<HashMap<Pubkey, String>, crate::error::Error> {
+        let mut seen = HashSet::new();
+        let mut domains = HashMap::new();
+
+        for address in std::iter::once(&transaction.fee_payer).chain(&transaction.signers) {
+            if !seen.insert(address) {
+                continue;
+            }
+            if let Some(name) = self.resolve_address(address).await? {
+                domains.insert(*address, name);
+            }
+        }
+
+        Ok(domains)
+    }
+
+    /// Executes a typed request, such as a
+    /// [`TransactionHistoryRequest`](crate::requests::TransactionHistoryRequest).
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The request to execute.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the request is invalid, the request fails or the
+    /// response status is not 200.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), shyft_rs_sdk::Error> {
+    /// # use shyft_rs_sdk::{requests::ParsedTransactionRequest, ShyftApi};
+    /// #
+    /// # let api_key = "your_api_key";
+    /// # let client = ShyftApi::new(api_key, None, None, None, None, None).unwrap();
+    /// let tx_signature = "uZz2qDvLzndsTEY31YkxgRe1rYQ8MsCtS3DwhPYP1Md7u7dnUK4HW3vYzsxE6GSxFhSG5zpvqSQnSUn1sPmzTBu".parse()?;
+    /// let transaction_details = client
+    ///     .execute(&ParsedTransactionRequest::new(tx_signature))
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn execute<R: ShyftRequest>(
+        &self,
+        request: &R,
+    ) -> Result<R::Response, crate::error::Error> {
+        request.validate()?;
+
+        let cache_policy = if request.is_immutable() {
+            CachePolicy::Immutable
+        } else {
+            CachePolicy::Mutable
+        };
+        self.send(
+            request.http_request(&self.client, &self.base_url, &self.default_params),
+            cache_policy,
+        )
+        .await
+    }
+
+    /// Sends a request and returns the result of the response, going through the cache.
+    ///
+    /// Immutable responses are only cached with the finalized commitment, and mutable
+    /// responses only if a cache TTL is set.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the request fails, the response status is not 200
+    /// or the response cannot be deserialized.
+    async fn send<T: DeserializeOwned>(
+        &self,
+        request: reqwest_middleware::RequestBuilder,
+        cache_policy: CachePolicy,
+    ) -> Result<T, crate::error::Error> {
+        let mut request = request.build()?;
+        // Set on the request, as the builder of reqwest-middleware has no timeout on wasm32
+        *request.timeout_mut() = Some(self.timeout.unwrap_or(constants::TIMEOUT));
+
+        let ttl = match cache_policy {
+            CachePolicy::Immutable if self.is_finalized() => Some(None),
+            CachePolicy::Immutable => None,
+            CachePolicy::Mutable => self.cache_ttl.map(Some),
+        };
+        let cache = self.cache.as_ref().zip(ttl).map(|(cache, ttl)| {
+            let body = request
+                .body()
+                .and_then(|body| body.as_bytes())
+                .map(String::from_utf8_lossy)
+                .unwrap_or_default();
+            let key = format!("{} {} {}", request.method(), request.url(), body);
+            (cache, key, ttl)
+        });
+
+        if let Some((cache, key, _)) = &cache {
+            if let Some(cached) = cache.get(key).await {
+                if let Ok(response) = serde_json::from_str::<models::Response<T>>(&cached) {
+                    return Ok(response.result);
+                }
+            }
+        }
+
+        let response = self.client.execute(request).await?;
 
         if !response.status().is_success() {
             return Err(crate::error::Error::StatusNot200(response.text().await?));
         }
 
-        let parsed_response = response
-            .json::<models::Response<Vec<ParsedTransactionDetails>>>()
-            .await?
-            .result;
+        let body = response.text().await?;
+        let parsed_response = serde_json::from_str::<models::Response<T>>(&body)?.result;
+
+        if let Some((cache, key, ttl)) = cache {
+            cache.set(&key, body, ttl).await;
+        }
+
         Ok(parsed_response)
     }
+
+    /// Calls a method of the Solana JSON-RPC API of Shyft, on the network of the client.
+    ///
+    /// RPC responses are never cached.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the request fails, the response status is not 200
+    /// or the node answers with an error.
+    pub(crate) async fn rpc<T: DeserializeOwned>(
+        &self,
+        method: &str,
+        params: serde_json::Value,
+    ) -> Result<T, crate::error::Error> {
+        let url = match (&self.rpc_url, self.default_params["network"].parse()) {
+            (Some(rpc_url), _) => rpc_url.as_str(),
+            (None, Ok(Network::Devnet)) => constants::RPC_URL_DEVNET,
+            (None, Ok(Network::Testnet)) => constants::RPC_URL_TESTNET,
+            (None, _) => constants::RPC_URL,
+        };
+        let body = serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": method,
+            "params": params,
+        });
+
+        let mut request = self.client.post(url).json(&body).build()?;
+        *request.timeout_mut() = Some(self.timeout.unwrap_or(constants::TIMEOUT));
+        let mut extensions = http::Extensions::new();
+        extensions.insert(ApiKeyInQuery);
+        let response = self
+            .client
+            .execute_with_extensions(request, &mut extensions)
+            .await?;
+
+        // The URL of the response contains the API key, so it is dropped from errors
+        if !response.status().is_success() {
+            let text = response.text().await.map_err(reqwest::Error::without_url)?;
+            return Err(crate::error::Error::StatusNot200(text));
+        }
+
+        let response = response
+            .json::<models::JsonRpcResponse<T>>()
+            .await
+            .map_err(reqwest::Error::without_url)?;
+        match (response.result, response.error) {
+            (Some(result), None) => Ok(result),
+            (_, error) => Err(crate::error::Error::Rpc(
+                error.map_or_else(|| "missing result".to_string(), |error| error.to_string()),
+            )),
+        }
+    }
+
+    /// Returns `true` if the client uses the finalized commitment.
+    fn is_finalized(&self) -> bool {
+        self.default_params.get("commitment").map(String::as_str)
+            == Some(&Commitment::Finalized.to_string())
+    }
+}
+
+/// Builder for [`ShyftApi`], created with [`ShyftApi::builder`] or
+/// [`ShyftApi::builder_with_api_key_pool`].
+///
+/// Every option not set falls back to the same default as [`ShyftApi::new`].
+#[derive(Debug, Clone)]
+pub struct ShyftApiBuilder {
+    /// The API keys, or `None` if the API key is invalid.
+    api_keys: Option<ApiKeyPool>,
+    /// Minimum retry interval in milliseconds.
+    min_retry_interval: Option<u64>,
+    /// Maximum retry interval in milliseconds.
+    max_retry_interval: Option<u64>,
+    /// Maximum number of retries.
+    max_retries: Option<u32>,
+    /// Network to interact with.
+    network: Option<Network>,
+    /// Commitment level for transactions.
+    commitment: Option<Commitment>,
+    /// Client-side rate limiter.
+    rate_limiter: Option<RateLimiter>,
+    /// Cache for API responses.
+    cache: Option<Arc<dyn Cache>>,
+    /// Time to live of cached mutable responses.
+    cache_ttl: Option<Duration>,
+    /// Base URL of the Shyft API.
+    base_url: Option<String>,
+    /// URL of the JSON-RPC API.
+    rpc_url: Option<String>,
+}
+
+impl ShyftApiBuilder {
+    /// Creates a builder with the given API keys and every other option unset.
+    fn new(api_keys: Option<ApiKeyPool>) -> Self {
+        Self {
+            api_keys,
+            min_retry_interval: None,
+            max_retry_interval: None,
+            max_retries: None,
+            network: None,
+            commitment: None,
+            rate_limiter: None,
+            cache: None,
+            cache_ttl: None,
+            base_url: None,
+            rpc_url: None,
+        }
+    }
+
+    /// Sets the minimum retry interval in milliseconds.
+    pub fn min_retry_interval(mut self, min_retry_interval: u64) -> Self {
+        self.min_retry_interval = Some(min_retry_interval);
+        self
+    }
+
+    /// Sets the maximum retry interval in milliseconds.
+    pub fn max_retry_interval(mut self, max_retry_interval: u64) -> Self {
+        self.max_retry_interval = Some(max_retry_interval);
+        self
+    }
+
+    /// Sets the maximum number of retries.
+    pub fn max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = Some(max_retries);
+        self
+    }
+
+    /// Sets the network to interact with.
+    pub fn network(mut self, network: Network) -> Self {
+        self.network = Some(network);
+        self
+    }
+
+    /// Sets the commitment level for transactions.
+    pub fn commitment(mut self, commitment: Commitment) -> Self {
+        self.commitment = Some(commitment);
+        self
+    }
+
+    /// Sets a client-side rate limiter, applied to every request including retries.
+    ///
+    /// The limiter is shared by all the clones of the client. Pass clones of the same
+    /// limiter to several clients to make them share it too.
+    pub fn rate_limiter(mut self, rate_limiter: RateLimiter) -> Self {
+        self.rate_limiter = Some(rate_limiter);
+        self
+    }
+
+    /// Sets a cache for API responses, such as an [`LruCache`](crate::LruCache).
+    ///
+    /// Transaction details are only cached with [`Commitment::Finalized`], as confirmed
+    /// transactions may still be rolled back. See [`ShyftApiBuilder::cache_ttl`] for mutable data.
+    pub fn cache(mut self, cache: impl Cache + 'static) -> Self {
+        self.cache = Some(Arc::new(cache));
+        self
+    }
+
+    /// Sets the time to live of cached mutable responses, such as transaction histories and
+    /// domains. Mutable responses are not cached unless this is set.
+    pub fn cache_ttl(mut self, cache_ttl: Duration) -> Self {
+        self.cache_ttl = Some(cache_ttl);
+        self
+    }
+
+    /// Sets a pool of API keys to rotate among, replacing the API key given to
+    /// [`ShyftApi::builder`]. See also [`ShyftApi::builder_with_api_key_pool`].
+    ///
+    /// The pool is shared with the client, so keys can be replaced at runtime with
+    /// [`ApiKeyPool::set_keys`] on a clone of it.
+    pub fn api_key_pool(mut self, api_keys: ApiKeyPool) -> Self {
+        self.api_keys = Some(api_keys);
+        self
+    }
+
+    /// Sets the base URL of the Shyft API, [`constants::URL`] by default, for example to go
+    /// through a proxy.
+    ///
+    /// Endpoint paths such as `transaction/parsed` are appended to it.
+    pub fn base_url(mut self, base_url: &str) -> Self {
+        let separator = if base_url.ends_with('/') { "" } else { "/" };
+        self.base_url = Some(format!("{}{}", base_url, separator));
+        self
+    }
+
+    /// Sets the URL of the Solana JSON-RPC API, used by the priority fee estimates. Defaults to
+    /// the Shyft RPC of the network.
+    pub fn rpc_url(mut self, rpc_url: &str) -> Self {
+        self.rpc_url = Some(rpc_url.to_string());
+        self
+    }
+
+    /// Builds the blocking Shyft API client.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the API key is invalid, or the HTTP client or the
+    /// runtime could not be built.
+    #[cfg(feature = "blocking")]
+    pub fn build_blocking(self) -> Result<crate::blocking::ShyftApi, crate::error::Error> {
+        crate::blocking::ShyftApi::from_async(self.build()?)
+    }
+
+    /// Builds the Shyft API client.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the API key is invalid or the HTTP client could not
+    /// be built.
+    pub fn build(self) -> Result<ShyftApi, crate::error::Error> {
+        let api_keys = self.api_keys.ok_or(crate::error::Error::InvalidApiKey)?;
+
+        let http_client = reqwest::Client::builder().build()?;
+
+        let mut default_params = HashMap::new();
+        default_params.insert(
+            "network".to_string(),
+            self.network.unwrap_or(Network::MainnetBeta).to_string(),
+        );
+        default_params.insert(
+            "commitment".to_string(),
+            self.commitment.unwrap_or(Commitment::Confirmed).to_string(),
+        );
+
+        let min_retry_interval = self
+            .min_retry_interval
+            .unwrap_or(constants::MIN_RETRY_INTERVAL);
+        let max_retry_interval = self
+            .max_retry_interval
+            .unwrap_or(constants::MAX_RETRY_INTERVAL);
+        let max_retries = self.max_retries.unwrap_or(constants::MAX_RETRIES);
+
+        let client = middleware_client(
+            http_client.clone(),
+            min_retry_interval,
+            max_retry_interval,
+            max_retries,
+            self.rate_limiter.clone(),
+            api_keys.clone(),
+            &default_params,
+        );
+
+        Ok(ShyftApi {
+            client,
+            default_params,
+            cache: self.cache,
+            cache_ttl: self.cache_ttl,
+            http_client,
+            min_retry_interval,
+            max_retry_interval,
+            max_retries,
+            rate_limiter: self.rate_limiter,
+            api_keys,
+            timeout: None,
+            max_watch_pages: None,
+            base_url: self.base_url.unwrap_or_else(|| constants::URL.to_string()),
+            rpc_url: self.rpc_url,
+        })
+    }
+}
+
+/// Wraps an HTTP client with the retry, rate limiting, API key and instrumentation middleware.
+fn middleware_client(
+    http_client: reqwest::Client,
+    min_retry_interval: u64,
+    max_retry_interval: u64,
+    max_retries: u32,
+    rate_limiter: Option<RateLimiter>,
+    api_keys: ApiKeyPool,
+    #[cfg_attr(
+        not(any(feature = "tracing", feature = "metrics")),
+        allow(unused_variables)
+    )]
+    default_params: &HashMap<String, String>,
+) -> reqwest_middleware::ClientWithMiddleware {
+    let retry_s = get_retry_strategy(min_retry_interval, max_retry_interval, max_retries);
+
+    let client = reqwest_middleware::ClientBuilder::new(http_client);
+    #[cfg(any(feature = "tracing", feature = "metrics"))]
+    let client = client.with(crate::instrument::Instrumentation::new(
+        default_params["network"].clone(),
+        default_params["commitment"].clone(),
+    ));
+    let mut client = client.with(retry_s);
+    #[cfg(any(feature = "tracing", feature = "metrics"))]
+    {
+        client = client.with(crate::instrument::AttemptCounter);
+    }
+    if let Some(rate_limiter) = rate_limiter {
+        client = client.with(rate_limiter);
+    }
+    client.with(api_keys).build()
 }
@@ -0,0 +1,329 @@
+//! `shyft`, a command-line tool for quick lookups against the Shyft API.
+//!
+//! Requires the `cli` feature. The API key is read from the `SHYFT_API_KEY` environment
+//! variable, or from the `--api-key` option.
+//!
+//! ```text
+//! shyft tx parsed <SIGNATURE>
+//! shyft tx history <ACCOUNT> [--all]
+//! shyft tx parse-selected <FILE>
+//! shyft wallet domains <WALLET>
+//! shyft wallet resolve <ADDRESS>
+//! ```
+//!
+//! Only the transaction and wallet APIs are wrapped by the SDK, so token and NFT lookups are not
+//! available yet.
+
+use std::{io, path::PathBuf};
+
+use clap::{Parser, Subcommand, ValueEnum};
+use serde::Serialize;
+use shyft_rs_sdk::{
+    constants, export,
+    models::{domain::Domain, parsed_transaction_details::ParsedTransactionDetails},
+    Commitment, Network, Pubkey, ShyftApi, Signature,
+};
+
+/// Command-line tool for the Shyft API.
+#[derive(Parser)]
+#[command(name = "shyft", version, about)]
+struct Cli {
+    /// Shyft API key.
+    #[arg(long, env = "SHYFT_API_KEY", hide_env_values = true)]
+    api_key: String,
+
+    /// Network to query (mainnet-beta, devnet, testnet).
+    #[arg(long, global = true, default_value = "mainnet-beta")]
+    network: Network,
+
+    /// Commitment level (confirmed, finalized).
+    #[arg(long, global = true, default_value = "confirmed")]
+    commitment: Commitment,
+
+    /// Output format.
+    #[arg(long, short, global = true, value_enum, default_value_t = Output::Json)]
+    output: Output,
+
+    /// Command to run.
+    #[command(subcommand)]
+    command: Command,
+}
+
+/// Output format of the results.
+#[derive(Clone, Copy, ValueEnum)]
+enum Output {
+    /// Pretty-printed JSON.
+    Json,
+    /// Human-readable table.
+    Table,
+    /// CSV, one row per transaction action.
+    Csv,
+}
+
+/// Top-level commands.
+#[derive(Subcommand)]
+enum Command {
+    /// Transaction lookups.
+    #[command(subcommand)]
+    Tx(TxCommand),
+    /// Wallet lookups.
+    #[command(subcommand)]
+    Wallet(WalletCommand),
+}
+
+/// Transaction lookups.
+#[derive(Subcommand)]
+enum TxCommand {
+    /// Fetch the parsed details of a transaction.
+    Parsed {
+        /// Signature of the transaction.
+        signature: Signature,
+    },
+    /// Fetch the transaction history of an account, most recent first.
+    History {
+        /// Address of the account.
+        account: Pubkey,
+        /// Number of transactions to fetch per page.
+        #[arg(long, default_value_t = 10)]
+        tx_num: u32,
+        /// Only fetch transactions before this signature.
+        #[arg(long)]
+        before: Option<Signature>,
+        /// Only fetch transactions until this signature.
+        #[arg(long)]
+        until: Option<Signature>,
+        /// Fetch the full history, page by page.
+        #[arg(long)]
+        all: bool,
+        /// Include raw transaction details.
+        #[arg(long)]
+        raw: bool,
+        /// Include transaction events.
+        #[arg(long)]
+        events: bool,
+    },
+    /// Fetch the parsed details of the transactions listed in a file, one signature per line.
+    ///
+    /// Transactions that could not be fetched are reported on stderr.
+    ParseSelected {
+        /// Path of the file listing the signatures.
+        file: PathBuf,
+        /// Maximum number of batches of 100 signatures requested at once.
+        #[arg(long, default_value_t = 4)]
+        concurrency: usize,
+        /// Include raw transaction details.
+        #[arg(long)]
+        raw: bool,
+        /// Include transaction events.
+        #[arg(long)]
+        events: bool,
+    },
+}
+
+/// Wallet lookups.
+#[derive(Subcommand)]
+enum WalletCommand {
+    /// Fetch the `.sol` domains owned by a wallet.
+    Domains {
+        /// Address of the wallet.
+        wallet: Pubkey,
+    },
+    /// Resolve an address to its primary `.sol` domain.
+    Resolve {
+        /// Address to resolve.
+        address: Pubkey,
+    },
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let cli = Cli::parse();
+    let client = ShyftApi::new(
+        &cli.api_key,
+        None,
+        None,
+        None,
+        Some(cli.network),
+        Some(cli.commitment),
+    )?;
+
+    match cli.command {
+        Command::Tx(TxCommand::Parsed { signature }) => {
+            let transaction = client.get_transaction_parsed(&signature).await?;
+            print_transactions(cli.output, &[transaction])?;
+        }
+        Command::Tx(TxCommand::History {
+            account,
+            tx_num,
+            mut before,
+            until,
+            all,
+            raw,
+            events,
+        }) => {
+            let tx_num = if all { constants::MAX_TX_NUM } else { tx_num };
+            let mut transactions = Vec::new();
+            loop {
+                let page = client
+                    .get_transaction_history(
+                        &account,
+                        Some(tx_num),
+                        before.as_ref(),
+                        until.as_ref(),
+                        Some(raw),
+                        Some(events),
+                    )
+                    .await?;
+                let is_last_page = !all || page.len() < tx_num as usize;
+                before = page
+                    .last()
+                    .and_then(|transaction| transaction.signatures.first().copied());
+                transactions.extend(page);
+                if is_last_page || before.is_none() {
+                    break;
+                }
+            }
+            print_transactions(cli.output, &transactions)?;
+        }
+        Command::Tx(TxCommand::ParseSelected {
+            file,
+            concurrency,
+            raw,
+            events,
+        }) => {
+            let signatures = std::fs::read_to_string(file)?
+                .lines()
+                .map(str::trim)
+                .filter(|line| !line.is_empty())
+                .map(str::parse)
+                .collect::<Result<Vec<Signature>, _>>()?;
+            let results = client
+                .get_transaction_parse_selected_bulk(
+                    signatures,
+                    concurrency,
+                    Some(raw),
+                    Some(events),
+                )
+                .await;
+
+            let total = results.len();
+            let mut transactions = Vec::with_capacity(total);
+            for (signature, result) in results {
+                match result {
+                    Ok(transaction) => transactions.push(transaction),
+                    Err(error) => eprintln!("{}: {}", signature, error),
+                }
+            }
+            print_transactions(cli.output, &transactions)?;
+
+            let failed = total - transactions.len();
+            if failed > 0 {
+                return Err(
+                    format!("{} of {} transactions could not be fetched", failed, total).into(),
+                );
+            }
+        }
+        Command::Wallet(WalletCommand::Domains { wallet }) => {
+            let domains = client.get_domains(&wallet).await?;
+            print_domains(cli.output, &domains)?;
+        }
+        Command::Wallet(WalletCommand::Resolve { address }) => {
+            let domains: Vec<Domain> = client
+                .resolve_address(&address)
+                .await?
+                .map(|name| Domain { address, name })
+                .into_iter()
+                .collect();
+            print_domains(cli.output, &domains)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Prints transactions in the requested output format.
+fn print_transactions(
+    output: Output,
+    transactions: &[ParsedTransactionDetails],
+) -> Result<(), Box<dyn std::error::Error>> {
+    match output {
+        Output::Json => print_json(transactions)?,
+        Output::Csv => export::write_csv(io::stdout().lock(), &export::flatten(transactions))?,
+        Output::Table => print_table(
+            &["SIGNATURE", "TIMESTAMP", "TYPE", "STATUS", "FEE", "ACTIONS"],
+            transactions.iter().map(|transaction| {
+                vec![
+                    transaction
+                        .signatures
+                        .first()
+                        .map(ToString::to_string)
+                        .unwrap_or_default(),
+                    transaction.timestamp.clone(),
+                    transaction.transaction_type.to_string(),
+                    transaction.status.to_string(),
+                    transaction.fee.to_string(),
+                    transaction.actions.len().to_string(),
+                ]
+            }),
+        ),
+    }
+    Ok(())
+}
+
+/// Prints domains in the requested output format.
+fn print_domains(output: Output, domains: &[Domain]) -> Result<(), Box<dyn std::error::Error>> {
+    match output {
+        Output::Json => print_json(domains)?,
+        Output::Csv => {
+            let mut writer = csv::Writer::from_writer(io::stdout().lock());
+            for domain in domains {
+                writer.serialize(domain)?;
+            }
+            writer.flush()?;
+        }
+        Output::Table => print_table(
+            &["NAME", "ADDRESS"],
+            domains
+                .iter()
+                .map(|domain| vec![domain.name.clone(), domain.address.to_string()]),
+        ),
+    }
+    Ok(())
+}
+
+/// Prints a value as pretty-printed JSON.
+fn print_json<T: Serialize + ?Sized>(value: &T) -> Result<(), serde_json::Error> {
+    serde_json::to_writer_pretty(io::stdout().lock(), value)?;
+    println!();
+    Ok(())
+}
+
+/// Prints rows as a table with left-aligned, space-separated columns.
+fn print_table(headers: &[&str], rows: impl Iterator<Item = Vec<String>>) {
+    let rows: Vec<Vec<String>> = rows.collect();
+    let widths: Vec<usize> = headers
+        .iter()
+        .enumerate()
+        .map(|(i, header)| {
+            rows.iter()
+                .map(|row| row[i].len())
+                .chain([header.len()])
+                .max()
+                .unwrap_or_default()
+        })
+        .collect();
+
+    let print_row = |cells: Vec<&str>| {
+        let line: Vec<String> = cells
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{cell:<width$}"))
+            .collect();
+        println!("{}", line.join("  ").trim_end());
+    };
+
+    print_row(headers.to_vec());
+    for row in &rows {
+        print_row(row.iter().map(String::as_str).collect());
+    }
+}
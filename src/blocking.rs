@@ -0,0 +1,304 @@
+//! This module provides a blocking client for the Shyft API.
+//!
+//! The blocking [`ShyftApi`] has the same methods as the async [`crate::ShyftApi`] and shares its
+//! models and error types, except that watchers return iterators instead of streams. Each client
+//! runs the async client on its own single-threaded runtime, so synchronous programs don't need
+//! to set up one.
+//!
+//! The blocking client must not be used from within an async runtime, as blocking on it would
+//! panic.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use shyft_rs_sdk::blocking::ShyftApi;
+//!
+//! let client = ShyftApi::new("your_api_key", None, None, None, None, None)?;
+//! let tx_signature = "uZz2qDvLzndsTEY31YkxgRe1rYQ8MsCtS3DwhPYP1Md7u7dnUK4HW3vYzsxE6GSxFhSG5zpvqSQnSUn1sPmzTBu".parse()?;
+//! let transaction_details = client.get_transaction_parsed(&tx_signature)?;
+//! # Ok::<(), shyft_rs_sdk::Error>(())
+//! ```
+//!
+//! Other options are set with [`ShyftApiBuilder::build_blocking`](crate::ShyftApiBuilder::build_blocking).
+
+use std::{collections::HashMap, future::Future, sync::Arc, time::Duration};
+
+use futures::{Stream, StreamExt};
+use tokio::runtime::Runtime;
+
+use crate::{
+    models::{
+        domain::Domain, parsed_transaction_details::ParsedTransactionDetails,
+        priority_fee::PriorityFeeEstimate,
+    },
+    requests::ShyftRequest,
+    Commitment, Network, Pubkey, RequestOptions, Signature,
+};
+
+/// Struct representing the blocking Shyft API client.
+///
+/// Clones share the same connection pool and runtime.
+#[derive(Debug, Clone)]
+pub struct ShyftApi {
+    /// The async client.
+    inner: crate::ShyftApi,
+    /// The runtime the async client runs on.
+    runtime: Arc<Runtime>,
+}
+
+impl ShyftApi {
+    /// Creates a new instance of the blocking Shyft API client.
+    ///
+    /// See [`crate::ShyftApi::new`] for the arguments.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the HTTP client or the runtime could not be built.
+    pub fn new(
+        api_key: &str,
+        min_retry_interval: Option<u64>,
+        max_retry_interval: Option<u64>,
+        max_retries: Option<u32>,
+        network: Option<Network>,
+        commitment: Option<Commitment>,
+    ) -> Result<Self, crate::error::Error> {
+        Self::from_async(crate::ShyftApi::new(
+            api_key,
+            min_retry_interval,
+            max_retry_interval,
+            max_retries,
+            network,
+            commitment,
+        )?)
+    }
+
+    /// Creates a blocking client from an async client.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the runtime could not be built.
+    pub fn from_async(client: crate::ShyftApi) -> Result<Self, crate::error::Error> {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()?;
+
+        Ok(Self {
+            inner: client,
+            runtime: Arc::new(runtime),
+        })
+    }
+
+    /// Returns a client with some of its configuration overridden.
+    ///
+    /// See [`crate::ShyftApi::with_options`].
+    pub fn with_options(&self, options: RequestOptions) -> ShyftApi {
+        Self {
+            inner: self.inner.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Fetches the transaction history for a given account.
+    ///
+    /// See [`crate::ShyftApi::get_transaction_history`].
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the request is invalid, the request fails or the
+    /// response status is not 200.
+    pub fn get_transaction_history(
+        &self,
+        account: &Pubkey,
+        tx_num: Option<u32>,
+        before_tx_signature: Option<&Signature>,
+        until_tx_signature: Option<&Signature>,
+        enable_raw: Option<bool>,
+        enable_events: Option<bool>,
+    ) -> Result<Vec<ParsedTransactionDetails>, crate::error::Error> {
+        self.block_on(self.inner.get_transaction_history(
+            account,
+            tx_num,
+            before_tx_signature,
+            until_tx_signature,
+            enable_raw,
+            enable_events,
+        ))
+    }
+
+    /// Retrieves parsed transaction details for a given transaction signature.
+    ///
+    /// See [`crate::ShyftApi::get_transaction_parsed`].
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the request is invalid, the request fails or the
+    /// response status is not 200.
+    pub fn get_transaction_parsed(
+        &self,
+        tx_signature: &Signature,
+    ) -> Result<ParsedTransactionDetails, crate::error::Error> {
+        self.block_on(self.inner.get_transaction_parsed(tx_signature))
+    }
+
+    /// Retrieves parsed transaction details for given transaction signatures.
+    ///
+    /// See [`crate::ShyftApi::get_transaction_parse_selected`].
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the request is invalid, the request fails or the
+    /// response status is not 200.
+    pub fn get_transaction_parse_selected(
+        &self,
+        transaction_signatures: &[Signature],
+        enable_raw: Option<bool>,
+        enable_events: Option<bool>,
+    ) -> Result<Vec<ParsedTransactionDetails>, crate::error::Error> {
+        self.block_on(self.inner.get_transaction_parse_selected(
+            transaction_signatures,
+            enable_raw,
+            enable_events,
+        ))
+    }
+
+    /// Retrieves parsed transaction details for any number of transaction signatures.
+    ///
+    /// See [`crate::ShyftApi::get_transaction_parse_selected_bulk`].
+    pub fn get_transaction_parse_selected_bulk(
+        &self,
+        transaction_signatures: impl IntoIterator<Item = Signature>,
+        concurrency: usize,
+        enable_raw: Option<bool>,
+        enable_events: Option<bool>,
+    ) -> Vec<(
+        Signature,
+        Result<ParsedTransactionDetails, crate::error::Error>,
+    )> {
+        self.block_on(self.inner.get_transaction_parse_selected_bulk(
+            transaction_signatures,
+            concurrency,
+            enable_raw,
+            enable_events,
+        ))
+    }
+
+    /// Fetches all the `.sol` domains owned by a wallet.
+    ///
+    /// See [`crate::ShyftApi::get_domains`].
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the request is invalid, the request fails or the
+    /// response status is not 200.
+    pub fn get_domains(&self, wallet: &Pubkey) -> Result<Vec<Domain>, crate::error::Error> {
+        self.block_on(self.inner.get_domains(wallet))
+    }
+
+    /// Resolves an address to its primary `.sol` domain name.
+    ///
+    /// See [`crate::ShyftApi::resolve_address`].
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the request is invalid, the request fails or the
+    /// response status is not 200.
+    pub fn resolve_address(&self, address: &Pubkey) -> Result<Option<String>, crate::error::Error> {
+        self.block_on(self.inner.resolve_address(address))
+    }
+
+    /// Resolves the primary `.sol` domains of the fee payer and signers of a transaction.
+    ///
+    /// See [`crate::ShyftApi::resolve_transaction_domains`].
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if any of the underlying requests fail.
+    pub fn resolve_transaction_domains(
+        &self,
+        transaction: &ParsedTransactionDetails,
+    ) -> Result<HashMap<Pubkey, String>, crate::error::Error> {
+        self.block_on(self.inner.resolve_transaction_domains(transaction))
+    }
+
+    /// Estimates the priority fee of a transaction writing to some accounts.
+    ///
+    /// See [`crate::ShyftApi::estimate_priority_fee`].
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the arguments are invalid or the prioritization fees
+    /// request fails.
+    pub fn estimate_priority_fee(
+        &self,
+        accounts: &[Pubkey],
+        percentile: u8,
+    ) -> Result<PriorityFeeEstimate, crate::error::Error> {
+        self.block_on(self.inner.estimate_priority_fee(accounts, percentile))
+    }
+
+    /// Executes a typed request.
+    ///
+    /// See [`crate::ShyftApi::execute`].
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the request is invalid, the request fails or the
+    /// response status is not 200.
+    pub fn execute<R: ShyftRequest>(
+        &self,
+        request: &R,
+    ) -> Result<R::Response, crate::error::Error> {
+        self.block_on(self.inner.execute(request))
+    }
+
+    /// Watches an account for new transactions, by polling its transaction history.
+    ///
+    /// See [`crate::ShyftApi::watch_account`]. The returned iterator never ends, and blocks
+    /// until the next new transaction or error.
+    pub fn watch_account(
+        &self,
+        account: Pubkey,
+        poll_interval: Duration,
+        enable_raw: Option<bool>,
+        enable_events: Option<bool>,
+    ) -> impl Iterator<Item = Result<ParsedTransactionDetails, crate::error::Error>> {
+        self.iter(
+            self.inner
+                .watch_account(account, poll_interval, enable_raw, enable_events),
+        )
+    }
+
+    /// Watches several accounts for new transactions, by polling their transaction histories.
+    ///
+    /// See [`crate::ShyftApi::watch_accounts`]. The returned iterator never ends, and blocks
+    /// until the next new transaction or error.
+    pub fn watch_accounts(
+        &self,
+        accounts: impl IntoIterator<Item = Pubkey>,
+        poll_interval: Duration,
+        enable_raw: Option<bool>,
+        enable_events: Option<bool>,
+    ) -> impl Iterator<
+        Item = (
+            Pubkey,
+            Result<ParsedTransactionDetails, crate::error::Error>,
+        ),
+    > {
+        self.iter(
+            self.inner
+                .watch_accounts(accounts, poll_interval, enable_raw, enable_events),
+        )
+    }
+
+    /// Runs a future of the async client to completion.
+    fn block_on<F: Future>(&self, future: F) -> F::Output {
+        self.runtime.block_on(future)
+    }
+
+    /// Turns a stream of the async client into an iterator, blocking on each item.
+    fn iter<S: Stream>(&self, stream: S) -> impl Iterator<Item = S::Item> {
+        let runtime = self.runtime.clone();
+        let mut stream = Box::pin(stream);
+        std::iter::from_fn(move || runtime.block_on(stream.next()))
+    }
+}
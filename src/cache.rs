@@ -0,0 +1,102 @@
+//! This module provides a pluggable cache for Shyft API responses.
+//!
+//! Responses are cached by endpoint and parameters. Transaction details are immutable once
+//! finalized, so they are only cached when the client uses [`Commitment::Finalized`] and are
+//! kept until evicted. Mutable data, such as transaction histories, domains and batches of
+//! selected transactions, which may be missing some of them, is only cached when a TTL is
+//! configured with [`ShyftApiBuilder::cache_ttl`].
+//!
+//! The in-memory [`LruCache`] is provided, and other backends such as Redis or disk can be
+//! plugged in by implementing [`Cache`].
+//!
+//! [`Commitment::Finalized`]: crate::Commitment::Finalized
+//! [`ShyftApiBuilder::cache_ttl`]: crate::ShyftApiBuilder::cache_ttl
+//!
+//! # Examples
+//!
+//! ```
+//! use std::{num::NonZeroUsize, time::Duration};
+//!
+//! use shyft_rs_sdk::{Commitment, LruCache, ShyftApi};
+//!
+//! let client = ShyftApi::builder("your_api_key")
+//!     .commitment(Commitment::Finalized)
+//!     .cache(LruCache::new(NonZeroUsize::new(1_000).unwrap()))
+//!     .cache_ttl(Duration::from_secs(30))
+//!     .build()?;
+//! # Ok::<(), shyft_rs_sdk::Error>(())
+//! ```
+
+use std::{fmt::Debug, num::NonZeroUsize, sync::Mutex, time::Duration};
+
+use crate::time::Instant;
+
+/// Trait for cache backends storing raw Shyft API responses.
+///
+/// Values are the JSON bodies of the responses. Backends may evict entries at any time.
+#[async_trait::async_trait]
+pub trait Cache: Debug + Send + Sync {
+    /// Returns the value stored for a key, if any and not expired.
+    async fn get(&self, key: &str) -> Option<String>;
+
+    /// Stores a value for a key, expiring after `ttl` if given.
+    async fn set(&self, key: &str, value: String, ttl: Option<Duration>);
+}
+
+/// Struct representing an in-memory cache evicting the least recently used entries.
+#[derive(Debug)]
+pub struct LruCache {
+    /// The cached entries.
+    entries: Mutex<lru::LruCache<String, Entry>>,
+}
+
+/// A cached value with its expiry.
+#[derive(Debug)]
+struct Entry {
+    /// The cached value.
+    value: String,
+    /// When the value expires, if ever.
+    expires_at: Option<Instant>,
+}
+
+impl LruCache {
+    /// Creates a new cache holding up to `capacity` entries.
+    pub fn new(capacity: NonZeroUsize) -> Self {
+        Self {
+            entries: Mutex::new(lru::LruCache::new(capacity)),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Cache for LruCache {
+    async fn get(&self, key: &str) -> Option<String> {
+        let mut entries = self.entries.lock().unwrap();
+        let entry = entries.get(key)?;
+        if entry
+            .expires_at
+            .is_some_and(|expires_at| expires_at <= Instant::now())
+        {
+            entries.pop(key);
+            return None;
+        }
+        Some(entry.value.clone())
+    }
+
+    async fn set(&self, key: &str, value: String, ttl: Option<Duration>) {
+        let expires_at = ttl.map(|ttl| Instant::now() + ttl);
+        self.entries
+            .lock()
+            .unwrap()
+            .put(key.to_owned(), Entry { value, expires_at });
+    }
+}
+
+/// Enum representing how a response may be cached.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum CachePolicy {
+    /// The response never changes once finalized.
+    Immutable,
+    /// The response may change over time.
+    Mutable,
+}
@@ -1,11 +1,73 @@
 //! This module contains constants used throughout the Shyft Rust SDK.
 
+use std::time::Duration;
+
 /// Base URL for the Shyft API
 pub const URL: &str = "https://api.shyft.to/sol/v1/";
 
+/// RPC URL of Shyft on mainnet-beta
+pub const RPC_URL: &str = "https://rpc.shyft.to";
+/// RPC URL of Shyft on devnet
+pub const RPC_URL_DEVNET: &str = "https://devnet-rpc.shyft.to";
+/// RPC URL of Shyft on testnet
+pub const RPC_URL_TESTNET: &str = "https://testnet-rpc.shyft.to";
+
+/// Default timeout of each request
+pub const TIMEOUT: Duration = Duration::from_secs(10);
+
 /// Minimum retry interval in milliseconds
 pub const MIN_RETRY_INTERVAL: u64 = 500;
 /// Maximum retry interval in milliseconds
 pub const MAX_RETRY_INTERVAL: u64 = 1000;
 /// Maximum number of retries
 pub const MAX_RETRIES: u32 = 3;
+
+/// Maximum number of transaction signatures per parse_selected request
+pub const MAX_PARSE_SELECTED_SIGNATURES: usize = 100;
+
+/// Maximum number of transactions per transaction history request
+pub const MAX_TX_NUM: u32 = 100;
+
+/// Maximum number of history pages fetched by each poll of a watcher
+pub const MAX_WATCH_PAGES: u32 = 10;
+
+/// Address of the wrapped SOL mint, used by Shyft for SOL legs of swaps and transfers
+pub const WRAPPED_SOL_MINT: &str = "So11111111111111111111111111111111111111112";
+
+/// Maximum number of accounts per getRecentPrioritizationFees request
+pub const MAX_PRIORITIZATION_FEE_ACCOUNTS: usize = 128;
+
+/// Number of recent transactions per account sampled for their compute units
+pub const COMPUTE_UNIT_SAMPLE_TX_NUM: u32 = 20;
+
+/// Maximum number of transaction histories fetched at once to sample compute units
+pub const COMPUTE_UNIT_SAMPLE_CONCURRENCY: usize = 8;
+
+/// Delay without gRPC updates after which the server is pinged
+#[cfg(feature = "grpc")]
+pub const GRPC_PING_INTERVAL: Duration = Duration::from_secs(10);
+/// Delay before the first gRPC reconnect attempt
+#[cfg(feature = "grpc")]
+pub const GRPC_MIN_RECONNECT_INTERVAL: Duration = Duration::from_millis(500);
+/// Maximum delay between two gRPC reconnect attempts
+#[cfg(feature = "grpc")]
+pub const GRPC_MAX_RECONNECT_INTERVAL: Duration = Duration::from_secs(30);
+
+/// WebSocket RPC URL of Shyft on mainnet-beta
+#[cfg(feature = "pubsub")]
+pub const WS_RPC_URL: &str = "wss://rpc.shyft.to";
+/// WebSocket RPC URL of Shyft on devnet
+#[cfg(feature = "pubsub")]
+pub const WS_RPC_URL_DEVNET: &str = "wss://devnet-rpc.shyft.to";
+/// WebSocket RPC URL of Shyft on testnet
+#[cfg(feature = "pubsub")]
+pub const WS_RPC_URL_TESTNET: &str = "wss://testnet-rpc.shyft.to";
+/// Interval between two WebSocket pings
+#[cfg(feature = "pubsub")]
+pub const WS_PING_INTERVAL: Duration = Duration::from_secs(30);
+/// Delay before the first WebSocket reconnect attempt
+#[cfg(feature = "pubsub")]
+pub const WS_MIN_RECONNECT_INTERVAL: Duration = Duration::from_millis(500);
+/// Maximum delay between two WebSocket reconnect attempts
+#[cfg(feature = "pubsub")]
+pub const WS_MAX_RECONNECT_INTERVAL: Duration = Duration::from_secs(30);
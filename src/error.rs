@@ -11,7 +11,102 @@ pub enum Error {
     #[error("Reqwest middleware error: {0}")]
     ReqwestMiddleware(#[from] reqwest_middleware::Error),
 
+    /// Error originating from serde_json.
+    #[error("Serde JSON error: {0}")]
+    SerdeJson(#[from] serde_json::Error),
+
+    /// Error originating from an I/O operation.
+    #[cfg(any(feature = "export", feature = "blocking"))]
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// Error originating from the csv library.
+    #[cfg(feature = "export")]
+    #[error("CSV error: {0}")]
+    Csv(#[from] csv::Error),
+
+    /// Error originating from the parquet library.
+    #[cfg(feature = "parquet")]
+    #[error("Parquet error: {0}")]
+    Parquet(#[from] parquet::errors::ParquetError),
+
+    /// Error originating from the SQLite database of the indexer.
+    #[cfg(feature = "indexer")]
+    #[error("SQLite error: {0}")]
+    Sqlite(#[from] rusqlite::Error),
+
+    /// Error originating from the gRPC transport.
+    #[cfg(feature = "grpc")]
+    #[error("gRPC transport error: {0}")]
+    GrpcTransport(#[from] tonic::transport::Error),
+
+    /// Error indicating that a gRPC call failed.
+    #[cfg(feature = "grpc")]
+    #[error("gRPC status: {0}")]
+    GrpcStatus(Box<tonic::Status>),
+
+    /// Error originating from the WebSocket connection, shared by every subscription.
+    #[cfg(feature = "pubsub")]
+    #[error("WebSocket error: {0}")]
+    WebSocket(std::sync::Arc<tokio_tungstenite::tungstenite::Error>),
+
+    /// Error indicating that the RPC node answered a request with an error.
+    #[error("RPC error: {0}")]
+    Rpc(String),
+
     /// Error indicating that the response status was not 200 OK.
     #[error("Response status not 200: {0}")]
     StatusNot200(String),
+
+    /// Error indicating that a transaction could not be found or parsed.
+    #[error("Transaction not found: {0}")]
+    TransactionNotFound(crate::Signature),
+
+    /// Error indicating that the batch request containing a transaction failed.
+    #[error("Batch request failed: {0}")]
+    Batch(std::sync::Arc<Error>),
+
+    /// Error indicating that a string is not a valid base58-encoded address.
+    #[error("Invalid address: {0}")]
+    InvalidPubkey(String),
+
+    /// Error indicating that a string is not a valid base58-encoded transaction signature.
+    #[error("Invalid transaction signature: {0}")]
+    InvalidSignature(String),
+
+    /// Error indicating that an API key is not a valid header value.
+    #[error("Invalid API key")]
+    InvalidApiKey,
+
+    /// Error indicating that every API key of the pool is disabled or was removed.
+    #[error("No API key available")]
+    NoApiKey,
+
+    /// Error indicating that a request does not satisfy the constraints of Shyft.
+    #[error("Invalid request: {0}")]
+    InvalidRequest(String),
+
+    /// Error indicating that an update of a gRPC subscription could not be converted.
+    #[cfg(feature = "grpc")]
+    #[error("Invalid gRPC update: {0}")]
+    InvalidUpdate(String),
+
+    /// Error indicating that a timestamp could not be parsed.
+    #[cfg(feature = "chrono")]
+    #[error("Invalid timestamp: {0}")]
+    InvalidTimestamp(#[from] chrono::ParseError),
+}
+
+#[cfg(feature = "grpc")]
+impl From<tonic::Status> for Error {
+    fn from(status: tonic::Status) -> Self {
+        Error::GrpcStatus(Box::new(status))
+    }
+}
+
+#[cfg(feature = "pubsub")]
+impl From<tokio_tungstenite::tungstenite::Error> for Error {
+    fn from(error: tokio_tungstenite::tungstenite::Error) -> Self {
+        Error::WebSocket(std::sync::Arc::new(error))
+    }
 }
@@ -0,0 +1,359 @@
+/*!
+This module exports transactions as tabular data, in CSV, [JSON Lines] or [Parquet] format.
+
+Transactions are flattened into [`ActionRow`]s, one row per action, each carrying the columns
+of its transaction. Transactions without actions produce a single row with empty action
+columns. Well-known action types (SOL and token transfers, mints and swaps) also have their
+addresses and amounts extracted into dedicated columns.
+
+Requires the `export` feature, and the `parquet` feature for Parquet output.
+
+[JSON Lines]: https://jsonlines.org/
+[Parquet]: https://parquet.apache.org/
+
+# Examples
+
+```no_run
+# #[tokio::main]
+# async fn main() -> Result<(), shyft_rs_sdk::Error> {
+# use shyft_rs_sdk::ShyftApi;
+use shyft_rs_sdk::export;
+#
+# let api_key = "your_api_key";
+# let client = ShyftApi::new(api_key, None, None, None, None, None).unwrap();
+
+let account = "your_account_address".parse()?;
+let history = client
+    .get_transaction_history(&account, Some(100), None, None, None, None)
+    .await?;
+
+let rows = export::flatten(&history);
+export::write_csv(std::fs::File::create("history.csv")?, &rows)?;
+# Ok(())
+# }
+```
+*/
+
+use std::io::Write;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    constants,
+    models::parsed_transaction_details::{Info, ParsedTransactionDetails, TxStatus, TxType},
+    Decimal, Pubkey, Signature,
+};
+
+/// Struct representing a single action of a transaction, flattened with its transaction.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ActionRow {
+    /// Signature of the transaction.
+    pub signature: Option<Signature>,
+    /// Timestamp of the transaction, in RFC 3339 format.
+    pub timestamp: String,
+    /// Status of the transaction.
+    pub status: TxStatus,
+    /// Type of the transaction.
+    pub transaction_type: TxType,
+    /// Fee of the transaction, in SOL.
+    #[serde(with = "rust_decimal::serde::str")]
+    pub fee: Decimal,
+    /// Fee payer of the transaction.
+    pub fee_payer: Pubkey,
+    /// Address of the transaction's protocol.
+    pub protocol_address: Pubkey,
+    /// Name of the transaction's protocol.
+    pub protocol_name: String,
+    /// Index of the action in the transaction.
+    pub action_index: Option<u32>,
+    /// Type of the action.
+    pub action_type: Option<TxType>,
+    /// Address of the action's source protocol.
+    pub source_protocol_address: Option<Pubkey>,
+    /// Name of the action's source protocol.
+    pub source_protocol_name: Option<String>,
+    /// Address of the action's parent protocol.
+    pub parent_protocol: Option<Pubkey>,
+    /// Index of the action's instruction.
+    pub ix_index: Option<u32>,
+    /// Sender of a transfer, or swapper of a swap.
+    pub sender: Option<Pubkey>,
+    /// Receiver of a transfer or mint.
+    pub receiver: Option<Pubkey>,
+    /// Token sent, transferred or minted.
+    pub token_in_address: Option<Pubkey>,
+    /// Amount of the token sent, transferred or minted.
+    #[serde(with = "rust_decimal::serde::str_option")]
+    pub token_in_amount: Option<Decimal>,
+    /// Token received in a swap.
+    pub token_out_address: Option<Pubkey>,
+    /// Amount of the token received in a swap.
+    #[serde(with = "rust_decimal::serde::str_option")]
+    pub token_out_amount: Option<Decimal>,
+    /// Information of the action, as JSON.
+    pub info: Option<String>,
+}
+
+impl ActionRow {
+    /// Flattens a transaction into rows, one per action.
+    pub fn from_transaction(transaction: &ParsedTransactionDetails) -> Vec<ActionRow> {
+        let row = ActionRow {
+            signature: transaction.signatures.first().copied(),
+            timestamp: transaction.timestamp.clone(),
+            status: transaction.status.clone(),
+            transaction_type: transaction.transaction_type.clone(),
+            fee: transaction.fee,
+            fee_payer: transaction.fee_payer,
+            protocol_address: transaction.protocol.address,
+            protocol_name: transaction.protocol.name.clone(),
+            action_index: None,
+            action_type: None,
+            source_protocol_address: None,
+            source_protocol_name: None,
+            parent_protocol: None,
+            ix_index: None,
+            sender: None,
+            receiver: None,
+            token_in_address: None,
+            token_in_amount: None,
+            token_out_address: None,
+            token_out_amount: None,
+            info: None,
+        };
+
+        if transaction.actions.is_empty() {
+            return vec![row];
+        }
+
+        transaction
+            .actions
+            .iter()
+            .zip(0..)
+            .map(|(action, action_index)| {
+                let mut row = ActionRow {
+                    action_index: Some(action_index),
+                    action_type: Some(action.action_type.clone()),
+                    source_protocol_address: Some(action.source_protocol.address),
+                    source_protocol_name: Some(action.source_protocol.name.clone()),
+                    parent_protocol: action.parent_protocol,
+                    ix_index: action.ix_index,
+                    info: Some(action.info.to_string()),
+                    ..row.clone()
+                };
+
+                match action.typed_info() {
+                    Ok(Some(Info::SolTransfer(info))) => {
+                        row.sender = Some(info.sender);
+                        row.receiver = Some(info.receiver);
+                        row.token_in_address = Some(
+                            constants::WRAPPED_SOL_MINT
+                                .parse()
+                                .expect("WRAPPED_SOL_MINT is a valid address"),
+                        );
+                        row.token_in_amount = Some(info.amount);
+                    }
+                    Ok(Some(Info::TokenTransfer(info))) => {
+                        row.sender = Some(info.sender);
+                        row.receiver = Some(info.receiver);
+                        row.token_in_address = Some(info.token_address);
+                        row.token_in_amount = Some(info.amount);
+                    }
+                    Ok(Some(Info::TokenMint(info))) => {
+                        row.receiver = Some(info.receiver_address);
+                        row.token_in_address = Some(info.token_address);
+                        row.token_in_amount = Some(info.amount);
+                    }
+                    Ok(Some(Info::PumpfunSwap(info))) => {
+                        row.sender = Some(info.swapper);
+                        row.token_in_address = Some(info.tokens_swapped.token_in.token_address);
+                        row.token_in_amount = Some(info.tokens_swapped.token_in.amount);
+                        row.token_out_address = Some(info.tokens_swapped.token_out.token_address);
+                        row.token_out_amount = Some(info.tokens_swapped.token_out.amount);
+                    }
+                    _ => {}
+                }
+
+                row
+            })
+            .collect()
+    }
+}
+
+/// Flattens transactions into rows, one per action.
+pub fn flatten<'a>(
+    transactions: impl IntoIterator<Item = &'a ParsedTransactionDetails>,
+) -> Vec<ActionRow> {
+    transactions
+        .into_iter()
+        .flat_map(ActionRow::from_transaction)
+        .collect()
+}
+
+/// Writes rows as CSV, with a header row.
+///
+/// # Errors
+///
+/// This function will return an error if a row cannot be written.
+pub fn write_csv<W: Write>(writer: W, rows: &[ActionRow]) -> Result<(), crate::error::Error> {
+    let mut writer = csv::Writer::from_writer(writer);
+    for row in rows {
+        writer.serialize(row)?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+/// Writes rows as JSON Lines, one JSON object per line.
+///
+/// # Errors
+///
+/// This function will return an error if a row cannot be written.
+pub fn write_ndjson<W: Write>(
+    mut writer: W,
+    rows: &[ActionRow],
+) -> Result<(), crate::error::Error> {
+    for row in rows {
+        serde_json::to_writer(&mut writer, row)?;
+        writer.write_all(b"\n")?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+/// Writes rows as a Parquet file with a single row group.
+///
+/// All columns are UTF-8 strings, except for the indexes which are 64-bit integers.
+///
+/// # Errors
+///
+/// This function will return an error if the rows cannot be written.
+#[cfg(feature = "parquet")]
+pub fn write_parquet<W: Write + Send>(
+    writer: W,
+    rows: &[ActionRow],
+) -> Result<(), crate::error::Error> {
+    use std::sync::Arc;
+
+    use parquet::{
+        data_type::{ByteArray, ByteArrayType, Int64Type},
+        file::{properties::WriterProperties, writer::SerializedFileWriter},
+        schema::parser::parse_message_type,
+    };
+
+    /// Values of a single Parquet column.
+    enum Column {
+        /// A UTF-8 string column.
+        String(Vec<Option<String>>),
+        /// A 64-bit integer column.
+        Int64(Vec<Option<i64>>),
+    }
+
+    /// Collects an optional string column.
+    fn strings<'a, T: ToString>(
+        rows: &'a [ActionRow],
+        f: impl Fn(&'a ActionRow) -> Option<T>,
+    ) -> Column {
+        Column::String(
+            rows.iter()
+                .map(|row| f(row).map(|v| v.to_string()))
+                .collect(),
+        )
+    }
+
+    /// Collects an optional integer column.
+    fn ints(rows: &[ActionRow], f: impl Fn(&ActionRow) -> Option<u32>) -> Column {
+        Column::Int64(rows.iter().map(|row| f(row).map(i64::from)).collect())
+    }
+
+    let columns = [
+        ("signature", strings(rows, |row| row.signature)),
+        ("timestamp", strings(rows, |row| Some(&row.timestamp))),
+        ("status", strings(rows, |row| Some(&row.status))),
+        (
+            "transaction_type",
+            strings(rows, |row| Some(&row.transaction_type)),
+        ),
+        ("fee", strings(rows, |row| Some(row.fee))),
+        ("fee_payer", strings(rows, |row| Some(row.fee_payer))),
+        (
+            "protocol_address",
+            strings(rows, |row| Some(row.protocol_address)),
+        ),
+        (
+            "protocol_name",
+            strings(rows, |row| Some(&row.protocol_name)),
+        ),
+        ("action_index", ints(rows, |row| row.action_index)),
+        ("action_type", strings(rows, |row| row.action_type.as_ref())),
+        (
+            "source_protocol_address",
+            strings(rows, |row| row.source_protocol_address),
+        ),
+        (
+            "source_protocol_name",
+            strings(rows, |row| row.source_protocol_name.as_ref()),
+        ),
+        ("parent_protocol", strings(rows, |row| row.parent_protocol)),
+        ("ix_index", ints(rows, |row| row.ix_index)),
+        ("sender", strings(rows, |row| row.sender)),
+        ("receiver", strings(rows, |row| row.receiver)),
+        (
+            "token_in_address",
+            strings(rows, |row| row.token_in_address),
+        ),
+        ("token_in_amount", strings(rows, |row| row.token_in_amount)),
+        (
+            "token_out_address",
+            strings(rows, |row| row.token_out_address),
+        ),
+        (
+            "token_out_amount",
+            strings(rows, |row| row.token_out_amount),
+        ),
+        ("info", strings(rows, |row| row.info.as_ref())),
+    ];
+
+    let fields: Vec<String> = columns
+        .iter()
+        .map(|(name, column)| match column {
+            Column::String(_) => format!("OPTIONAL BYTE_ARRAY {name} (UTF8);"),
+            Column::Int64(_) => format!("OPTIONAL INT64 {name};"),
+        })
+        .collect();
+    let schema = parse_message_type(&format!("message action_row {{ {} }}", fields.join(" ")))?;
+
+    let properties = Arc::new(WriterProperties::builder().build());
+    let mut writer = SerializedFileWriter::new(writer, Arc::new(schema), properties)?;
+    let mut row_group = writer.next_row_group()?;
+
+    for (_, column) in &columns {
+        let Some(mut column_writer) = row_group.next_column()? else {
+            break;
+        };
+        match column {
+            Column::String(values) => {
+                let levels: Vec<i16> = values.iter().map(|v| i16::from(v.is_some())).collect();
+                let values: Vec<ByteArray> = values
+                    .iter()
+                    .flatten()
+                    .map(|v| ByteArray::from(v.as_str()))
+                    .collect();
+                column_writer
+                    .typed::<ByteArrayType>()
+                    .write_batch(&values, Some(&levels), None)?;
+            }
+            Column::Int64(values) => {
+                let levels: Vec<i16> = values.iter().map(|v| i16::from(v.is_some())).collect();
+                let values: Vec<i64> = values.iter().flatten().copied().collect();
+                column_writer
+                    .typed::<Int64Type>()
+                    .write_batch(&values, Some(&levels), None)?;
+            }
+        }
+        column_writer.close()?;
+    }
+
+    row_group.close()?;
+    writer.close()?;
+    Ok(())
+}
@@ -0,0 +1,164 @@
+//! This module contains the typed filters of a gRPC subscription.
+
+use super::proto::{self, CommitmentLevel};
+use crate::{Pubkey, Signature};
+
+/// Struct representing the filters of a gRPC subscription.
+///
+/// Each filter is named, and every update lists the names of the filters it matched. Updates
+/// matching any filter are sent.
+#[derive(Debug, Clone, Default)]
+pub struct SubscribeFilters {
+    /// The subscription request being built.
+    request: proto::SubscribeRequest,
+}
+
+impl SubscribeFilters {
+    /// Creates empty filters, matching no update.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a filter of account updates.
+    pub fn accounts(mut self, name: impl Into<String>, filter: AccountFilter) -> Self {
+        self.request.accounts.insert(name.into(), filter.filter);
+        self
+    }
+
+    /// Adds a filter of transaction updates.
+    pub fn transactions(mut self, name: impl Into<String>, filter: TransactionFilter) -> Self {
+        self.request.transactions.insert(name.into(), filter.filter);
+        self
+    }
+
+    /// Adds a filter of slot updates.
+    pub fn slots(mut self, name: impl Into<String>, filter: SlotFilter) -> Self {
+        self.request.slots.insert(name.into(), filter.filter);
+        self
+    }
+
+    /// Adds a filter of block metadata updates, matching every block.
+    pub fn blocks_meta(mut self, name: impl Into<String>) -> Self {
+        self.request
+            .blocks_meta
+            .insert(name.into(), proto::SubscribeRequestFilterBlocksMeta {});
+        self
+    }
+
+    /// Sets the commitment level of the updates. Defaults to the one of the server, usually
+    /// [`CommitmentLevel::Processed`].
+    pub fn commitment(mut self, commitment: CommitmentLevel) -> Self {
+        self.request.commitment = Some(commitment.into());
+        self
+    }
+
+    /// Replays the updates from a past slot, if the server still has them.
+    pub fn from_slot(mut self, slot: u64) -> Self {
+        self.request.from_slot = Some(slot);
+        self
+    }
+}
+
+impl From<SubscribeFilters> for proto::SubscribeRequest {
+    fn from(filters: SubscribeFilters) -> Self {
+        filters.request
+    }
+}
+
+/// Struct representing a filter of account updates, by address or by owner.
+///
+/// An account matches the filter if it is one of its accounts or is owned by one of its owners.
+#[derive(Debug, Clone, Default)]
+pub struct AccountFilter {
+    /// The filter being built.
+    filter: proto::SubscribeRequestFilterAccounts,
+}
+
+impl AccountFilter {
+    /// Creates an empty filter.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an account to the filter.
+    pub fn account(mut self, account: Pubkey) -> Self {
+        self.filter.account.push(account.to_string());
+        self
+    }
+
+    /// Adds a program owning the accounts to the filter.
+    pub fn owner(mut self, owner: Pubkey) -> Self {
+        self.filter.owner.push(owner.to_string());
+        self
+    }
+}
+
+/// Struct representing a filter of transaction updates.
+#[derive(Debug, Clone, Default)]
+pub struct TransactionFilter {
+    /// The filter being built.
+    filter: proto::SubscribeRequestFilterTransactions,
+}
+
+impl TransactionFilter {
+    /// Creates a filter matching every transaction.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only matches the transactions using at least one of the included accounts.
+    pub fn account_include(mut self, account: Pubkey) -> Self {
+        self.filter.account_include.push(account.to_string());
+        self
+    }
+
+    /// Excludes the transactions using this account.
+    pub fn account_exclude(mut self, account: Pubkey) -> Self {
+        self.filter.account_exclude.push(account.to_string());
+        self
+    }
+
+    /// Only matches the transactions using every required account.
+    pub fn account_required(mut self, account: Pubkey) -> Self {
+        self.filter.account_required.push(account.to_string());
+        self
+    }
+
+    /// Only matches vote transactions if `true`, or non-vote transactions if `false`.
+    pub fn vote(mut self, vote: bool) -> Self {
+        self.filter.vote = Some(vote);
+        self
+    }
+
+    /// Only matches failed transactions if `true`, or successful transactions if `false`.
+    pub fn failed(mut self, failed: bool) -> Self {
+        self.filter.failed = Some(failed);
+        self
+    }
+
+    /// Only matches the transaction with this signature.
+    pub fn signature(mut self, signature: Signature) -> Self {
+        self.filter.signature = Some(signature.to_string());
+        self
+    }
+}
+
+/// Struct representing a filter of slot updates.
+#[derive(Debug, Clone, Default)]
+pub struct SlotFilter {
+    /// The filter being built.
+    filter: proto::SubscribeRequestFilterSlots,
+}
+
+impl SlotFilter {
+    /// Creates a filter matching every slot update.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only matches the updates of the commitment level of the subscription if `true`.
+    pub fn filter_by_commitment(mut self, filter_by_commitment: bool) -> Self {
+        self.filter.filter_by_commitment = Some(filter_by_commitment);
+        self
+    }
+}
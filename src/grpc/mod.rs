@@ -0,0 +1,415 @@
+/*!
+This module provides a client for the Yellowstone gRPC (Geyser) streams offered by Shyft.
+
+A [`GrpcClient`] opens subscriptions described by typed [`SubscribeFilters`], and yields the
+matching account, transaction, slot and block metadata updates as a stream of [`Update`]s.
+Transaction updates are converted into the [`RawTransaction`](crate::models::raw_transaction::RawTransaction)
+model of the crate.
+
+Subscriptions are kept alive by answering the pings of the server and pinging it when no update
+was received for a while. When the connection is lost, the client reconnects with an exponential
+backoff and resumes from the last slot it received, with `from_slot`. Updates of that slot may
+be sent again after a reconnect.
+
+`https` endpoints are always reached with `rustls` and the root certificates of the platform, as
+`tonic` does not support other TLS backends. The `native-tls` and `rustls-tls` features only
+apply to the HTTP and WebSocket clients.
+
+# Examples
+
+```no_run
+# #[tokio::main]
+# async fn main() -> Result<(), shyft_rs_sdk::Error> {
+use futures::StreamExt;
+use shyft_rs_sdk::grpc::{
+    CommitmentLevel, GrpcClient, SubscribeFilters, TransactionFilter, UpdateKind,
+};
+
+let client = GrpcClient::new("https://grpc.ny.shyft.to", Some("your_x_token"))?;
+let raydium = "675kPX9MHTjS2zt1qfr1NYHuzeLXfQM9H24wFSUt1Mp8".parse()?;
+let filters = SubscribeFilters::new()
+    .transactions(
+        "raydium",
+        TransactionFilter::new().account_include(raydium).vote(false),
+    )
+    .commitment(CommitmentLevel::Confirmed);
+
+let mut updates = Box::pin(client.subscribe(filters));
+while let Some(update) = updates.next().await {
+    match update {
+        Ok(update) => {
+            if let UpdateKind::Transaction(update) = update.kind {
+                println!("{} in slot {}", update.signature, update.slot);
+            }
+        }
+        Err(error) => eprintln!("{}", error),
+    }
+}
+# Ok(())
+# }
+```
+*/
+
+mod filters;
+pub mod proto;
+mod updates;
+
+use std::time::Duration;
+
+use futures::{channel::mpsc, stream, Stream};
+use http::uri::PathAndQuery;
+use tonic::{
+    codec::ProstCodec,
+    metadata::AsciiMetadataValue,
+    transport::{ClientTlsConfig, Endpoint},
+    Streaming,
+};
+
+use crate::{constants, error::Error};
+
+pub use filters::{AccountFilter, SlotFilter, SubscribeFilters, TransactionFilter};
+pub use proto::{CommitmentLevel, SlotStatus};
+pub use updates::{
+    AccountUpdate, BlockMetaUpdate, SlotUpdate, TransactionUpdate, Update, UpdateKind,
+};
+
+/// Metadata carrying the access token of the gRPC endpoint.
+const X_TOKEN_HEADER: &str = "x-token";
+
+/// Struct representing a Yellowstone gRPC client.
+///
+/// Clones share the same configuration, but each subscription opens its own connection.
+#[derive(Debug, Clone)]
+pub struct GrpcClient {
+    /// The gRPC endpoint.
+    endpoint: Endpoint,
+    /// The access token, as a sensitive metadata value.
+    x_token: Option<AsciiMetadataValue>,
+    /// Delay without updates after which the server is pinged.
+    ping_interval: Duration,
+    /// Delay before the first reconnect attempt.
+    min_reconnect_interval: Duration,
+    /// Maximum delay between two reconnect attempts.
+    max_reconnect_interval: Duration,
+}
+
+impl GrpcClient {
+    /// Creates a new gRPC client with the default configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `endpoint` - The URL of the gRPC endpoint, such as `https://grpc.ny.shyft.to`.
+    /// * `x_token` - The access token of the endpoint, if it requires one.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the endpoint is not a valid URL or the token is not
+    /// a valid metadata value.
+    pub fn new(endpoint: &str, x_token: Option<&str>) -> Result<Self, Error> {
+        let builder = Self::builder(endpoint);
+        match x_token {
+            Some(x_token) => builder.x_token(x_token).build(),
+            None => builder.build(),
+        }
+    }
+
+    /// Returns a builder to configure a gRPC client.
+    pub fn builder(endpoint: &str) -> GrpcClientBuilder {
+        GrpcClientBuilder {
+            endpoint: endpoint.to_string(),
+            x_token: None,
+            ping_interval: None,
+            min_reconnect_interval: None,
+            max_reconnect_interval: None,
+        }
+    }
+
+    /// Subscribes to the updates matching some filters.
+    ///
+    /// The returned stream never ends. Errors are emitted without ending the stream: the client
+    /// reconnects and resumes from the last slot received. Drop the stream to unsubscribe.
+    ///
+    /// # Arguments
+    ///
+    /// * `filters` - The filters of the updates.
+    pub fn subscribe(
+        &self,
+        filters: SubscribeFilters,
+    ) -> impl Stream<Item = Result<Update, Error>> {
+        let subscription = Subscription {
+            client: self.clone(),
+            request: filters.into(),
+            failures: 0,
+            next_ping_id: 0,
+            connection: None,
+        };
+        stream::unfold(subscription, |subscription| async move {
+            Some(subscription.next().await)
+        })
+    }
+
+    /// Opens a subscription, sending its request first.
+    async fn connect(&self, request: proto::SubscribeRequest) -> Result<Connection, Error> {
+        let channel = self.endpoint.connect().await?;
+        let mut grpc = tonic::client::Grpc::new(channel);
+        grpc.ready().await?;
+
+        let (requests, receiver) = mpsc::unbounded();
+        // The receiver is alive, so sending can't fail
+        let _ = requests.unbounded_send(request);
+
+        let mut grpc_request = tonic::Request::new(receiver);
+        if let Some(x_token) = &self.x_token {
+            grpc_request
+                .metadata_mut()
+                .insert(X_TOKEN_HEADER, x_token.clone());
+        }
+
+        let codec = ProstCodec::<proto::SubscribeRequest, proto::SubscribeUpdate>::default();
+        let updates = grpc
+            .streaming(
+                grpc_request,
+                PathAndQuery::from_static(proto::SUBSCRIBE_PATH),
+                codec,
+            )
+            .await?
+            .into_inner();
+
+        Ok(Connection { requests, updates })
+    }
+
+    /// Returns the delay before a reconnect attempt, doubling after each failure.
+    fn reconnect_interval(&self, failures: u32) -> Duration {
+        let factor = 2u32.saturating_pow(failures.saturating_sub(1));
+        self.min_reconnect_interval
+            .saturating_mul(factor)
+            .min(self.max_reconnect_interval)
+    }
+}
+
+/// State of a subscription.
+struct Subscription {
+    /// The client opening the connections.
+    client: GrpcClient,
+    /// The subscription request, with `from_slot` set to the last slot received.
+    request: proto::SubscribeRequest,
+    /// Number of consecutive failures, reset when an update is received.
+    failures: u32,
+    /// Identifier of the next ping sent to the server.
+    next_ping_id: i32,
+    /// The open connection, if any.
+    connection: Option<Connection>,
+}
+
+/// An open subscription stream.
+struct Connection {
+    /// Sender of the requests to the server, such as pings.
+    requests: mpsc::UnboundedSender<proto::SubscribeRequest>,
+    /// The updates sent by the server.
+    updates: Streaming<proto::SubscribeUpdate>,
+}
+
+impl Subscription {
+    /// Returns the next update, reconnecting if needed.
+    async fn next(mut self) -> (Result<Update, Error>, Self) {
+        loop {
+            let Some(connection) = self.connection.as_mut() else {
+                if self.failures > 0 {
+                    crate::time::sleep(self.client.reconnect_interval(self.failures)).await;
+                }
+                match self.client.connect(self.request.clone()).await {
+                    Ok(connection) => self.connection = Some(connection),
+                    Err(error) => {
+                        self.failures += 1;
+                        return (Err(error), self);
+                    }
+                }
+                continue;
+            };
+
+            let message =
+                tokio::time::timeout(self.client.ping_interval, connection.updates.message()).await;
+            let update = match message {
+                // No update for a while, check that the connection is still alive
+                Err(_) => {
+                    self.ping();
+                    continue;
+                }
+                Ok(Ok(Some(update))) => update,
+                // The server closed the stream
+                Ok(Ok(None)) => {
+                    self.disconnect();
+                    continue;
+                }
+                Ok(Err(status)) => {
+                    self.disconnect();
+                    return (Err(status.into()), self);
+                }
+            };
+            self.failures = 0;
+
+            let kind = match update.update_oneof {
+                Some(proto::subscribe_update::UpdateOneof::Account(update)) => {
+                    UpdateKind::Account(match update.try_into() {
+                        Ok(update) => update,
+                        Err(error) => return (Err(error), self),
+                    })
+                }
+                Some(proto::subscribe_update::UpdateOneof::Slot(update)) => {
+                    UpdateKind::Slot(update.into())
+                }
+                Some(proto::subscribe_update::UpdateOneof::Transaction(update)) => {
+                    UpdateKind::Transaction(match (*update).try_into() {
+                        Ok(update) => Box::new(update),
+                        Err(error) => return (Err(error), self),
+                    })
+                }
+                Some(proto::subscribe_update::UpdateOneof::BlockMeta(update)) => {
+                    UpdateKind::BlockMeta(update.into())
+                }
+                Some(proto::subscribe_update::UpdateOneof::Ping(_)) => {
+                    self.ping();
+                    continue;
+                }
+                Some(proto::subscribe_update::UpdateOneof::Pong(_)) | None => continue,
+            };
+
+            let update = Update {
+                filters: update.filters,
+                kind,
+            };
+            // Slot updates run ahead of the commitment level, so they don't move the resume point
+            if !matches!(update.kind, UpdateKind::Slot(_)) {
+                self.advance(update.slot());
+            }
+            return (Ok(update), self);
+        }
+    }
+
+    /// Pings the server, with a new identifier.
+    fn ping(&mut self) {
+        let id = self.next_ping_id;
+        self.next_ping_id = self.next_ping_id.wrapping_add(1);
+        if let Some(connection) = &self.connection {
+            // If the request stream was closed, the next read fails and reconnects
+            let _ = connection.requests.unbounded_send(proto::SubscribeRequest {
+                ping: Some(proto::SubscribeRequestPing { id }),
+                ..Default::default()
+            });
+        }
+    }
+
+    /// Records the slot of an update, to resume from it after a reconnect.
+    fn advance(&mut self, slot: u64) {
+        if self
+            .request
+            .from_slot
+            .is_none_or(|from_slot| from_slot < slot)
+        {
+            self.request.from_slot = Some(slot);
+        }
+    }
+
+    /// Drops the connection, so the next update reconnects after a backoff.
+    fn disconnect(&mut self) {
+        self.connection = None;
+        self.failures += 1;
+    }
+}
+
+/// Struct representing a builder for [`GrpcClient`].
+pub struct GrpcClientBuilder {
+    /// The URL of the gRPC endpoint.
+    endpoint: String,
+    /// The access token of the endpoint.
+    x_token: Option<String>,
+    /// Delay without updates after which the server is pinged.
+    ping_interval: Option<Duration>,
+    /// Delay before the first reconnect attempt.
+    min_reconnect_interval: Option<Duration>,
+    /// Maximum delay between two reconnect attempts.
+    max_reconnect_interval: Option<Duration>,
+}
+
+impl std::fmt::Debug for GrpcClientBuilder {
+    /// Formats the builder, redacting the access token like the sensitive value of the client.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("GrpcClientBuilder")
+            .field("endpoint", &self.endpoint)
+            .field("x_token", &self.x_token.as_ref().map(|_| "Sensitive"))
+            .field("ping_interval", &self.ping_interval)
+            .field("min_reconnect_interval", &self.min_reconnect_interval)
+            .field("max_reconnect_interval", &self.max_reconnect_interval)
+            .finish()
+    }
+}
+
+impl GrpcClientBuilder {
+    /// Sets the access token of the endpoint, sent as `x-token`.
+    pub fn x_token(mut self, x_token: &str) -> Self {
+        self.x_token = Some(x_token.to_string());
+        self
+    }
+
+    /// Sets the delay without updates after which the server is pinged, also used as the
+    /// HTTP/2 keepalive interval.
+    pub fn ping_interval(mut self, ping_interval: Duration) -> Self {
+        self.ping_interval = Some(ping_interval);
+        self
+    }
+
+    /// Sets the delay before the first reconnect attempt, doubled after each failure.
+    pub fn min_reconnect_interval(mut self, min_reconnect_interval: Duration) -> Self {
+        self.min_reconnect_interval = Some(min_reconnect_interval);
+        self
+    }
+
+    /// Sets the maximum delay between two reconnect attempts.
+    pub fn max_reconnect_interval(mut self, max_reconnect_interval: Duration) -> Self {
+        self.max_reconnect_interval = Some(max_reconnect_interval);
+        self
+    }
+
+    /// Builds the gRPC client.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the endpoint is not a valid URL or the token is not
+    /// a valid metadata value.
+    pub fn build(self) -> Result<GrpcClient, Error> {
+        let ping_interval = self.ping_interval.unwrap_or(constants::GRPC_PING_INTERVAL);
+
+        let mut endpoint = self
+            .endpoint
+            .parse::<Endpoint>()?
+            .connect_timeout(constants::TIMEOUT)
+            .http2_keep_alive_interval(ping_interval)
+            .keep_alive_timeout(constants::TIMEOUT)
+            .keep_alive_while_idle(true);
+        if endpoint.uri().scheme_str() == Some("https") {
+            endpoint = endpoint.tls_config(ClientTlsConfig::new().with_native_roots())?;
+        }
+
+        let x_token = self
+            .x_token
+            .map(|x_token| {
+                let mut value =
+                    AsciiMetadataValue::try_from(x_token).map_err(|_| Error::InvalidApiKey)?;
+                value.set_sensitive(true);
+                Ok::<_, Error>(value)
+            })
+            .transpose()?;
+
+        Ok(GrpcClient {
+            endpoint,
+            x_token,
+            ping_interval,
+            min_reconnect_interval: self
+                .min_reconnect_interval
+                .unwrap_or(constants::GRPC_MIN_RECONNECT_INTERVAL),
+            max_reconnect_interval: self
+                .max_reconnect_interval
+                .unwrap_or(constants::GRPC_MAX_RECONNECT_INTERVAL),
+        })
+    }
+}
@@ -0,0 +1,495 @@
+//! This module contains the protobuf messages of the Yellowstone gRPC `Geyser` service.
+//!
+//! Only the messages and fields used by [`GrpcClient`](super::GrpcClient) are declared, with the
+//! tags of `geyser.proto` and `solana-storage.proto`. Unknown fields sent by the server are
+//! skipped when decoding. They are public so that a stub `Geyser` server can be written for
+//! tests.
+
+use std::collections::HashMap;
+
+/// Path of the `Subscribe` method of the `Geyser` service.
+pub const SUBSCRIBE_PATH: &str = "/geyser.Geyser/Subscribe";
+
+/// Enum representing the commitment level of a subscription.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, prost::Enumeration)]
+#[repr(i32)]
+pub enum CommitmentLevel {
+    /// The slot was processed by the node.
+    Processed = 0,
+    /// The slot was confirmed by a supermajority of the cluster.
+    Confirmed = 1,
+    /// The slot was finalized by a supermajority of the cluster.
+    Finalized = 2,
+}
+
+/// Enum representing the status of a slot.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, prost::Enumeration)]
+#[repr(i32)]
+pub enum SlotStatus {
+    /// The slot was processed by the node.
+    Processed = 0,
+    /// The slot was confirmed by a supermajority of the cluster.
+    Confirmed = 1,
+    /// The slot was finalized by a supermajority of the cluster.
+    Finalized = 2,
+    /// The first shred of the slot was received.
+    FirstShredReceived = 3,
+    /// Every shred of the slot was received.
+    Completed = 4,
+    /// The bank of the slot was created.
+    CreatedBank = 5,
+    /// The slot was marked dead.
+    Dead = 6,
+}
+
+/// Request sent to open or update a subscription.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct SubscribeRequest {
+    /// Account filters, by name.
+    #[prost(map = "string, message", tag = "1")]
+    pub accounts: HashMap<String, SubscribeRequestFilterAccounts>,
+    /// Slot filters, by name.
+    #[prost(map = "string, message", tag = "2")]
+    pub slots: HashMap<String, SubscribeRequestFilterSlots>,
+    /// Transaction filters, by name.
+    #[prost(map = "string, message", tag = "3")]
+    pub transactions: HashMap<String, SubscribeRequestFilterTransactions>,
+    /// Block metadata filters, by name.
+    #[prost(map = "string, message", tag = "5")]
+    pub blocks_meta: HashMap<String, SubscribeRequestFilterBlocksMeta>,
+    /// Commitment level of the updates.
+    #[prost(enumeration = "CommitmentLevel", optional, tag = "6")]
+    pub commitment: Option<i32>,
+    /// Ping sent to keep the connection alive.
+    #[prost(message, optional, tag = "9")]
+    pub ping: Option<SubscribeRequestPing>,
+    /// Slot to replay the updates from.
+    #[prost(uint64, optional, tag = "11")]
+    pub from_slot: Option<u64>,
+}
+
+/// Filter of account updates.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct SubscribeRequestFilterAccounts {
+    /// Base58-encoded addresses of the accounts.
+    #[prost(string, repeated, tag = "2")]
+    pub account: Vec<String>,
+    /// Base58-encoded addresses of the programs owning the accounts.
+    #[prost(string, repeated, tag = "3")]
+    pub owner: Vec<String>,
+}
+
+/// Filter of slot updates.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct SubscribeRequestFilterSlots {
+    /// Whether to only send the updates of the commitment level of the subscription.
+    #[prost(bool, optional, tag = "1")]
+    pub filter_by_commitment: Option<bool>,
+}
+
+/// Filter of transaction updates.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct SubscribeRequestFilterTransactions {
+    /// Whether to include or exclude vote transactions, or both if unset.
+    #[prost(bool, optional, tag = "1")]
+    pub vote: Option<bool>,
+    /// Whether to include or exclude failed transactions, or both if unset.
+    #[prost(bool, optional, tag = "2")]
+    pub failed: Option<bool>,
+    /// Base58-encoded accounts, one of which must be used by the transactions.
+    #[prost(string, repeated, tag = "3")]
+    pub account_include: Vec<String>,
+    /// Base58-encoded accounts which must not be used by the transactions.
+    #[prost(string, repeated, tag = "4")]
+    pub account_exclude: Vec<String>,
+    /// Base58-encoded signature of a transaction.
+    #[prost(string, optional, tag = "5")]
+    pub signature: Option<String>,
+    /// Base58-encoded accounts, all of which must be used by the transactions.
+    #[prost(string, repeated, tag = "6")]
+    pub account_required: Vec<String>,
+}
+
+/// Filter of block metadata updates.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct SubscribeRequestFilterBlocksMeta {}
+
+/// Ping sent by the client.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct SubscribeRequestPing {
+    /// Identifier echoed in the pong.
+    #[prost(int32, tag = "1")]
+    pub id: i32,
+}
+
+/// Update sent by the server.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct SubscribeUpdate {
+    /// Names of the filters matched by the update.
+    #[prost(string, repeated, tag = "1")]
+    pub filters: Vec<String>,
+    /// The update itself.
+    #[prost(oneof = "subscribe_update::UpdateOneof", tags = "2, 3, 4, 6, 7, 9")]
+    pub update_oneof: Option<subscribe_update::UpdateOneof>,
+}
+
+/// Nested types of [`SubscribeUpdate`].
+pub mod subscribe_update {
+    /// Enum representing the kinds of updates.
+    #[derive(Clone, PartialEq, prost::Oneof)]
+    pub enum UpdateOneof {
+        /// An account was updated.
+        #[prost(message, tag = "2")]
+        Account(super::SubscribeUpdateAccount),
+        /// The status of a slot changed.
+        #[prost(message, tag = "3")]
+        Slot(super::SubscribeUpdateSlot),
+        /// A transaction was processed.
+        #[prost(message, tag = "4")]
+        Transaction(Box<super::SubscribeUpdateTransaction>),
+        /// A ping sent by the server.
+        #[prost(message, tag = "6")]
+        Ping(super::SubscribeUpdatePing),
+        /// The metadata of a block.
+        #[prost(message, tag = "7")]
+        BlockMeta(super::SubscribeUpdateBlockMeta),
+        /// The answer to a ping sent by the client.
+        #[prost(message, tag = "9")]
+        Pong(super::SubscribeUpdatePong),
+    }
+}
+
+/// Account update.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct SubscribeUpdateAccount {
+    /// The updated account.
+    #[prost(message, optional, tag = "1")]
+    pub account: Option<SubscribeUpdateAccountInfo>,
+    /// Slot of the update.
+    #[prost(uint64, tag = "2")]
+    pub slot: u64,
+    /// Whether the update was sent while the node was starting up.
+    #[prost(bool, tag = "3")]
+    pub is_startup: bool,
+}
+
+/// State of an updated account.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct SubscribeUpdateAccountInfo {
+    /// Address of the account.
+    #[prost(bytes = "vec", tag = "1")]
+    pub pubkey: Vec<u8>,
+    /// Balance of the account, in lamports.
+    #[prost(uint64, tag = "2")]
+    pub lamports: u64,
+    /// Program owning the account.
+    #[prost(bytes = "vec", tag = "3")]
+    pub owner: Vec<u8>,
+    /// Whether the account is a program.
+    #[prost(bool, tag = "4")]
+    pub executable: bool,
+    /// Epoch at which the account next owes rent.
+    #[prost(uint64, tag = "5")]
+    pub rent_epoch: u64,
+    /// Data of the account.
+    #[prost(bytes = "vec", tag = "6")]
+    pub data: Vec<u8>,
+    /// Version of the write, ordering the updates of a slot.
+    #[prost(uint64, tag = "7")]
+    pub write_version: u64,
+    /// Signature of the transaction which updated the account, if any.
+    #[prost(bytes = "vec", optional, tag = "8")]
+    pub txn_signature: Option<Vec<u8>>,
+}
+
+/// Slot update.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct SubscribeUpdateSlot {
+    /// The slot.
+    #[prost(uint64, tag = "1")]
+    pub slot: u64,
+    /// Parent of the slot.
+    #[prost(uint64, optional, tag = "2")]
+    pub parent: Option<u64>,
+    /// New status of the slot.
+    #[prost(enumeration = "SlotStatus", tag = "3")]
+    pub status: i32,
+}
+
+/// Transaction update.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct SubscribeUpdateTransaction {
+    /// The transaction.
+    #[prost(message, optional, tag = "1")]
+    pub transaction: Option<SubscribeUpdateTransactionInfo>,
+    /// Slot the transaction was processed in.
+    #[prost(uint64, tag = "2")]
+    pub slot: u64,
+}
+
+/// Processed transaction.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct SubscribeUpdateTransactionInfo {
+    /// First signature of the transaction.
+    #[prost(bytes = "vec", tag = "1")]
+    pub signature: Vec<u8>,
+    /// Whether the transaction is a vote.
+    #[prost(bool, tag = "2")]
+    pub is_vote: bool,
+    /// The signed transaction.
+    #[prost(message, optional, tag = "3")]
+    pub transaction: Option<Transaction>,
+    /// Status metadata of the transaction.
+    #[prost(message, optional, tag = "4")]
+    pub meta: Option<TransactionStatusMeta>,
+    /// Index of the transaction in its block.
+    #[prost(uint64, tag = "5")]
+    pub index: u64,
+}
+
+/// Ping sent by the server.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct SubscribeUpdatePing {}
+
+/// Answer to a ping sent by the client.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct SubscribeUpdatePong {
+    /// Identifier of the ping.
+    #[prost(int32, tag = "1")]
+    pub id: i32,
+}
+
+/// Block metadata update.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct SubscribeUpdateBlockMeta {
+    /// Slot of the block.
+    #[prost(uint64, tag = "1")]
+    pub slot: u64,
+    /// Base58-encoded hash of the block.
+    #[prost(string, tag = "2")]
+    pub blockhash: String,
+    /// Unix timestamp of the block.
+    #[prost(message, optional, tag = "4")]
+    pub block_time: Option<UnixTimestamp>,
+    /// Height of the block.
+    #[prost(message, optional, tag = "5")]
+    pub block_height: Option<BlockHeight>,
+    /// Slot of the parent block.
+    #[prost(uint64, tag = "6")]
+    pub parent_slot: u64,
+    /// Base58-encoded hash of the parent block.
+    #[prost(string, tag = "7")]
+    pub parent_blockhash: String,
+    /// Number of transactions executed in the block.
+    #[prost(uint64, tag = "8")]
+    pub executed_transaction_count: u64,
+}
+
+/// Unix timestamp of a block.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct UnixTimestamp {
+    /// The timestamp, in seconds.
+    #[prost(int64, tag = "1")]
+    pub timestamp: i64,
+}
+
+/// Height of a block.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct BlockHeight {
+    /// The height.
+    #[prost(uint64, tag = "1")]
+    pub block_height: u64,
+}
+
+/// Signed transaction.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Transaction {
+    /// Signatures of the transaction.
+    #[prost(bytes = "vec", repeated, tag = "1")]
+    pub signatures: Vec<Vec<u8>>,
+    /// Message of the transaction.
+    #[prost(message, optional, tag = "2")]
+    pub message: Option<Message>,
+}
+
+/// Transaction message.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Message {
+    /// Message header.
+    #[prost(message, optional, tag = "1")]
+    pub header: Option<MessageHeader>,
+    /// Static account keys of the transaction.
+    #[prost(bytes = "vec", repeated, tag = "2")]
+    pub account_keys: Vec<Vec<u8>>,
+    /// Recent blockhash of the transaction.
+    #[prost(bytes = "vec", tag = "3")]
+    pub recent_blockhash: Vec<u8>,
+    /// Top-level instructions of the transaction.
+    #[prost(message, repeated, tag = "4")]
+    pub instructions: Vec<CompiledInstruction>,
+    /// Whether the message is versioned.
+    #[prost(bool, tag = "5")]
+    pub versioned: bool,
+    /// Address lookup tables used by a versioned message.
+    #[prost(message, repeated, tag = "6")]
+    pub address_table_lookups: Vec<MessageAddressTableLookup>,
+}
+
+/// Header of a transaction message.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct MessageHeader {
+    /// Number of signatures required for the transaction to be valid.
+    #[prost(uint32, tag = "1")]
+    pub num_required_signatures: u32,
+    /// Number of read-only signed accounts.
+    #[prost(uint32, tag = "2")]
+    pub num_readonly_signed_accounts: u32,
+    /// Number of read-only unsigned accounts.
+    #[prost(uint32, tag = "3")]
+    pub num_readonly_unsigned_accounts: u32,
+}
+
+/// Address lookup table used by a versioned message.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct MessageAddressTableLookup {
+    /// Address of the lookup table.
+    #[prost(bytes = "vec", tag = "1")]
+    pub account_key: Vec<u8>,
+    /// Indexes of the writable addresses loaded from the table.
+    #[prost(bytes = "vec", tag = "2")]
+    pub writable_indexes: Vec<u8>,
+    /// Indexes of the read-only addresses loaded from the table.
+    #[prost(bytes = "vec", tag = "3")]
+    pub readonly_indexes: Vec<u8>,
+}
+
+/// Top-level instruction.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct CompiledInstruction {
+    /// Index of the program in the account keys.
+    #[prost(uint32, tag = "1")]
+    pub program_id_index: u32,
+    /// Indexes of the accounts in the account keys.
+    #[prost(bytes = "vec", tag = "2")]
+    pub accounts: Vec<u8>,
+    /// Instruction data.
+    #[prost(bytes = "vec", tag = "3")]
+    pub data: Vec<u8>,
+}
+
+/// Status metadata of a transaction.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct TransactionStatusMeta {
+    /// Error of the transaction, unset if it succeeded.
+    #[prost(message, optional, tag = "1")]
+    pub err: Option<TransactionError>,
+    /// Fee paid by the transaction, in lamports.
+    #[prost(uint64, tag = "2")]
+    pub fee: u64,
+    /// Lamport balances of the accounts before the transaction.
+    #[prost(uint64, repeated, tag = "3")]
+    pub pre_balances: Vec<u64>,
+    /// Lamport balances of the accounts after the transaction.
+    #[prost(uint64, repeated, tag = "4")]
+    pub post_balances: Vec<u64>,
+    /// Inner instructions of the transaction.
+    #[prost(message, repeated, tag = "5")]
+    pub inner_instructions: Vec<InnerInstructions>,
+    /// Log messages of the transaction.
+    #[prost(string, repeated, tag = "6")]
+    pub log_messages: Vec<String>,
+    /// Token balances of the accounts before the transaction.
+    #[prost(message, repeated, tag = "7")]
+    pub pre_token_balances: Vec<TokenBalance>,
+    /// Token balances of the accounts after the transaction.
+    #[prost(message, repeated, tag = "8")]
+    pub post_token_balances: Vec<TokenBalance>,
+    /// Whether the inner instructions were not recorded.
+    #[prost(bool, tag = "10")]
+    pub inner_instructions_none: bool,
+    /// Whether the log messages were not recorded.
+    #[prost(bool, tag = "11")]
+    pub log_messages_none: bool,
+    /// Writable addresses loaded from address lookup tables.
+    #[prost(bytes = "vec", repeated, tag = "12")]
+    pub loaded_writable_addresses: Vec<Vec<u8>>,
+    /// Read-only addresses loaded from address lookup tables.
+    #[prost(bytes = "vec", repeated, tag = "13")]
+    pub loaded_readonly_addresses: Vec<Vec<u8>>,
+    /// Compute units consumed by the transaction.
+    #[prost(uint64, optional, tag = "16")]
+    pub compute_units_consumed: Option<u64>,
+}
+
+/// Error of a failed transaction.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct TransactionError {
+    /// The bincode-serialized `TransactionError`.
+    #[prost(bytes = "vec", tag = "1")]
+    pub err: Vec<u8>,
+}
+
+/// Inner instructions invoked by a top-level instruction.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct InnerInstructions {
+    /// Index of the top-level instruction.
+    #[prost(uint32, tag = "1")]
+    pub index: u32,
+    /// Instructions invoked by the top-level instruction.
+    #[prost(message, repeated, tag = "2")]
+    pub instructions: Vec<InnerInstruction>,
+}
+
+/// Inner instruction.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct InnerInstruction {
+    /// Index of the program in the account keys.
+    #[prost(uint32, tag = "1")]
+    pub program_id_index: u32,
+    /// Indexes of the accounts in the account keys.
+    #[prost(bytes = "vec", tag = "2")]
+    pub accounts: Vec<u8>,
+    /// Instruction data.
+    #[prost(bytes = "vec", tag = "3")]
+    pub data: Vec<u8>,
+    /// Invocation depth of the instruction.
+    #[prost(uint32, optional, tag = "4")]
+    pub stack_height: Option<u32>,
+}
+
+/// Balance of a token account.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct TokenBalance {
+    /// Index of the token account in the account keys.
+    #[prost(uint32, tag = "1")]
+    pub account_index: u32,
+    /// Base58-encoded address of the token.
+    #[prost(string, tag = "2")]
+    pub mint: String,
+    /// Balance of the token account.
+    #[prost(message, optional, tag = "3")]
+    pub ui_token_amount: Option<UiTokenAmount>,
+    /// Base58-encoded owner of the token account, empty if unknown.
+    #[prost(string, tag = "4")]
+    pub owner: String,
+    /// Base58-encoded token program owning the token account, empty if unknown.
+    #[prost(string, tag = "5")]
+    pub program_id: String,
+}
+
+/// Token amount.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct UiTokenAmount {
+    /// Amount of tokens, as a float.
+    #[prost(double, tag = "1")]
+    pub ui_amount: f64,
+    /// Decimals of the token.
+    #[prost(uint32, tag = "2")]
+    pub decimals: u32,
+    /// Raw amount of tokens.
+    #[prost(string, tag = "3")]
+    pub amount: String,
+    /// Amount of tokens, as a string.
+    #[prost(string, tag = "4")]
+    pub ui_amount_string: String,
+}
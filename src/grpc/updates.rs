@@ -0,0 +1,387 @@
+//! This module contains the typed updates of a gRPC subscription and their conversion from the
+//! protobuf messages.
+
+use std::str::FromStr;
+
+use super::proto::{self, SlotStatus};
+use crate::{
+    models::raw_transaction::{
+        AccountKey, AddressTableLookup, CompiledInstruction, InnerInstructions, Instruction,
+        Legacy, LoadedAddresses, Message, MessageHeader, RawTransaction, TokenBalance, Transaction,
+        TransactionMeta, TransactionVersion, UiTokenAmount,
+    },
+    Decimal, Error, Pubkey, Signature,
+};
+
+/// Struct representing an update of a gRPC subscription.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Update {
+    /// Names of the filters matched by the update.
+    pub filters: Vec<String>,
+    /// The update itself.
+    pub kind: UpdateKind,
+}
+
+impl Update {
+    /// Returns the slot of the update.
+    pub fn slot(&self) -> u64 {
+        match &self.kind {
+            UpdateKind::Account(update) => update.slot,
+            UpdateKind::Slot(update) => update.slot,
+            UpdateKind::Transaction(update) => update.slot,
+            UpdateKind::BlockMeta(update) => update.slot,
+        }
+    }
+}
+
+/// Enum representing the kinds of updates of a gRPC subscription.
+#[derive(Debug, Clone, PartialEq)]
+pub enum UpdateKind {
+    /// An account matching an [`AccountFilter`](super::AccountFilter) was updated.
+    Account(AccountUpdate),
+    /// The status of a slot changed.
+    Slot(SlotUpdate),
+    /// A transaction matching a [`TransactionFilter`](super::TransactionFilter) was processed.
+    Transaction(Box<TransactionUpdate>),
+    /// The metadata of a block.
+    BlockMeta(BlockMetaUpdate),
+}
+
+/// Struct representing an account update.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AccountUpdate {
+    /// Slot of the update.
+    pub slot: u64,
+    /// Address of the account.
+    pub pubkey: Pubkey,
+    /// Balance of the account, in lamports.
+    pub lamports: u64,
+    /// Program owning the account.
+    pub owner: Pubkey,
+    /// Whether the account is a program.
+    pub executable: bool,
+    /// Epoch at which the account next owes rent.
+    pub rent_epoch: u64,
+    /// Data of the account.
+    pub data: Vec<u8>,
+    /// Version of the write, ordering the updates of a slot.
+    pub write_version: u64,
+    /// Signature of the transaction which updated the account, if any.
+    pub txn_signature: Option<Signature>,
+    /// Whether the update was sent while the node was starting up.
+    pub is_startup: bool,
+}
+
+/// Struct representing a slot update.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SlotUpdate {
+    /// The slot.
+    pub slot: u64,
+    /// Parent of the slot.
+    pub parent: Option<u64>,
+    /// New status of the slot.
+    pub status: SlotStatus,
+}
+
+/// Struct representing a transaction update.
+///
+/// The transaction is converted into a [`RawTransaction`], as returned by Shyft with
+/// `enable_raw`, in the `json` encoding. Its `block_time` is unknown. The error of a failed
+/// transaction is kept as its bincode-serialized bytes, in a JSON array.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TransactionUpdate {
+    /// Slot the transaction was processed in.
+    pub slot: u64,
+    /// First signature of the transaction.
+    pub signature: Signature,
+    /// Whether the transaction is a vote.
+    pub is_vote: bool,
+    /// Index of the transaction in its block.
+    pub index: u64,
+    /// The transaction.
+    pub transaction: RawTransaction,
+}
+
+/// Struct representing a block metadata update.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BlockMetaUpdate {
+    /// Slot of the block.
+    pub slot: u64,
+    /// Base58-encoded hash of the block.
+    pub blockhash: String,
+    /// Unix timestamp of the block.
+    pub block_time: Option<i64>,
+    /// Height of the block.
+    pub block_height: Option<u64>,
+    /// Slot of the parent block.
+    pub parent_slot: u64,
+    /// Base58-encoded hash of the parent block.
+    pub parent_blockhash: String,
+    /// Number of transactions executed in the block.
+    pub executed_transaction_count: u64,
+}
+
+impl TryFrom<proto::SubscribeUpdateAccount> for AccountUpdate {
+    type Error = Error;
+
+    fn try_from(update: proto::SubscribeUpdateAccount) -> Result<Self, Self::Error> {
+        let account = update
+            .account
+            .ok_or_else(|| invalid_update("account update without account"))?;
+
+        Ok(Self {
+            slot: update.slot,
+            pubkey: pubkey(&account.pubkey)?,
+            lamports: account.lamports,
+            owner: pubkey(&account.owner)?,
+            executable: account.executable,
+            rent_epoch: account.rent_epoch,
+            data: account.data,
+            write_version: account.write_version,
+            txn_signature: account
+                .txn_signature
+                .as_deref()
+                .map(signature)
+                .transpose()?,
+            is_startup: update.is_startup,
+        })
+    }
+}
+
+impl From<proto::SubscribeUpdateSlot> for SlotUpdate {
+    fn from(update: proto::SubscribeUpdateSlot) -> Self {
+        Self {
+            slot: update.slot,
+            parent: update.parent,
+            status: SlotStatus::try_from(update.status).unwrap_or(SlotStatus::Processed),
+        }
+    }
+}
+
+impl TryFrom<proto::SubscribeUpdateTransaction> for TransactionUpdate {
+    type Error = Error;
+
+    fn try_from(update: proto::SubscribeUpdateTransaction) -> Result<Self, Self::Error> {
+        let info = update
+            .transaction
+            .ok_or_else(|| invalid_update("transaction update without transaction"))?;
+        let transaction = info
+            .transaction
+            .ok_or_else(|| invalid_update("transaction update without transaction"))?;
+        let message = transaction
+            .message
+            .ok_or_else(|| invalid_update("transaction without message"))?;
+
+        let version = if message.versioned {
+            TransactionVersion::Number(0)
+        } else {
+            TransactionVersion::Legacy(Legacy::Legacy)
+        };
+
+        let transaction = RawTransaction {
+            block_time: None,
+            slot: update.slot,
+            meta: info.meta.map(convert_meta).transpose()?,
+            transaction: Transaction {
+                signatures: transaction
+                    .signatures
+                    .iter()
+                    .map(|bytes| signature(bytes))
+                    .collect::<Result<_, _>>()?,
+                message: convert_message(message)?,
+            },
+            version: Some(version),
+        };
+
+        Ok(Self {
+            slot: update.slot,
+            signature: signature(&info.signature)?,
+            is_vote: info.is_vote,
+            index: info.index,
+            transaction,
+        })
+    }
+}
+
+impl From<proto::SubscribeUpdateBlockMeta> for BlockMetaUpdate {
+    fn from(update: proto::SubscribeUpdateBlockMeta) -> Self {
+        Self {
+            slot: update.slot,
+            blockhash: update.blockhash,
+            block_time: update.block_time.map(|block_time| block_time.timestamp),
+            block_height: update
+                .block_height
+                .map(|block_height| block_height.block_height),
+            parent_slot: update.parent_slot,
+            parent_blockhash: update.parent_blockhash,
+            executed_transaction_count: update.executed_transaction_count,
+        }
+    }
+}
+
+/// Converts a transaction message.
+fn convert_message(message: proto::Message) -> Result<Message, Error> {
+    let header = message
+        .header
+        .map(|header| {
+            Ok::<_, Error>(MessageHeader {
+                num_required_signatures: index(header.num_required_signatures)?,
+                num_readonly_signed_accounts: index(header.num_readonly_signed_accounts)?,
+                num_readonly_unsigned_accounts: index(header.num_readonly_unsigned_accounts)?,
+            })
+        })
+        .transpose()?;
+
+    let address_table_lookups = if message.versioned {
+        Some(
+            message
+                .address_table_lookups
+                .into_iter()
+                .map(|lookup| {
+                    Ok::<_, Error>(AddressTableLookup {
+                        account_key: pubkey(&lookup.account_key)?,
+                        writable_indexes: lookup.writable_indexes,
+                        readonly_indexes: lookup.readonly_indexes,
+                    })
+                })
+                .collect::<Result<_, _>>()?,
+        )
+    } else {
+        None
+    };
+
+    Ok(Message {
+        header,
+        account_keys: message
+            .account_keys
+            .iter()
+            .map(|bytes| pubkey(bytes).map(AccountKey::Plain))
+            .collect::<Result<_, _>>()?,
+        recent_blockhash: bs58::encode(message.recent_blockhash).into_string(),
+        instructions: message
+            .instructions
+            .into_iter()
+            .map(|instruction| {
+                Ok::<_, Error>(Instruction::Compiled(CompiledInstruction {
+                    program_id_index: index(instruction.program_id_index)?,
+                    accounts: instruction.accounts,
+                    data: bs58::encode(instruction.data).into_string(),
+                    stack_height: None,
+                }))
+            })
+            .collect::<Result<_, _>>()?,
+        address_table_lookups,
+    })
+}
+
+/// Converts the status metadata of a transaction.
+fn convert_meta(meta: proto::TransactionStatusMeta) -> Result<TransactionMeta, Error> {
+    let inner_instructions = if meta.inner_instructions_none {
+        None
+    } else {
+        Some(
+            meta.inner_instructions
+                .into_iter()
+                .map(|inner| {
+                    Ok::<_, Error>(InnerInstructions {
+                        index: index(inner.index)?,
+                        instructions: inner
+                            .instructions
+                            .into_iter()
+                            .map(|instruction| {
+                                Ok::<_, Error>(Instruction::Compiled(CompiledInstruction {
+                                    program_id_index: index(instruction.program_id_index)?,
+                                    accounts: instruction.accounts,
+                                    data: bs58::encode(instruction.data).into_string(),
+                                    stack_height: instruction.stack_height,
+                                }))
+                            })
+                            .collect::<Result<_, _>>()?,
+                    })
+                })
+                .collect::<Result<_, _>>()?,
+        )
+    };
+
+    let loaded_addresses = LoadedAddresses {
+        writable: meta
+            .loaded_writable_addresses
+            .iter()
+            .map(|bytes| pubkey(bytes))
+            .collect::<Result<_, _>>()?,
+        readonly: meta
+            .loaded_readonly_addresses
+            .iter()
+            .map(|bytes| pubkey(bytes))
+            .collect::<Result<_, _>>()?,
+    };
+
+    Ok(TransactionMeta {
+        err: meta.err.map(|err| serde_json::Value::from(err.err)),
+        fee: meta.fee,
+        pre_balances: meta.pre_balances,
+        post_balances: meta.post_balances,
+        inner_instructions,
+        pre_token_balances: Some(convert_token_balances(meta.pre_token_balances)?),
+        post_token_balances: Some(convert_token_balances(meta.post_token_balances)?),
+        log_messages: (!meta.log_messages_none).then_some(meta.log_messages),
+        loaded_addresses: Some(loaded_addresses),
+        compute_units_consumed: meta.compute_units_consumed,
+    })
+}
+
+/// Converts the token balances of a transaction.
+fn convert_token_balances(balances: Vec<proto::TokenBalance>) -> Result<Vec<TokenBalance>, Error> {
+    balances
+        .into_iter()
+        .map(|balance| {
+            let amount = balance
+                .ui_token_amount
+                .ok_or_else(|| invalid_update("token balance without amount"))?;
+
+            Ok(TokenBalance {
+                account_index: index(balance.account_index)?,
+                mint: balance.mint.parse()?,
+                owner: optional_pubkey(&balance.owner)?,
+                program_id: optional_pubkey(&balance.program_id)?,
+                ui_token_amount: UiTokenAmount {
+                    amount: amount.amount.parse().map_err(|_| {
+                        invalid_update(format!("invalid token amount {}", amount.amount))
+                    })?,
+                    decimals: index(amount.decimals)?,
+                    ui_amount: Decimal::from_str(&amount.ui_amount_string).ok(),
+                    ui_amount_string: amount.ui_amount_string,
+                },
+            })
+        })
+        .collect()
+}
+
+/// Converts raw bytes into an address.
+fn pubkey(bytes: &[u8]) -> Result<Pubkey, Error> {
+    <[u8; 32]>::try_from(bytes)
+        .map(Pubkey::new)
+        .map_err(|_| Error::InvalidPubkey(bs58::encode(bytes).into_string()))
+}
+
+/// Parses a base58-encoded address, empty if unknown.
+fn optional_pubkey(s: &str) -> Result<Option<Pubkey>, Error> {
+    (!s.is_empty()).then(|| s.parse()).transpose()
+}
+
+/// Converts raw bytes into a transaction signature.
+fn signature(bytes: &[u8]) -> Result<Signature, Error> {
+    <[u8; 64]>::try_from(bytes)
+        .map(Signature::new)
+        .map_err(|_| Error::InvalidSignature(bs58::encode(bytes).into_string()))
+}
+
+/// Converts an index or count into the byte it fits in on chain.
+fn index(value: u32) -> Result<u8, Error> {
+    u8::try_from(value).map_err(|_| invalid_update(format!("index {value} out of range")))
+}
+
+/// Returns an error for an update that cannot be converted.
+fn invalid_update(reason: impl Into<String>) -> Error {
+    Error::InvalidUpdate(reason.into())
+}
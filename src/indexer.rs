@@ -0,0 +1,598 @@
+//! This module persists the transaction histories of wallets into a SQLite database.
+//!
+//! An [`Indexer`] stores each [`ParsedTransactionDetails`] once, keyed by its signature, along
+//! with its [`ActionRow`]s, so analytics can query the history without fetching it again.
+//! Wallets are synced incrementally: each wallet has a [`Checkpoint`] holding the newest and
+//! oldest transactions stored, and a sync only fetches the transactions made since the newest
+//! one, with `until_tx_signature`, then backfills the history older than the oldest one, with
+//! `before_tx_signature`, until it is complete. The backfill is committed page by page, so an
+//! interrupted sync resumes where it stopped.
+//!
+//! Stored transactions can be queried by wallet, time range, action type and mint with an
+//! [`IndexQuery`].
+//!
+//! Requires the `indexer` feature.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), shyft_rs_sdk::Error> {
+//! # use shyft_rs_sdk::ShyftApi;
+//! use shyft_rs_sdk::{
+//!     indexer::{IndexQuery, Indexer},
+//!     models::parsed_transaction_details::TxType,
+//! };
+//! #
+//! # let api_key = "your_api_key";
+//! # let client = ShyftApi::new(api_key, None, None, None, None, None).unwrap();
+//!
+//! let mut indexer = Indexer::open("history.sqlite")?;
+//! indexer.track_wallet(&"8R5brRqNa1CDMtcQRaLPQfJeLBrtyqpjDPTSKbBvmsna".parse()?)?;
+//!
+//! // Fetches only the transactions not stored yet
+//! let inserted = indexer.sync(&client, None, None).await?;
+//! println!("{} new transactions", inserted);
+//!
+//! let swaps = indexer.actions(&IndexQuery::new().action_type(TxType::Swap))?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::path::Path;
+
+use chrono::{DateTime, Utc};
+use rusqlite::{params, params_from_iter, types::Value, Connection, OptionalExtension};
+
+use crate::{
+    constants,
+    export::ActionRow,
+    models::parsed_transaction_details::{ParsedTransactionDetails, TxType},
+    requests::TransactionHistoryRequest,
+    Pubkey, ShyftApi, Signature,
+};
+
+/// Schema of the database, created when it is opened.
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS transactions (
+    signature TEXT PRIMARY KEY,
+    block_time INTEGER NOT NULL,
+    transaction_type TEXT NOT NULL,
+    details TEXT NOT NULL
+);
+CREATE INDEX IF NOT EXISTS transactions_block_time ON transactions (block_time);
+
+CREATE TABLE IF NOT EXISTS actions (
+    signature TEXT NOT NULL REFERENCES transactions (signature),
+    row_index INTEGER NOT NULL,
+    block_time INTEGER NOT NULL,
+    action_type TEXT,
+    token_in_address TEXT,
+    token_out_address TEXT,
+    details TEXT NOT NULL,
+    PRIMARY KEY (signature, row_index)
+);
+CREATE INDEX IF NOT EXISTS actions_block_time ON actions (block_time);
+CREATE INDEX IF NOT EXISTS actions_action_type ON actions (action_type);
+CREATE INDEX IF NOT EXISTS actions_token_in_address ON actions (token_in_address);
+CREATE INDEX IF NOT EXISTS actions_token_out_address ON actions (token_out_address);
+
+CREATE TABLE IF NOT EXISTS wallets (
+    wallet TEXT PRIMARY KEY,
+    newest_signature TEXT,
+    oldest_signature TEXT,
+    backfilled INTEGER NOT NULL DEFAULT 0
+);
+
+CREATE TABLE IF NOT EXISTS wallet_transactions (
+    wallet TEXT NOT NULL,
+    signature TEXT NOT NULL REFERENCES transactions (signature),
+    PRIMARY KEY (wallet, signature)
+);
+CREATE INDEX IF NOT EXISTS wallet_transactions_signature ON wallet_transactions (signature);
+";
+
+/// Struct representing the sync progress of a tracked wallet.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Checkpoint {
+    /// The newest transaction stored, which the next sync fetches the transactions after.
+    pub newest: Option<Signature>,
+    /// The oldest transaction stored, which the backfill resumes before.
+    pub oldest: Option<Signature>,
+    /// Whether the history older than the oldest transaction was fully stored.
+    pub backfilled: bool,
+}
+
+/// Struct representing a query of the stored transactions or actions.
+///
+/// Every filter is optional, and the filters set must all match. Results are sorted newest
+/// first.
+#[derive(Debug, Clone, Default)]
+pub struct IndexQuery {
+    /// The wallet the transactions belong to.
+    wallet: Option<Pubkey>,
+    /// The earliest time of the transactions, inclusive.
+    from: Option<DateTime<Utc>>,
+    /// The latest time of the transactions, exclusive.
+    until: Option<DateTime<Utc>>,
+    /// The type of the actions.
+    action_type: Option<TxType>,
+    /// The token sent, received or minted by the actions.
+    mint: Option<Pubkey>,
+    /// The maximum number of results.
+    limit: Option<u32>,
+}
+
+impl IndexQuery {
+    /// Creates a query matching every stored transaction.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Matches only the transactions of a wallet.
+    pub fn wallet(mut self, wallet: Pubkey) -> Self {
+        self.wallet = Some(wallet);
+        self
+    }
+
+    /// Matches only the transactions made at or after a time.
+    pub fn from(mut self, from: DateTime<Utc>) -> Self {
+        self.from = Some(from);
+        self
+    }
+
+    /// Matches only the transactions made before a time.
+    pub fn until(mut self, until: DateTime<Utc>) -> Self {
+        self.until = Some(until);
+        self
+    }
+
+    /// Matches only the actions of a type.
+    pub fn action_type(mut self, action_type: TxType) -> Self {
+        self.action_type = Some(action_type);
+        self
+    }
+
+    /// Matches only the actions sending, receiving or minting a token.
+    pub fn mint(mut self, mint: Pubkey) -> Self {
+        self.mint = Some(mint);
+        self
+    }
+
+    /// Limits the number of results.
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Returns the conditions on the transaction columns of `table`, with their parameters.
+    fn transaction_conditions(&self, table: &str, values: &mut Vec<Value>) -> Vec<String> {
+        let mut conditions = Vec::new();
+        if let Some(wallet) = self.wallet {
+            conditions.push(format!(
+                "{}.signature IN (SELECT signature FROM wallet_transactions WHERE wallet = ?)",
+                table
+            ));
+            values.push(Value::Text(wallet.to_string()));
+        }
+        if let Some(from) = self.from {
+            conditions.push(format!("{}.block_time >= ?", table));
+            values.push(Value::Integer(from.timestamp()));
+        }
+        if let Some(until) = self.until {
+            conditions.push(format!("{}.block_time < ?", table));
+            values.push(Value::Integer(until.timestamp()));
+        }
+        conditions
+    }
+
+    /// Returns the conditions on the columns of `actions`, with their parameters.
+    fn action_conditions(&self, values: &mut Vec<Value>) -> Vec<String> {
+        let mut conditions = Vec::new();
+        if let Some(action_type) = &self.action_type {
+            conditions.push("actions.action_type = ?".to_string());
+            values.push(Value::Text(action_type.to_string()));
+        }
+        if let Some(mint) = self.mint {
+            conditions.push(
+                "(actions.token_in_address = ? OR actions.token_out_address = ?)".to_string(),
+            );
+            values.push(Value::Text(mint.to_string()));
+            values.push(Value::Text(mint.to_string()));
+        }
+        conditions
+    }
+
+    /// Returns a SQL query made of `select`, the conditions, `order` and the limit.
+    fn sql(
+        &self,
+        select: &str,
+        conditions: &[String],
+        order: &str,
+        values: &mut Vec<Value>,
+    ) -> String {
+        let mut sql = select.to_string();
+        if !conditions.is_empty() {
+            sql.push_str(" WHERE ");
+            sql.push_str(&conditions.join(" AND "));
+        }
+        sql.push_str(" ORDER BY ");
+        sql.push_str(order);
+        if let Some(limit) = self.limit {
+            sql.push_str(" LIMIT ?");
+            values.push(Value::Integer(limit.into()));
+        }
+        sql
+    }
+}
+
+/// Struct representing a SQLite store of wallet histories.
+#[derive(Debug)]
+pub struct Indexer {
+    /// The connection to the SQLite database.
+    connection: Connection,
+}
+
+impl Indexer {
+    /// Opens the database at `path`, creating it if it does not exist.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the database cannot be opened or its schema
+    /// cannot be created.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, crate::error::Error> {
+        Self::with_connection(Connection::open(path)?)
+    }
+
+    /// Opens a database in memory, dropped with the indexer.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the schema cannot be created.
+    pub fn open_in_memory() -> Result<Self, crate::error::Error> {
+        Self::with_connection(Connection::open_in_memory()?)
+    }
+
+    /// Creates the schema of the database if needed.
+    fn with_connection(connection: Connection) -> Result<Self, crate::error::Error> {
+        connection.execute_batch(SCHEMA)?;
+        Ok(Self { connection })
+    }
+
+    /// Tracks a wallet, so that [`Indexer::sync`] syncs its history.
+    ///
+    /// Tracking a wallet again keeps its checkpoint.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the database cannot be written.
+    pub fn track_wallet(&self, wallet: &Pubkey) -> Result<(), crate::error::Error> {
+        self.connection.execute(
+            "INSERT OR IGNORE INTO wallets (wallet) VALUES (?1)",
+            params![wallet.to_string()],
+        )?;
+        Ok(())
+    }
+
+    /// Returns the tracked wallets.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the database cannot be read.
+    pub fn tracked_wallets(&self) -> Result<Vec<Pubkey>, crate::error::Error> {
+        let mut statement = self
+            .connection
+            .prepare("SELECT wallet FROM wallets ORDER BY wallet")?;
+        let wallets = statement
+            .query_map([], |row| row.get::<_, String>(0))?
+            .collect::<Result<Vec<_>, _>>()?;
+        wallets.iter().map(|wallet| wallet.parse()).collect()
+    }
+
+    /// Returns the checkpoint of a wallet, or `None` if the wallet is not tracked.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the database cannot be read.
+    pub fn checkpoint(&self, wallet: &Pubkey) -> Result<Option<Checkpoint>, crate::error::Error> {
+        let row = self
+            .connection
+            .query_row(
+                "SELECT newest_signature, oldest_signature, backfilled FROM wallets WHERE wallet = ?1",
+                params![wallet.to_string()],
+                |row| {
+                    Ok((
+                        row.get::<_, Option<String>>(0)?,
+                        row.get::<_, Option<String>>(1)?,
+                        row.get::<_, bool>(2)?,
+                    ))
+                },
+            )
+            .optional()?;
+
+        row.map(|(newest, oldest, backfilled)| {
+            Ok(Checkpoint {
+                newest: newest.map(|newest| newest.parse()).transpose()?,
+                oldest: oldest.map(|oldest| oldest.parse()).transpose()?,
+                backfilled,
+            })
+        })
+        .transpose()
+    }
+
+    /// Stores transactions of a wallet, skipping the transactions already stored.
+    ///
+    /// Checkpoints are left untouched, so this can store transactions received from other
+    /// sources, such as a [watcher](crate::ShyftApi::watch_account).
+    ///
+    /// # Arguments
+    ///
+    /// * `wallet` - The wallet the transactions belong to.
+    /// * `transactions` - The transactions to store.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if a timestamp cannot be parsed or the database
+    /// cannot be written. No transaction is stored then.
+    pub fn insert(
+        &mut self,
+        wallet: &Pubkey,
+        transactions: &[ParsedTransactionDetails],
+    ) -> Result<usize, crate::error::Error> {
+        let tx = self.connection.transaction()?;
+        let inserted = insert_transactions(&tx, wallet, transactions)?;
+        tx.commit()?;
+        Ok(inserted)
+    }
+
+    /// Syncs the history of every tracked wallet. See [`Indexer::sync_wallet`].
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - The client fetching the transaction histories.
+    /// * `enable_raw` - An optional flag to enable raw transaction details.
+    /// * `enable_events` - An optional flag to enable events in the transaction details.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if a wallet fails to sync. The wallets synced before
+    /// it keep their progress.
+    pub async fn sync(
+        &mut self,
+        client: &ShyftApi,
+        enable_raw: Option<bool>,
+        enable_events: Option<bool>,
+    ) -> Result<usize, crate::error::Error> {
+        let mut inserted = 0;
+        for wallet in self.tracked_wallets()? {
+            inserted += self
+                .sync_wallet(client, wallet, enable_raw, enable_events)
+                .await?;
+        }
+        Ok(inserted)
+    }
+
+    /// Syncs the history of a wallet, tracking it if needed, and returns the number of
+    /// transactions stored.
+    ///
+    /// The transactions made since the newest transaction stored are fetched first, then the
+    /// history older than the oldest transaction stored is backfilled, unless it is complete.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - The client fetching the transaction history.
+    /// * `wallet` - The wallet to sync.
+    /// * `enable_raw` - An optional flag to enable raw transaction details.
+    /// * `enable_events` - An optional flag to enable events in the transaction details.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if a page of the history cannot be fetched or
+    /// stored. The pages of the backfill stored before it are kept.
+    pub async fn sync_wallet(
+        &mut self,
+        client: &ShyftApi,
+        wallet: Pubkey,
+        enable_raw: Option<bool>,
+        enable_events: Option<bool>,
+    ) -> Result<usize, crate::error::Error> {
+        self.track_wallet(&wallet)?;
+        let checkpoint = self.checkpoint(&wallet)?.unwrap_or_default();
+        let page = |until: Option<Signature>, before: Option<Signature>| {
+            let mut request = TransactionHistoryRequest::new(wallet).tx_num(constants::MAX_TX_NUM);
+            if let Some(until) = until {
+                request = request.until_tx_signature(until);
+            }
+            if let Some(before) = before {
+                request = request.before_tx_signature(before);
+            }
+            if let Some(enable_raw) = enable_raw {
+                request = request.enable_raw(enable_raw);
+            }
+            if let Some(enable_events) = enable_events {
+                request = request.enable_events(enable_events);
+            }
+            async move { client.execute(&request).await }
+        };
+        let mut inserted = 0;
+
+        // Transactions made since the last sync, stored at once so that no gap is left
+        if checkpoint.newest.is_some() || checkpoint.backfilled {
+            let mut new_transactions = Vec::new();
+            let mut before = None;
+            loop {
+                let transactions = page(checkpoint.newest, before).await?;
+                let full = transactions.len() >= constants::MAX_TX_NUM as usize;
+                before = transactions.last().and_then(first_signature);
+                new_transactions.extend(transactions);
+
+                // A page shorter than the maximum is the last one
+                if !full || before.is_none() {
+                    break;
+                }
+            }
+
+            let tx = self.connection.transaction()?;
+            inserted += insert_transactions(&tx, &wallet, &new_transactions)?;
+            if let Some(newest) = new_transactions.first().and_then(first_signature) {
+                tx.execute(
+                    "UPDATE wallets SET newest_signature = ?2 WHERE wallet = ?1",
+                    params![wallet.to_string(), newest.to_string()],
+                )?;
+            }
+            tx.commit()?;
+        }
+
+        // Older transactions, stored page by page so that the backfill can resume
+        let mut before = checkpoint.oldest;
+        let mut backfilled = checkpoint.backfilled;
+        while !backfilled {
+            let transactions = page(None, before).await?;
+            let full = transactions.len() >= constants::MAX_TX_NUM as usize;
+            before = transactions.last().and_then(first_signature).or(before);
+            backfilled = !full || transactions.is_empty();
+
+            let tx = self.connection.transaction()?;
+            inserted += insert_transactions(&tx, &wallet, &transactions)?;
+            tx.execute(
+                "UPDATE wallets SET newest_signature = COALESCE(newest_signature, ?2),
+                    oldest_signature = ?3, backfilled = ?4 WHERE wallet = ?1",
+                params![
+                    wallet.to_string(),
+                    transactions
+                        .first()
+                        .and_then(first_signature)
+                        .map(|newest| newest.to_string()),
+                    before.map(|oldest| oldest.to_string()),
+                    backfilled,
+                ],
+            )?;
+            tx.commit()?;
+        }
+
+        Ok(inserted)
+    }
+
+    /// Returns the stored transactions having an action matching a query.
+    ///
+    /// Without an action type or mint filter, transactions without actions match too.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the database cannot be read.
+    pub fn transactions(
+        &self,
+        query: &IndexQuery,
+    ) -> Result<Vec<ParsedTransactionDetails>, crate::error::Error> {
+        let mut values = Vec::new();
+        let mut conditions = query.transaction_conditions("transactions", &mut values);
+        let action_conditions = query.action_conditions(&mut values);
+        if !action_conditions.is_empty() {
+            conditions.push(format!(
+                "EXISTS (SELECT 1 FROM actions WHERE actions.signature = transactions.signature AND {})",
+                action_conditions.join(" AND ")
+            ));
+        }
+        let sql = query.sql(
+            "SELECT details FROM transactions",
+            &conditions,
+            "block_time DESC, signature",
+            &mut values,
+        );
+
+        self.query_json(&sql, values)
+    }
+
+    /// Returns the stored actions matching a query, flattened with their transaction.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the database cannot be read.
+    pub fn actions(&self, query: &IndexQuery) -> Result<Vec<ActionRow>, crate::error::Error> {
+        let mut values = Vec::new();
+        let mut conditions = query.transaction_conditions("actions", &mut values);
+        conditions.extend(query.action_conditions(&mut values));
+        let sql = query.sql(
+            "SELECT details FROM actions",
+            &conditions,
+            "block_time DESC, signature, row_index",
+            &mut values,
+        );
+
+        self.query_json(&sql, values)
+    }
+
+    /// Runs a query selecting JSON documents, and deserializes them.
+    fn query_json<T: serde::de::DeserializeOwned>(
+        &self,
+        sql: &str,
+        values: Vec<Value>,
+    ) -> Result<Vec<T>, crate::error::Error> {
+        let mut statement = self.connection.prepare(sql)?;
+        let documents = statement
+            .query_map(params_from_iter(values), |row| row.get::<_, String>(0))?
+            .collect::<Result<Vec<_>, _>>()?;
+        documents
+            .iter()
+            .map(|document| Ok(serde_json::from_str(document)?))
+            .collect()
+    }
+}
+
+/// Returns the signature identifying a transaction.
+fn first_signature(transaction: &ParsedTransactionDetails) -> Option<Signature> {
+    transaction.signatures.first().copied()
+}
+
+/// Stores transactions of a wallet, with their actions, and returns the number of new ones.
+fn insert_transactions(
+    tx: &rusqlite::Transaction<'_>,
+    wallet: &Pubkey,
+    transactions: &[ParsedTransactionDetails],
+) -> Result<usize, crate::error::Error> {
+    let mut insert_transaction = tx.prepare_cached(
+        "INSERT OR IGNORE INTO transactions (signature, block_time, transaction_type, details)
+            VALUES (?1, ?2, ?3, ?4)",
+    )?;
+    let mut insert_action = tx.prepare_cached(
+        "INSERT INTO actions (signature, row_index, block_time, action_type, token_in_address,
+            token_out_address, details) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+    )?;
+    let mut insert_wallet_transaction = tx.prepare_cached(
+        "INSERT OR IGNORE INTO wallet_transactions (wallet, signature) VALUES (?1, ?2)",
+    )?;
+    let mut inserted = 0;
+
+    for transaction in transactions {
+        // Transactions are deduplicated by signature, so one without can't be stored
+        let Some(signature) = first_signature(transaction) else {
+            continue;
+        };
+        let signature = signature.to_string();
+        let block_time = transaction.timestamp_utc()?.timestamp();
+
+        let new = insert_transaction.execute(params![
+            signature,
+            block_time,
+            transaction.transaction_type.to_string(),
+            serde_json::to_string(transaction)?,
+        ])? > 0;
+        if new {
+            for (row, row_index) in ActionRow::from_transaction(transaction).iter().zip(0..) {
+                insert_action.execute(params![
+                    signature,
+                    row_index,
+                    block_time,
+                    row.action_type.as_ref().map(ToString::to_string),
+                    row.token_in_address.map(|address| address.to_string()),
+                    row.token_out_address.map(|address| address.to_string()),
+                    serde_json::to_string(row)?,
+                ])?;
+            }
+            inserted += 1;
+        }
+        insert_wallet_transaction.execute(params![wallet.to_string(), signature])?;
+    }
+
+    Ok(inserted)
+}
@@ -0,0 +1,164 @@
+//! This module instruments Shyft API calls with `tracing` spans and `metrics`.
+//!
+//! With the `tracing` feature, every call runs in a `shyft_request` span recording the endpoint,
+//! network, commitment, redacted query parameters, status, retry count and latency.
+//!
+//! With the `metrics` feature, every call updates the following metrics, labelled by `endpoint`,
+//! `network` and `commitment`:
+//!
+//! - `shyft_requests_total`: counter of calls, also labelled by `status`.
+//! - `shyft_retries_total`: counter of retried attempts.
+//! - `shyft_request_duration_seconds`: histogram of call latencies, retries included.
+//!
+//! Nothing is emitted unless a `tracing` subscriber or a `metrics` recorder is installed by the
+//! application.
+
+use http::Extensions;
+use reqwest::{Request, Response};
+use reqwest_middleware::{Middleware, Next};
+
+use crate::time::Instant;
+
+/// Query parameters whose values are never recorded.
+#[cfg(feature = "tracing")]
+const REDACTED_PARAMS: &[&str] = &["api_key"];
+
+/// Prefix of the API paths, stripped from the endpoint names.
+const PATH_PREFIX: &str = "/sol/v1/";
+
+/// Middleware instrumenting API calls, placed before the retry middleware.
+#[derive(Debug, Clone)]
+pub(crate) struct Instrumentation {
+    /// Network of the client.
+    network: String,
+    /// Commitment of the client.
+    commitment: String,
+}
+
+/// Middleware counting the attempts of a call, placed after the retry middleware.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct AttemptCounter;
+
+/// Number of attempts of a call, stored in the request extensions.
+#[derive(Debug, Clone, Copy, Default)]
+struct Attempts(u32);
+
+impl Instrumentation {
+    /// Creates a new instrumentation middleware for a client.
+    pub(crate) fn new(network: String, commitment: String) -> Self {
+        Self {
+            network,
+            commitment,
+        }
+    }
+}
+
+/// Returns the endpoint name of a request, such as `transaction/parsed`.
+fn endpoint(req: &Request) -> String {
+    let path = req.url().path();
+    path.strip_prefix(PATH_PREFIX).unwrap_or(path).to_owned()
+}
+
+/// Returns the query string of a request, with the values of sensitive parameters redacted.
+#[cfg(feature = "tracing")]
+fn redacted_params(req: &Request) -> String {
+    req.url()
+        .query_pairs()
+        .map(|(key, value)| {
+            if REDACTED_PARAMS.contains(&key.as_ref()) {
+                format!("{key}=[REDACTED]")
+            } else {
+                format!("{key}={value}")
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("&")
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+impl Middleware for Instrumentation {
+    /// Records the span and metrics of an API call, once all its attempts are done.
+    async fn handle(
+        &self,
+        req: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> reqwest_middleware::Result<Response> {
+        let endpoint = endpoint(&req);
+        extensions.insert(Attempts::default());
+
+        #[cfg(feature = "tracing")]
+        let span = tracing::info_span!(
+            "shyft_request",
+            endpoint = %endpoint,
+            method = %req.method(),
+            network = %self.network,
+            commitment = %self.commitment,
+            params = %redacted_params(&req),
+            status = tracing::field::Empty,
+            retries = tracing::field::Empty,
+            latency_ms = tracing::field::Empty,
+        );
+
+        let start = Instant::now();
+        let run = next.run(req, extensions);
+        #[cfg(feature = "tracing")]
+        let run = tracing::Instrument::instrument(run, span.clone());
+        let result = run.await;
+        let latency = start.elapsed();
+
+        let retries = extensions
+            .get::<Attempts>()
+            .map_or(0, |attempts| attempts.0.saturating_sub(1));
+        let status = match &result {
+            Ok(response) => response.status().as_u16().to_string(),
+            Err(_) => "error".to_owned(),
+        };
+
+        #[cfg(feature = "tracing")]
+        {
+            span.record("status", status.as_str());
+            span.record("retries", retries);
+            span.record("latency_ms", latency.as_millis() as u64);
+            span.in_scope(|| match &result {
+                Ok(_) => tracing::debug!("Shyft API call completed"),
+                Err(err) => tracing::warn!(error = %err, "Shyft API call failed"),
+            });
+        }
+
+        #[cfg(feature = "metrics")]
+        {
+            let labels = [
+                ("endpoint", endpoint),
+                ("network", self.network.clone()),
+                ("commitment", self.commitment.clone()),
+            ];
+            let mut status_labels = labels.to_vec();
+            status_labels.push(("status", status));
+            metrics::counter!("shyft_requests_total", &status_labels).increment(1);
+            metrics::counter!("shyft_retries_total", &labels).increment(retries.into());
+            metrics::histogram!("shyft_request_duration_seconds", &labels)
+                .record(latency.as_secs_f64());
+        }
+
+        result
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+impl Middleware for AttemptCounter {
+    /// Counts an attempt of the call before sending it.
+    async fn handle(
+        &self,
+        req: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> reqwest_middleware::Result<Response> {
+        if let Some(attempts) = extensions.get_mut::<Attempts>() {
+            attempts.0 += 1;
+        }
+        next.run(req, extensions).await
+    }
+}
@@ -0,0 +1,330 @@
+//! This module provides a pool of Shyft API keys shared by a client.
+//!
+//! Each request is sent with a key of the [`ApiKeyPool`], picked round-robin or by remaining
+//! quota. A key answered with `401 Unauthorized` is disabled until the keys are replaced, and a
+//! key answered with `429 Too Many Requests` is skipped until its `Retry-After` delay, or one
+//! second, has elapsed. In both cases the request is sent again with another key, if any.
+//!
+//! The pool is shared by all its clones, so keys can be replaced at runtime without rebuilding
+//! the client. Keys are stored as sensitive header values and never printed by `Debug`. Keys
+//! sent in the query string of RPC calls are removed from the URLs of their errors.
+//!
+//! # Examples
+//!
+//! ```
+//! use shyft_rs_sdk::{ApiKeyPool, KeyRotation, ShyftApi};
+//!
+//! let keys = ApiKeyPool::new(["first_api_key", "second_api_key"])?
+//!     .with_rotation(KeyRotation::RemainingQuota);
+//! let client = ShyftApi::builder_with_api_key_pool(keys.clone()).build()?;
+//!
+//! // Later, rotate the keys without rebuilding the client
+//! keys.set_keys(["third_api_key"])?;
+//! # Ok::<(), shyft_rs_sdk::Error>(())
+//! ```
+
+use std::{
+    cmp::Reverse,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use http::Extensions;
+use reqwest::{header, Request, Response, StatusCode};
+use reqwest_middleware::{Middleware, Next};
+
+use crate::time::Instant;
+
+/// Header carrying the API key.
+const API_KEY_HEADER: &str = "x-api-key";
+
+/// Query parameter carrying the API key, for requests marked with [`ApiKeyInQuery`].
+pub(crate) const API_KEY_PARAM: &str = "api_key";
+
+/// Header carrying the number of requests remaining in the quota of a key, if sent by Shyft.
+const REMAINING_QUOTA_HEADER: &str = "x-ratelimit-remaining";
+
+/// Delay before a rate limited key is used again, if the response has no `Retry-After` header.
+const DEFAULT_COOLDOWN: Duration = Duration::from_secs(1);
+
+/// Enum representing how the key of each request is picked.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum KeyRotation {
+    /// Keys are used in turn.
+    #[default]
+    RoundRobin,
+    /// The key with the most requests remaining in its quota is used, as reported by the
+    /// `X-RateLimit-Remaining` header. Keys whose quota is unknown are used first.
+    RemainingQuota,
+}
+
+/// Request extension sending the API key as the `api_key` query parameter instead of a header,
+/// as required by the RPC endpoints of Shyft.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ApiKeyInQuery;
+
+/// Struct representing a pool of Shyft API keys, shared by all its clones.
+#[derive(Debug, Clone)]
+pub struct ApiKeyPool {
+    /// The shared state of the pool.
+    inner: Arc<Mutex<Pool>>,
+}
+
+/// State of a key pool.
+#[derive(Debug)]
+struct Pool {
+    /// The keys and their state.
+    keys: Vec<Key>,
+    /// How the key of each request is picked.
+    rotation: KeyRotation,
+    /// Index of the next key for round-robin rotation.
+    next: usize,
+}
+
+/// An API key and its state.
+#[derive(Debug)]
+struct Key {
+    /// The key, as a sensitive header value.
+    value: header::HeaderValue,
+    /// Whether the key was rejected with `401 Unauthorized`.
+    disabled: bool,
+    /// The key is skipped until this instant, after a `429 Too Many Requests`.
+    cooldown_until: Option<Instant>,
+    /// Number of requests remaining in the quota of the key, if known.
+    remaining_quota: Option<u64>,
+}
+
+impl ApiKeyPool {
+    /// Creates a new pool of API keys, using round-robin rotation.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if a key is not a valid header value.
+    pub fn new<K: AsRef<str>>(keys: impl IntoIterator<Item = K>) -> Result<Self, crate::Error> {
+        Ok(Self {
+            inner: Arc::new(Mutex::new(Pool {
+                keys: parse_keys(keys)?,
+                rotation: KeyRotation::default(),
+                next: 0,
+            })),
+        })
+    }
+
+    /// Sets how the key of each request is picked.
+    pub fn with_rotation(self, rotation: KeyRotation) -> Self {
+        self.inner.lock().unwrap().rotation = rotation;
+        self
+    }
+
+    /// Replaces all the keys of the pool, for every client using it.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if a key is not a valid header value, in which case
+    /// the keys are left unchanged.
+    pub fn set_keys<K: AsRef<str>>(
+        &self,
+        keys: impl IntoIterator<Item = K>,
+    ) -> Result<(), crate::Error> {
+        let keys = parse_keys(keys)?;
+        let mut pool = self.inner.lock().unwrap();
+        pool.keys = keys;
+        pool.next = 0;
+        Ok(())
+    }
+
+    /// Adds a key to the pool, for every client using it.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the key is not a valid header value.
+    pub fn add_key(&self, key: &str) -> Result<(), crate::Error> {
+        let key = parse_keys([key])?;
+        self.inner.lock().unwrap().keys.extend(key);
+        Ok(())
+    }
+
+    /// Removes a key from the pool, returning `true` if it was in the pool.
+    pub fn remove_key(&self, key: &str) -> bool {
+        let mut pool = self.inner.lock().unwrap();
+        let len = pool.keys.len();
+        pool.keys.retain(|k| k.value != key);
+        pool.keys.len() != len
+    }
+
+    /// Returns the number of keys in the pool.
+    pub fn len(&self) -> usize {
+        self.inner.lock().unwrap().keys.len()
+    }
+
+    /// Returns `true` if the pool has no keys.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the number of keys that are neither disabled nor rate limited.
+    pub fn available(&self) -> usize {
+        let now = Instant::now();
+        let pool = self.inner.lock().unwrap();
+        pool.keys.iter().filter(|key| key.is_available(now)).count()
+    }
+
+    /// Picks the key for a request, skipping the keys already tried for it.
+    ///
+    /// If every key is disabled or rate limited, the one whose cooldown ends first is returned,
+    /// so the response is left for the retry strategy to handle.
+    fn select(&self, tried: &[header::HeaderValue]) -> Option<header::HeaderValue> {
+        let now = Instant::now();
+        let mut pool = self.inner.lock().unwrap();
+        let len = pool.keys.len();
+
+        let mut candidates = (0..len)
+            .map(|offset| (pool.next + offset) % len)
+            .filter(|&i| pool.keys[i].is_available(now) && !tried.contains(&pool.keys[i].value));
+        let index = match pool.rotation {
+            KeyRotation::RoundRobin => candidates.next(),
+            KeyRotation::RemainingQuota => candidates
+                .min_by_key(|&i| Reverse(pool.keys[i].remaining_quota.unwrap_or(u64::MAX))),
+        };
+
+        let index = match index {
+            Some(index) => index,
+            None if tried.is_empty() => pool
+                .keys
+                .iter()
+                .enumerate()
+                .filter(|(_, key)| !key.disabled)
+                .min_by_key(|(_, key)| key.cooldown_until)
+                .map(|(i, _)| i)?,
+            None => return None,
+        };
+
+        pool.next = (index + 1) % len;
+        Some(pool.keys[index].value.clone())
+    }
+
+    /// Updates the state of a key from the response to a request sent with it.
+    fn report(&self, value: &header::HeaderValue, response: &Response) {
+        let mut pool = self.inner.lock().unwrap();
+        let Some(key) = pool.keys.iter_mut().find(|key| key.value == value) else {
+            // The key was removed while the request was in flight
+            return;
+        };
+
+        if let Some(remaining_quota) = response
+            .headers()
+            .get(REMAINING_QUOTA_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.trim().parse().ok())
+        {
+            key.remaining_quota = Some(remaining_quota);
+        }
+
+        match response.status() {
+            StatusCode::UNAUTHORIZED => key.disabled = true,
+            StatusCode::TOO_MANY_REQUESTS => {
+                let cooldown = response
+                    .headers()
+                    .get(header::RETRY_AFTER)
+                    .and_then(|value| value.to_str().ok())
+                    .and_then(|value| value.trim().parse().ok())
+                    .map_or(DEFAULT_COOLDOWN, Duration::from_secs);
+                key.cooldown_until = Some(Instant::now() + cooldown);
+                key.remaining_quota = Some(0);
+            }
+            _ => {}
+        }
+    }
+}
+
+impl Key {
+    /// Returns `true` if the key is neither disabled nor rate limited.
+    fn is_available(&self, now: Instant) -> bool {
+        !self.disabled
+            && self
+                .cooldown_until
+                .is_none_or(|cooldown_until| cooldown_until <= now)
+    }
+}
+
+/// Parses API keys into sensitive header values.
+fn parse_keys<K: AsRef<str>>(keys: impl IntoIterator<Item = K>) -> Result<Vec<Key>, crate::Error> {
+    keys.into_iter()
+        .map(|key| {
+            let mut value = header::HeaderValue::from_str(key.as_ref())
+                .map_err(|_| crate::Error::InvalidApiKey)?;
+            value.set_sensitive(true);
+            Ok(Key {
+                value,
+                disabled: false,
+                cooldown_until: None,
+                remaining_quota: None,
+            })
+        })
+        .collect()
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+impl Middleware for ApiKeyPool {
+    /// Sends the request with a key of the pool, trying other keys on `401` and `429`.
+    async fn handle(
+        &self,
+        req: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> reqwest_middleware::Result<Response> {
+        let Some(mut key) = self.select(&[]) else {
+            return Err(reqwest_middleware::Error::middleware(
+                crate::Error::NoApiKey,
+            ));
+        };
+        let mut req = req;
+        let mut tried = Vec::new();
+        let in_query = extensions.get::<ApiKeyInQuery>().is_some();
+
+        loop {
+            // Keep a copy to send again with another key, unless the body is a stream
+            let retry_req = req.try_clone();
+            match key.to_str() {
+                Ok(value) if in_query => {
+                    req.url_mut()
+                        .query_pairs_mut()
+                        .append_pair(API_KEY_PARAM, value);
+                }
+                _ => {
+                    req.headers_mut().insert(API_KEY_HEADER, key.clone());
+                }
+            }
+
+            // The URL of a key sent in the query is dropped from errors, which are displayed
+            let response =
+                next.clone()
+                    .run(req, extensions)
+                    .await
+                    .map_err(|error| match error {
+                        reqwest_middleware::Error::Reqwest(error) if in_query => {
+                            reqwest_middleware::Error::Reqwest(error.without_url())
+                        }
+                        error => error,
+                    })?;
+            self.report(&key, &response);
+
+            if !matches!(
+                response.status(),
+                StatusCode::UNAUTHORIZED | StatusCode::TOO_MANY_REQUESTS
+            ) {
+                return Ok(response);
+            }
+
+            tried.push(key);
+            match (retry_req, self.select(&tried)) {
+                (Some(retry_req), Some(next_key)) => {
+                    req = retry_req;
+                    key = next_key;
+                }
+                _ => return Ok(response),
+            }
+        }
+    }
+}
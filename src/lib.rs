@@ -10,9 +10,41 @@ This is not an official SDK. It is a personal project for learning Rust and API
 ## Features
 
 - Configurable retry strategy for API requests.
+- Client-side rate limiting matching your Shyft plan, shared across clients.
+- [API key pools](ApiKeyPool) with rotation, failover on `401`/`429` and hot-swapping.
+- Pluggable response caching, with an in-memory LRU cache.
+- Per-call overrides of the network, commitment, timeout and retry policy with [`RequestOptions`].
 - Fetch transaction history for a given account.
 - Retrieve parsed transaction details for a specific transaction signature.
-- Fetch parsed bulk transactions in a single call.
+- Fetch parsed bulk transactions in a single call, or any number of them in concurrent batches.
+- Fetch the `.sol` domains of a wallet and resolve addresses to their primary domain.
+- Watch accounts for new transactions by polling, as a stream.
+- [Priority fee estimates](models::priority_fee) for transactions writing to some accounts.
+- [Yellowstone gRPC subscriptions](grpc) with typed filters, reconnect and resume from the last slot.
+- [WebSocket PubSub subscriptions](pubsub) as typed streams, resubscribed on reconnect, with log-triggered transaction parsing.
+- [SQLite indexing](indexer) of wallet histories, synced incrementally and queried by time, action type and mint.
+- [Typed requests](requests) for every endpoint, validated before sending and serializable as jobs.
+- Validated [`Pubkey`] and [`Signature`] types, so malformed inputs fail before a request is sent.
+- Exact [`Decimal`] UI amounts, integer raw amounts and [conversion helpers](amount) between them.
+- [SOL and token balance changes](analytics::balance_changes) computed from raw transactions.
+- [Realized and unrealized PnL](analytics::pnl) of a wallet's swaps, using FIFO, LIFO or average cost.
+
+## Cargo Features
+
+- `default-tls` (default): Uses the default TLS backend of `reqwest`.
+- `native-tls`: Uses the platform's native TLS, such as OpenSSL on Linux.
+- `rustls-tls`: Uses `rustls`, for static builds without OpenSSL. Disable the default features to drop OpenSSL entirely.
+- `blocking`: Adds a blocking client, for programs without an async runtime.
+- `chrono`: Parses transaction timestamps into `chrono::DateTime<Utc>`.
+- `export`: Exports transactions as CSV or JSON Lines, one row per action.
+- `parquet`: Adds Parquet output to the `export` feature.
+- `cli`: Builds the `shyft` command-line tool.
+- `grpc`: Adds a Yellowstone gRPC (Geyser) streaming client. It always uses `rustls` with the platform's root certificates, whichever TLS feature is selected.
+- `indexer`: Persists wallet histories into SQLite, synced incrementally. Enables `chrono` and `export`.
+- `pubsub`: Adds Solana PubSub subscriptions over the WebSocket RPC of Shyft.
+- `tracing`: Emits a `tracing` span per API call, with its endpoint, status, retries and latency.
+- `metrics`: Records request counts, retries and latencies per endpoint with the `metrics` crate.
+- `wasm`: Builds for `wasm32-unknown-unknown`, using browser timers instead of tokio. The `blocking`, `cli`, `grpc`, `indexer` and `pubsub` features are not supported in the browser.
 
 ## Configuration
 
@@ -24,6 +56,10 @@ You can configure the retry strategy by providing optional parameters when creat
 - `network`: Network to interact with(mainnet-beta, devnet, testnet).
 - `commitment`: Commitment level for transactions(confirmed, finalised).
 
+For more options, such as a client-side [`RateLimiter`] matching your Shyft plan, use
+[`ShyftApi::builder`]. Any of these can be overridden for some calls with
+[`ShyftApi::with_options`].
+
 ## Usage
 
 ### Creating a ShyftApi Instance
@@ -49,9 +85,9 @@ Equivalent to [GET /transaction/history]
 #
 # let api_key = "your_api_key";
 # let client = ShyftApi::new(api_key, None, None, None, None, None).unwrap();
-let account = "your_account_address";
+let account = "your_account_address".parse()?;
 let transaction_history = client
-    .get_transaction_history(account, Some(10), None, None, Some(true), None)
+    .get_transaction_history(&account, Some(10), None, None, Some(true), None)
     .await?;
 println!("{:?}", transaction_history);
 # Ok(())
@@ -71,9 +107,9 @@ Equivalent to [GET /transaction/parsed]
 #
 # let api_key = "your_api_key";
 # let client = ShyftApi::new(api_key, None, None, None, None, None).unwrap();
-let tx_signature = "your_transaction_signature";
+let tx_signature = "your_transaction_signature".parse()?;
 let parsed_transaction_details = client
-    .get_transaction_parsed(tx_signature)
+    .get_transaction_parsed(&tx_signature)
     .await
     .expect("Failed to fetch parsed transaction details");
 println!("{:?}", parsed_transaction_details);
@@ -95,8 +131,8 @@ Equivalent to [POST /transaction/parse_selected]
 # let api_key = "your_api_key";
 # let client = ShyftApi::new(api_key, None, None, None, None, None).unwrap();
 let tx_signatures = vec![
-    "your_transaction_signature_1".to_owned(),
-    "your_transaction_signature_2".to_owned(),
+    "your_transaction_signature_1".parse()?,
+    "your_transaction_signature_2".parse()?,
 ];
 let parsed_transactions = client
     .get_transaction_parse_selected(&tx_signatures, Some(true), Some(true))
@@ -105,6 +141,33 @@ let parsed_transactions = client
 println!("{:?}", parsed_transactions);
 # Ok(())
 # }
+```
+
+### Resolving `.sol` Domains
+
+Equivalent to [GET /wallet/get_domains] and [GET /wallet/resolve_address]
+
+[GET /wallet/get_domains]: https://docs.shyft.to/solana-apis/wallet/wallet-apis#get-domains
+[GET /wallet/resolve_address]: https://docs.shyft.to/solana-apis/wallet/wallet-apis#resolve-address
+
+```no_run
+# #[tokio::main]
+# async fn main() -> Result<(), shyft_rs_sdk::Error> {
+# use shyft_rs_sdk::ShyftApi;
+#
+# let api_key = "your_api_key";
+# let client = ShyftApi::new(api_key, None, None, None, None, None).unwrap();
+let wallet = "your_wallet_address".parse()?;
+let domains = client.get_domains(&wallet).await?;
+let primary_domain = client.resolve_address(&wallet).await?;
+
+// Resolve the fee payer and signers of a transaction to their domains
+let tx_signature = "your_transaction_signature".parse()?;
+let transaction = client.get_transaction_parsed(&tx_signature).await?;
+let names = client.resolve_transaction_domains(&transaction).await?;
+println!("{:?} {:?} {:?}", domains, primary_domain, names);
+# Ok(())
+# }
 ```
  */
 
@@ -115,12 +178,55 @@ println!("{:?}", parsed_transactions);
     clippy::missing_docs_in_private_items
 )]
 
+#[cfg(all(target_arch = "wasm32", not(feature = "wasm")))]
+compile_error!("the `wasm` feature is required to build for wasm32");
+#[cfg(all(
+    target_arch = "wasm32",
+    any(
+        feature = "blocking",
+        feature = "cli",
+        feature = "grpc",
+        feature = "indexer",
+        feature = "pubsub"
+    )
+))]
+compile_error!(
+    "the `blocking`, `cli`, `grpc`, `indexer` and `pubsub` features are not supported on wasm32"
+);
+
 mod api;
-mod constants;
+mod cache;
 mod error;
+#[cfg(any(feature = "tracing", feature = "metrics"))]
+mod instrument;
+mod key_pool;
+mod priority_fee;
+mod rate_limit;
 mod reqwest_ext;
-
+mod time;
+mod types;
+mod watch;
+
+pub mod amount;
+pub mod analytics;
+#[cfg(feature = "blocking")]
+pub mod blocking;
+pub mod constants;
+#[cfg(feature = "export")]
+pub mod export;
+#[cfg(feature = "grpc")]
+pub mod grpc;
+#[cfg(feature = "indexer")]
+pub mod indexer;
 pub mod models;
+#[cfg(feature = "pubsub")]
+pub mod pubsub;
+pub mod requests;
 
-pub use api::{Commitment, Network, ShyftApi};
+pub use api::{Commitment, Network, RequestOptions, ShyftApi, ShyftApiBuilder};
+pub use cache::{Cache, LruCache};
 pub use error::Error;
+pub use key_pool::{ApiKeyPool, KeyRotation};
+pub use rate_limit::{Plan, RateLimiter};
+pub use rust_decimal::Decimal;
+pub use types::{Pubkey, Signature};
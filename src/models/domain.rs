@@ -0,0 +1,16 @@
+/*!
+This module contains the data structures for Solana Name Service (`.sol`) domains.
+*/
+
+use serde::{Deserialize, Serialize};
+
+use crate::Pubkey;
+
+/// Struct representing a `.sol` domain owned by a wallet.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Domain {
+    /// Address of the domain's name account.
+    pub address: Pubkey,
+    /// Name of the domain, including the `.sol` suffix.
+    pub name: String,
+}
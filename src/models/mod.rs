@@ -6,7 +6,12 @@ This does not necessarily cover all response types from the Shyft API.
 </div>
 */
 
+pub mod domain;
 pub mod parsed_transaction_details;
+pub mod priority_fee;
+pub mod raw_transaction;
+
+mod serde_helpers;
 
 use serde::{Deserialize, Serialize};
 
@@ -24,3 +29,16 @@ pub struct Response<T> {
     /// The result of the API request.
     pub result: T,
 }
+
+/// A JSON-RPC response, as returned by the RPC endpoints.
+///
+/// # Type Parameters
+///
+/// * `T` - The type of the result field.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct JsonRpcResponse<T> {
+    /// The result of the call, if it succeeded.
+    pub result: Option<T>,
+    /// The error of the call, if it failed.
+    pub error: Option<serde_json::Value>,
+}
@@ -8,39 +8,210 @@ This module does not necessarily cover all transaction types.
 
 use serde::{Deserialize, Serialize};
 
+use super::{raw_transaction::RawTransaction, serde_helpers::u64_from_number_or_string};
+use crate::{Decimal, Pubkey, Signature};
+
 /// Struct representing parsed transaction details.
 #[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
 pub struct ParsedTransactionDetails {
-    /// Timestamp of the transaction.
+    /// Timestamp of the transaction, in RFC 3339 format.
+    ///
+    /// Enable the `chrono` feature to parse it with [`ParsedTransactionDetails::timestamp_utc`].
     pub timestamp: String,
-    /// Fee associated with the transaction.
-    pub fee: f64,
+    /// Fee associated with the transaction, in SOL.
+    pub fee: Decimal,
     /// Fee payer of the transaction.
-    pub fee_payer: String,
+    pub fee_payer: Pubkey,
     /// List of signers of the transaction.
-    pub signers: Vec<String>,
+    pub signers: Vec<Pubkey>,
     /// List of signatures of the transaction.
-    pub signatures: Vec<String>,
+    pub signatures: Vec<Signature>,
     /// Protocol information.
     pub protocol: Protocol,
     /// Type of the transaction.
     #[serde(rename = "type")]
-    pub transaction_type: String,
+    pub transaction_type: TxType,
     /// Status of the transaction.
-    pub status: String,
+    pub status: TxStatus,
     /// List of actions in the transaction.
     pub actions: Vec<Action>,
-    /// Raw transaction details.
-    pub raw: Option<serde_json::Value>,
-    /// Optional list of events in the transaction.
-    pub events: Option<Vec<serde_json::Value>>,
+    /// Raw transaction details, present when raw details are enabled.
+    pub raw: Option<RawTransaction>,
+    /// Optional list of events in the transaction, present when events are enabled.
+    pub events: Option<Vec<Event>>,
+}
+
+impl ParsedTransactionDetails {
+    /// Returns `true` if the transaction succeeded.
+    pub fn is_success(&self) -> bool {
+        self.status == TxStatus::Success
+    }
+
+    /// Returns the fee associated with the transaction in lamports.
+    ///
+    /// Returns `None` if the fee is not a valid lamport amount.
+    pub fn fee_lamports(&self) -> Option<u64> {
+        crate::amount::sol_to_lamports(self.fee)
+    }
+
+    /// Computes the SOL and token balance changes of the transaction.
+    ///
+    /// Returns `None` if the transaction was fetched without raw details.
+    /// See [`analytics::balance_changes`](crate::analytics::balance_changes) for details.
+    pub fn balance_changes(&self) -> Option<crate::analytics::balance_changes::BalanceChanges> {
+        crate::analytics::balance_changes::balance_changes(self.raw.as_ref()?)
+    }
+
+    /// Parses the timestamp of the transaction.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the timestamp is not a valid RFC 3339 timestamp.
+    #[cfg(feature = "chrono")]
+    pub fn timestamp_utc(&self) -> Result<chrono::DateTime<chrono::Utc>, crate::error::Error> {
+        Ok(chrono::DateTime::parse_from_rfc3339(&self.timestamp)?.with_timezone(&chrono::Utc))
+    }
+}
+
+/// Enum representing the status of a transaction.
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    Hash,
+    Serialize,
+    Deserialize,
+    strum_macros::Display,
+    strum_macros::EnumString,
+)]
+#[serde(from = "String", into = "String")]
+#[non_exhaustive]
+pub enum TxStatus {
+    /// The transaction was executed successfully.
+    Success,
+    /// The transaction failed.
+    Fail,
+    /// A status not known to this version of the SDK.
+    #[strum(default)]
+    Other(String),
+}
+
+impl From<String> for TxStatus {
+    fn from(status: String) -> Self {
+        match status.parse() {
+            Ok(status) => status,
+            Err(_) => Self::Other(status),
+        }
+    }
+}
+
+impl From<TxStatus> for String {
+    fn from(status: TxStatus) -> Self {
+        status.to_string()
+    }
+}
+
+/// Enum representing the type of a transaction or of an action within it.
+///
+/// # Examples
+///
+/// ```
+/// use shyft_rs_sdk::models::parsed_transaction_details::TxType;
+///
+/// assert_eq!("SWAP".parse::<TxType>().unwrap(), TxType::Swap);
+/// assert_eq!(TxType::NftMint.to_string(), "NFT_MINT");
+/// assert_eq!(
+///     "SOME_NEW_TYPE".parse::<TxType>().unwrap(),
+///     TxType::Other("SOME_NEW_TYPE".to_owned())
+/// );
+/// ```
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    Hash,
+    Serialize,
+    Deserialize,
+    strum_macros::Display,
+    strum_macros::EnumString,
+)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+#[serde(from = "String", into = "String")]
+#[non_exhaustive]
+pub enum TxType {
+    /// Token swap.
+    Swap,
+    /// SOL transfer.
+    SolTransfer,
+    /// Token transfer.
+    TokenTransfer,
+    /// Token creation.
+    TokenCreate,
+    /// Token minting.
+    TokenMint,
+    /// Token burning.
+    TokenBurn,
+    /// NFT minting.
+    NftMint,
+    /// NFT transfer.
+    NftTransfer,
+    /// NFT burning.
+    NftBurn,
+    /// NFT sale on a marketplace.
+    NftSale,
+    /// NFT listing on a marketplace.
+    NftList,
+    /// Update of an NFT listing.
+    NftListUpdate,
+    /// Cancellation of an NFT listing.
+    NftListCancel,
+    /// Bid on an NFT.
+    NftBid,
+    /// Cancellation of a bid on an NFT.
+    NftBidCancel,
+    /// Compressed NFT minting.
+    CompressedNftMint,
+    /// Compressed NFT transfer.
+    CompressedNftTransfer,
+    /// Compressed NFT burning.
+    CompressedNftBurn,
+    /// Liquidity pool creation.
+    CreatePool,
+    /// Liquidity added to a pool.
+    AddLiquidity,
+    /// Liquidity removed from a pool.
+    RemoveLiquidity,
+    /// Memo.
+    Memo,
+    /// A transaction Shyft could not classify.
+    Unknown,
+    /// A type not known to this version of the SDK.
+    #[strum(default)]
+    Other(String),
+}
+
+impl From<String> for TxType {
+    fn from(tx_type: String) -> Self {
+        match tx_type.parse() {
+            Ok(tx_type) => tx_type,
+            Err(_) => Self::Other(tx_type),
+        }
+    }
+}
+
+impl From<TxType> for String {
+    fn from(tx_type: TxType) -> Self {
+        tx_type.to_string()
+    }
 }
 
 /// Struct representing protocol information.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct Protocol {
     /// Address of the protocol.
-    pub address: String,
+    pub address: Pubkey,
     /// Name of the protocol.
     pub name: String,
 }
@@ -54,19 +225,289 @@ pub struct Action {
     pub source_protocol: Protocol,
     /// Type of the action.
     #[serde(rename = "type")]
-    pub action_type: String,
+    pub action_type: TxType,
     /// Optional parent protocol of the action.
-    pub parent_protocol: Option<String>,
+    pub parent_protocol: Option<Pubkey>,
     /// Optional index of the instruction.
     pub ix_index: Option<u32>,
 }
 
-/// Struct representing an event in the transaction.
-#[derive(Debug, Serialize, Deserialize)]
-pub struct Event {}
+impl Action {
+    /// Deserializes the information of the action into [`Info`], based on its type.
+    ///
+    /// Returns `None` if the action type has no typed counterpart in [`Info`].
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the information does not match the action type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use shyft_rs_sdk::models::parsed_transaction_details::{Action, Info};
+    /// # fn print_swap(action: &Action) -> Result<(), shyft_rs_sdk::Error> {
+    /// if let Some(Info::PumpfunSwap(swap)) = action.typed_info()? {
+    ///     println!("{} swapped {}", swap.swapper, swap.tokens_swapped.token_in.amount);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn typed_info(&self) -> Result<Option<Info>, crate::error::Error> {
+        let info = self.info.clone();
+        let info = match self.action_type {
+            TxType::CreatePool => Info::CreatePool(serde_json::from_value(info)?),
+            TxType::SolTransfer => Info::SolTransfer(serde_json::from_value(info)?),
+            TxType::TokenCreate => Info::TokenCreate(serde_json::from_value(info)?),
+            TxType::TokenMint => Info::TokenMint(serde_json::from_value(info)?),
+            TxType::TokenTransfer => Info::TokenTransfer(serde_json::from_value(info)?),
+            TxType::Swap => Info::PumpfunSwap(serde_json::from_value(info)?),
+            _ => return Ok(None),
+        };
+        Ok(Some(info))
+    }
+}
+
+/// Enum representing an event emitted in the transaction.
+///
+/// Events are only returned when `enable_events` is set. Events this SDK has no typed
+/// model for, or whose payload does not match the expected model, are kept as
+/// [`Event::Other`].
+///
+/// # Examples
+///
+/// ```
+/// use shyft_rs_sdk::models::parsed_transaction_details::Event;
+///
+/// let event: Event = serde_json::from_value(serde_json::json!({
+///     "name": "SwapEvent",
+///     "data": {
+///         "amm": "675kPX9MHTjS2zt1qfr1NYHuzeLXfQM9H24wFSUt1Mp8",
+///         "inputMint": "EWFka4M5XZrKzAq54eTXxN5K1H3u2es1HVqvr6hsqdSk",
+///         "inputAmount": 877265344778813700u64,
+///         "outputMint": "So11111111111111111111111111111111111111112",
+///         "outputAmount": 80249704804u64
+///     }
+/// }))?;
+///
+/// match event {
+///     Event::Swap(swap) => assert_eq!(swap.output_amount, 80249704804),
+///     other => panic!("unexpected event {}", other.name()),
+/// }
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(from = "RawEvent", into = "RawEvent")]
+#[non_exhaustive]
+pub enum Event {
+    /// Pump.fun `TradeEvent`, emitted on every buy or sell on a bonding curve.
+    PumpfunTrade(PumpfunTradeEvent),
+    /// Pump.fun `CreateEvent`, emitted when a new token is launched.
+    PumpfunCreate(PumpfunCreateEvent),
+    /// Pump.fun `CompleteEvent`, emitted when a bonding curve completes.
+    PumpfunComplete(PumpfunCompleteEvent),
+    /// Jupiter `SwapEvent`, emitted for every hop of a routed swap.
+    Swap(SwapEvent),
+    /// Raydium AMM `SwapBaseIn` log.
+    RaydiumSwapBaseIn(RaydiumSwapBaseInEvent),
+    /// Raydium AMM `SwapBaseOut` log.
+    RaydiumSwapBaseOut(RaydiumSwapBaseOutEvent),
+    /// Any other event, such as an Anchor event of an unsupported program.
+    Other(RawEvent),
+}
+
+impl Event {
+    /// Returns the name of the event as emitted by Shyft.
+    pub fn name(&self) -> &str {
+        match self {
+            Event::PumpfunTrade(_) => "TradeEvent",
+            Event::PumpfunCreate(_) => "CreateEvent",
+            Event::PumpfunComplete(_) => "CompleteEvent",
+            Event::Swap(_) => "SwapEvent",
+            Event::RaydiumSwapBaseIn(_) => "SwapBaseIn",
+            Event::RaydiumSwapBaseOut(_) => "SwapBaseOut",
+            Event::Other(raw) => &raw.name,
+        }
+    }
+}
+
+impl From<RawEvent> for Event {
+    fn from(raw: RawEvent) -> Self {
+        /// Deserializes the event data, keeping the raw event if it does not match the model.
+        fn typed<T: serde::de::DeserializeOwned>(raw: RawEvent, variant: fn(T) -> Event) -> Event {
+            match serde_json::from_value(raw.data.clone()) {
+                Ok(data) => variant(data),
+                Err(_) => Event::Other(raw),
+            }
+        }
+
+        match raw.name.as_str() {
+            "TradeEvent" => typed(raw, Event::PumpfunTrade),
+            "CreateEvent" => typed(raw, Event::PumpfunCreate),
+            "CompleteEvent" => typed(raw, Event::PumpfunComplete),
+            "SwapEvent" => typed(raw, Event::Swap),
+            "SwapBaseIn" => typed(raw, Event::RaydiumSwapBaseIn),
+            "SwapBaseOut" => typed(raw, Event::RaydiumSwapBaseOut),
+            _ => Event::Other(raw),
+        }
+    }
+}
+
+impl From<Event> for RawEvent {
+    fn from(event: Event) -> Self {
+        let name = event.name().to_owned();
+        let data = match event {
+            Event::PumpfunTrade(data) => serde_json::to_value(data),
+            Event::PumpfunCreate(data) => serde_json::to_value(data),
+            Event::PumpfunComplete(data) => serde_json::to_value(data),
+            Event::Swap(data) => serde_json::to_value(data),
+            Event::RaydiumSwapBaseIn(data) => serde_json::to_value(data),
+            Event::RaydiumSwapBaseOut(data) => serde_json::to_value(data),
+            Event::Other(raw) => return raw,
+        };
+        RawEvent {
+            name,
+            data: data.unwrap_or_default(),
+        }
+    }
+}
+
+/// Struct representing an event as returned by the Shyft API.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RawEvent {
+    /// Name of the event.
+    pub name: String,
+    /// Payload of the event.
+    pub data: serde_json::Value,
+}
+
+/// Struct representing a Pump.fun trade on a bonding curve.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PumpfunTradeEvent {
+    /// Address of the traded token.
+    pub mint: Pubkey,
+    /// Raw amount of SOL traded, in lamports.
+    #[serde(deserialize_with = "u64_from_number_or_string")]
+    pub sol_amount: u64,
+    /// Raw amount of tokens traded.
+    #[serde(deserialize_with = "u64_from_number_or_string")]
+    pub token_amount: u64,
+    /// `true` if the user bought tokens, `false` if they sold.
+    pub is_buy: bool,
+    /// Address of the trader.
+    pub user: Pubkey,
+    /// Unix timestamp of the trade.
+    pub timestamp: i64,
+    /// Virtual SOL reserves of the bonding curve after the trade.
+    #[serde(deserialize_with = "u64_from_number_or_string")]
+    pub virtual_sol_reserves: u64,
+    /// Virtual token reserves of the bonding curve after the trade.
+    #[serde(deserialize_with = "u64_from_number_or_string")]
+    pub virtual_token_reserves: u64,
+}
+
+/// Struct representing a Pump.fun token launch.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PumpfunCreateEvent {
+    /// Name of the token.
+    pub name: String,
+    /// Symbol of the token.
+    pub symbol: String,
+    /// Metadata URI of the token.
+    pub uri: String,
+    /// Address of the token.
+    pub mint: Pubkey,
+    /// Address of the bonding curve.
+    pub bonding_curve: Pubkey,
+    /// Address of the creator.
+    pub user: Pubkey,
+}
+
+/// Struct representing the completion of a Pump.fun bonding curve.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PumpfunCompleteEvent {
+    /// Address of the user whose trade completed the curve.
+    pub user: Pubkey,
+    /// Address of the token.
+    pub mint: Pubkey,
+    /// Address of the bonding curve.
+    pub bonding_curve: Pubkey,
+    /// Unix timestamp of the completion.
+    pub timestamp: i64,
+}
+
+/// Struct representing a single swap hop.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SwapEvent {
+    /// Address of the AMM program used for the hop.
+    pub amm: Pubkey,
+    /// Address of the input token.
+    pub input_mint: Pubkey,
+    /// Raw amount of the input token.
+    #[serde(deserialize_with = "u64_from_number_or_string")]
+    pub input_amount: u64,
+    /// Address of the output token.
+    pub output_mint: Pubkey,
+    /// Raw amount of the output token.
+    #[serde(deserialize_with = "u64_from_number_or_string")]
+    pub output_amount: u64,
+}
+
+/// Struct representing a Raydium AMM swap with a fixed input amount.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RaydiumSwapBaseInEvent {
+    /// Raw amount of the input token.
+    #[serde(deserialize_with = "u64_from_number_or_string")]
+    pub amount_in: u64,
+    /// Minimum raw amount of the output token accepted.
+    #[serde(deserialize_with = "u64_from_number_or_string")]
+    pub minimum_out: u64,
+    /// Swap direction, `1` for coin to pc and `2` for pc to coin.
+    pub direction: u64,
+    /// Raw balance of the user's source token account before the swap.
+    #[serde(deserialize_with = "u64_from_number_or_string")]
+    pub user_source: u64,
+    /// Raw coin reserves of the pool before the swap.
+    #[serde(deserialize_with = "u64_from_number_or_string")]
+    pub pool_coin: u64,
+    /// Raw pc reserves of the pool before the swap.
+    #[serde(deserialize_with = "u64_from_number_or_string")]
+    pub pool_pc: u64,
+    /// Raw amount of the output token.
+    #[serde(deserialize_with = "u64_from_number_or_string")]
+    pub out_amount: u64,
+}
+
+/// Struct representing a Raydium AMM swap with a fixed output amount.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RaydiumSwapBaseOutEvent {
+    /// Maximum raw amount of the input token accepted.
+    #[serde(deserialize_with = "u64_from_number_or_string")]
+    pub max_in: u64,
+    /// Raw amount of the output token.
+    #[serde(deserialize_with = "u64_from_number_or_string")]
+    pub amount_out: u64,
+    /// Swap direction, `1` for coin to pc and `2` for pc to coin.
+    pub direction: u64,
+    /// Raw balance of the user's source token account before the swap.
+    #[serde(deserialize_with = "u64_from_number_or_string")]
+    pub user_source: u64,
+    /// Raw coin reserves of the pool before the swap.
+    #[serde(deserialize_with = "u64_from_number_or_string")]
+    pub pool_coin: u64,
+    /// Raw pc reserves of the pool before the swap.
+    #[serde(deserialize_with = "u64_from_number_or_string")]
+    pub pool_pc: u64,
+    /// Raw amount of the input token.
+    #[serde(deserialize_with = "u64_from_number_or_string")]
+    pub deduct_in: u64,
+}
 
 /// Enum representing different types of information in the transaction.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub enum Info {
     /// Information about pool creation.
     CreatePool(CreatePool),
@@ -78,96 +519,96 @@ pub enum Info {
     TokenMint(TokenMint),
     /// Information about token transfer.
     TokenTransfer(TokenTransfer),
-    /// Information about PumpfunSwap.
+    /// Information about a token swap, of any protocol despite its name.
     PumpfunSwap(Swap),
 }
 
 /// Struct representing pool creation information.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct CreatePool {
     /// Address of the pool creator.
-    pub pool_creator: String,
+    pub pool_creator: Pubkey,
     /// Address of the liquidity pool.
-    pub liquidity_pool_address: String,
+    pub liquidity_pool_address: Pubkey,
     /// Address of the first token mint.
-    pub token_mint_one: String,
+    pub token_mint_one: Pubkey,
     /// Address of the second token mint.
-    pub token_mint_two: String,
+    pub token_mint_two: Pubkey,
     /// Address of the first token vault.
-    pub token_vault_one: String,
+    pub token_vault_one: Pubkey,
     /// Address of the second token vault.
-    pub token_vault_two: String,
+    pub token_vault_two: Pubkey,
 }
 
 /// Struct representing token creation information.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct TokenCreate {
     /// Address of the created token.
-    pub token_address: String,
+    pub token_address: Pubkey,
 }
 
 /// Struct representing token minting information.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct TokenMint {
     /// Address of the minted token.
-    pub token_address: String,
+    pub token_address: Pubkey,
     /// Amount of tokens minted.
-    pub amount: u64,
+    pub amount: Decimal,
     /// Raw amount of tokens minted.
     pub amount_raw: u64,
     /// Address of the receiver of the minted tokens.
-    pub receiver_address: String,
+    pub receiver_address: Pubkey,
 }
 
 /// Struct representing token transfer information.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct TokenTransfer {
     /// Amount of tokens transferred.
-    pub amount: u64,
+    pub amount: Decimal,
     /// Raw amount of tokens transferred.
     pub amount_raw: u64,
     /// Address of the receiver.
-    pub receiver: String,
+    pub receiver: Pubkey,
     /// Address of the sender.
-    pub sender: String,
+    pub sender: Pubkey,
     /// Associated account of the receiver.
-    pub receiver_associated_account: String,
+    pub receiver_associated_account: Pubkey,
     /// Address of the transferred token.
-    pub token_address: String,
+    pub token_address: Pubkey,
 }
 
 /// Struct representing SOL transfer information.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct SolTransfer {
     /// Address of the sender.
-    pub sender: String,
+    pub sender: Pubkey,
     /// Address of the receiver.
-    pub receiver: String,
+    pub receiver: Pubkey,
     /// Amount of SOL transferred.
-    pub amount: f64,
-    /// Raw amount of SOL transferred.
-    pub amount_raw: f64,
+    pub amount: Decimal,
+    /// Raw amount of SOL transferred, in lamports.
+    pub amount_raw: u64,
 }
 
 /// Struct representing Swap information.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct Swap {
     /// Address of the swapper.
-    pub swapper: String,
+    pub swapper: Pubkey,
     /// Tokens swapped information.
     pub tokens_swapped: TokensSwapped,
     /// List of swaps.
     pub swaps: Vec<serde_json::Value>,
     /// Slippage in percent.
-    pub slippage_in_percent: Option<f64>,
+    pub slippage_in_percent: Option<Decimal>,
     /// Quoted out amount.
-    pub quoted_out_amount: Option<f64>,
+    pub quoted_out_amount: Option<Decimal>,
     /// Slippage paid.
-    pub slippage_paid: Option<f64>,
+    pub slippage_paid: Option<Decimal>,
 }
 
 /// Struct representing tokens swapped.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct TokensSwapped {
     /// Token swapped in.
     #[serde(rename = "in")]
@@ -178,10 +619,10 @@ pub struct TokensSwapped {
 }
 
 /// Struct representing token information.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct TokenInfo {
     /// Address of the token.
-    pub token_address: String,
+    pub token_address: Pubkey,
     /// Name of the token.
     pub name: String,
     /// Symbol of the token.
@@ -189,7 +630,7 @@ pub struct TokenInfo {
     /// Image URI of the token.
     pub image_uri: String,
     /// Amount of the token.
-    pub amount: f64,
+    pub amount: Decimal,
     /// Raw amount of the token.
     pub amount_raw: u64,
 }
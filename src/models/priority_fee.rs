@@ -0,0 +1,95 @@
+/*!
+This module contains the data structures for priority fee estimation.
+
+# Examples
+
+```
+use shyft_rs_sdk::models::priority_fee::PriorityFeeEstimate;
+
+// Prioritization fees of recent slots, in micro-lamports per compute unit
+let fees = [0, 1_000, 5_000, 10_000, 50_000];
+// Compute units consumed by recent transactions
+let compute_units = [150_000, 200_000, 300_000];
+
+let estimate = PriorityFeeEstimate::from_samples(&fees, &compute_units, 75)?;
+assert_eq!(estimate.compute_unit_price, 10_000);
+assert_eq!(estimate.compute_units, Some(300_000));
+assert_eq!(estimate.priority_fee, Some(3_000));
+# Ok::<(), shyft_rs_sdk::Error>(())
+```
+*/
+
+use serde::{Deserialize, Serialize};
+
+/// Struct representing the prioritization fee of a recent slot, as returned by the
+/// `getRecentPrioritizationFees` RPC method.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct RecentPrioritizationFee {
+    /// The slot.
+    pub slot: u64,
+    /// Minimum fee paid by a transaction of the slot, in micro-lamports per compute unit.
+    pub prioritization_fee: u64,
+}
+
+/// Struct representing an estimate of the priority fee of a transaction.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub struct PriorityFeeEstimate {
+    /// Compute unit price to set, in micro-lamports per compute unit.
+    pub compute_unit_price: u64,
+    /// Compute units consumed by recent transactions, if any was found.
+    pub compute_units: Option<u64>,
+    /// Priority fee paid at this price for these compute units, in lamports.
+    pub priority_fee: Option<u64>,
+}
+
+impl PriorityFeeEstimate {
+    /// Estimates a priority fee from samples of recent prioritization fees and compute units.
+    ///
+    /// The compute unit price and the compute units are both taken at the percentile of their
+    /// samples, using the nearest-rank method. A price of `0` is returned if there are no fee
+    /// samples.
+    ///
+    /// # Arguments
+    ///
+    /// * `prioritization_fees` - Recent prioritization fees, in micro-lamports per compute unit.
+    /// * `compute_units` - Compute units consumed by recent transactions.
+    /// * `percentile` - The percentile to estimate, from 0 to 100.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the percentile is greater than 100.
+    pub fn from_samples(
+        prioritization_fees: &[u64],
+        compute_units: &[u64],
+        percentile: u8,
+    ) -> Result<Self, crate::Error> {
+        if percentile > 100 {
+            return Err(crate::Error::InvalidRequest(format!(
+                "percentile must be at most 100, got {percentile}"
+            )));
+        }
+
+        let compute_unit_price = nearest_rank(prioritization_fees, percentile).unwrap_or(0);
+        let compute_units = nearest_rank(compute_units, percentile);
+        // The compute unit price is in micro-lamports, rounded up to the next lamport
+        let priority_fee = compute_units.map(|compute_units| {
+            let micro_lamports = u128::from(compute_unit_price) * u128::from(compute_units);
+            u64::try_from(micro_lamports.div_ceil(1_000_000)).unwrap_or(u64::MAX)
+        });
+
+        Ok(Self {
+            compute_unit_price,
+            compute_units,
+            priority_fee,
+        })
+    }
+}
+
+/// Returns the value at a percentile of some samples, using the nearest-rank method.
+fn nearest_rank(samples: &[u64], percentile: u8) -> Option<u64> {
+    let mut samples = samples.to_vec();
+    samples.sort_unstable();
+    let rank = (samples.len() * usize::from(percentile)).div_ceil(100);
+    samples.get(rank.saturating_sub(1)).copied()
+}
@@ -0,0 +1,357 @@
+/*!
+This module contains the data structures for raw transactions, returned when `enable_raw` is set.
+
+The structures mirror the JSON returned by Solana's [`getTransaction`] RPC method, in both the
+`json` and `jsonParsed` encodings.
+
+[`getTransaction`]: https://solana.com/docs/rpc/http/gettransaction
+
+# Examples
+
+```
+use shyft_rs_sdk::models::raw_transaction::{Instruction, RawTransaction};
+
+let raw: RawTransaction = serde_json::from_value(serde_json::json!({
+    "blockTime": 1724668726,
+    "slot": 284512345,
+    "meta": {
+        "err": null,
+        "fee": 5000,
+        "preBalances": [1000000000, 0],
+        "postBalances": [899995000, 100000000],
+        "logMessages": ["Program 11111111111111111111111111111111 invoke [1]"],
+        "computeUnitsConsumed": 150
+    },
+    "transaction": {
+        "signatures": [
+            "uZz2qDvLzndsTEY31YkxgRe1rYQ8MsCtS3DwhPYP1Md7u7dnUK4HW3vYzsxE6GSxFhSG5zpvqSQnSUn1sPmzTBu"
+        ],
+        "message": {
+            "header": {
+                "numRequiredSignatures": 1,
+                "numReadonlySignedAccounts": 0,
+                "numReadonlyUnsignedAccounts": 1
+            },
+            "accountKeys": [
+                "8R5brRqNa1CDMtcQRaLPQfJeLBrtyqpjDPTSKbBvmsna",
+                "5Q544fKrFoe6tsEbD7S8EmxGTJYAKtTVhAW5Q5pge4j1",
+                "11111111111111111111111111111111"
+            ],
+            "recentBlockhash": "EkSnNWid2cvwEVnVx9aBqawnmiCNiDgp3gUdkDPTKN1N",
+            "instructions": [
+                { "programIdIndex": 2, "accounts": [0, 1], "data": "3Bxs411Dtc7pkFQj" }
+            ]
+        }
+    },
+    "version": "legacy"
+}))?;
+
+assert_eq!(raw.account_keys().len(), 3);
+assert!(matches!(raw.transaction.message.instructions[0], Instruction::Compiled(_)));
+assert_eq!(raw.meta.unwrap().compute_units_consumed, Some(150));
+# Ok::<(), serde_json::Error>(())
+```
+*/
+
+use serde::{Deserialize, Serialize};
+
+use super::serde_helpers::u64_from_number_or_string;
+use crate::{Decimal, Pubkey, Signature};
+
+/// Struct representing a raw transaction.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct RawTransaction {
+    /// Unix timestamp of when the transaction was processed.
+    pub block_time: Option<i64>,
+    /// Slot the transaction was processed in.
+    pub slot: u64,
+    /// Transaction status metadata.
+    pub meta: Option<TransactionMeta>,
+    /// The transaction itself.
+    pub transaction: Transaction,
+    /// Version of the transaction message.
+    #[serde(default)]
+    pub version: Option<TransactionVersion>,
+}
+
+impl RawTransaction {
+    /// Returns all the account keys of the transaction, in the order referenced by
+    /// instructions and balances.
+    ///
+    /// For versioned transactions in the `json` encoding, the addresses loaded from address
+    /// lookup tables are appended to the static account keys, writable ones first.
+    pub fn account_keys(&self) -> Vec<Pubkey> {
+        let message = &self.transaction.message;
+        let mut account_keys: Vec<Pubkey> = message
+            .account_keys
+            .iter()
+            .map(AccountKey::pubkey)
+            .collect();
+
+        let keys_are_parsed = message
+            .account_keys
+            .iter()
+            .any(|key| matches!(key, AccountKey::Parsed(_)));
+
+        if !keys_are_parsed {
+            if let Some(loaded_addresses) = self
+                .meta
+                .as_ref()
+                .and_then(|meta| meta.loaded_addresses.as_ref())
+            {
+                account_keys.extend(&loaded_addresses.writable);
+                account_keys.extend(&loaded_addresses.readonly);
+            }
+        }
+
+        account_keys
+    }
+
+    /// Returns the first signature of the transaction, which identifies it.
+    pub fn signature(&self) -> Option<&Signature> {
+        self.transaction.signatures.first()
+    }
+}
+
+/// Enum representing the version of a transaction message.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(untagged)]
+pub enum TransactionVersion {
+    /// A legacy transaction.
+    Legacy(Legacy),
+    /// A versioned transaction.
+    Number(u8),
+}
+
+/// Marker for the `"legacy"` transaction version.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Legacy {
+    /// A legacy transaction.
+    Legacy,
+}
+
+/// Struct representing a signed transaction.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Transaction {
+    /// Signatures of the transaction.
+    pub signatures: Vec<Signature>,
+    /// Message of the transaction.
+    pub message: Message,
+}
+
+/// Struct representing a transaction message.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Message {
+    /// Message header, only present in the `json` encoding.
+    #[serde(default)]
+    pub header: Option<MessageHeader>,
+    /// Account keys used by the transaction.
+    pub account_keys: Vec<AccountKey>,
+    /// Recent blockhash of the transaction.
+    pub recent_blockhash: String,
+    /// Top-level instructions of the transaction.
+    pub instructions: Vec<Instruction>,
+    /// Address lookup tables used by a versioned transaction.
+    #[serde(default)]
+    pub address_table_lookups: Option<Vec<AddressTableLookup>>,
+}
+
+/// Struct representing the header of a transaction message.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct MessageHeader {
+    /// Number of signatures required for the transaction to be valid.
+    pub num_required_signatures: u8,
+    /// Number of read-only signed accounts.
+    pub num_readonly_signed_accounts: u8,
+    /// Number of read-only unsigned accounts.
+    pub num_readonly_unsigned_accounts: u8,
+}
+
+/// Enum representing an account key of a transaction message.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(untagged)]
+pub enum AccountKey {
+    /// An account key in the `jsonParsed` encoding.
+    Parsed(ParsedAccountKey),
+    /// An account key in the `json` encoding.
+    Plain(Pubkey),
+}
+
+impl AccountKey {
+    /// Returns the address of the account.
+    pub fn pubkey(&self) -> Pubkey {
+        match self {
+            AccountKey::Parsed(key) => key.pubkey,
+            AccountKey::Plain(pubkey) => *pubkey,
+        }
+    }
+}
+
+/// Struct representing an account key in the `jsonParsed` encoding.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ParsedAccountKey {
+    /// Address of the account.
+    pub pubkey: Pubkey,
+    /// Whether the account signed the transaction.
+    pub signer: bool,
+    /// Whether the account is writable.
+    pub writable: bool,
+    /// Where the account key comes from, `transaction` or `lookupTable`.
+    pub source: Option<String>,
+}
+
+/// Struct representing an address lookup table used by a versioned transaction.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AddressTableLookup {
+    /// Address of the lookup table.
+    pub account_key: Pubkey,
+    /// Indexes of the writable addresses loaded from the table.
+    pub writable_indexes: Vec<u8>,
+    /// Indexes of the read-only addresses loaded from the table.
+    pub readonly_indexes: Vec<u8>,
+}
+
+/// Enum representing an instruction of a transaction.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(untagged)]
+pub enum Instruction {
+    /// An instruction in the `json` encoding.
+    Compiled(CompiledInstruction),
+    /// An instruction parsed by the RPC node, in the `jsonParsed` encoding.
+    Parsed(ParsedInstruction),
+    /// An instruction the RPC node could not parse, in the `jsonParsed` encoding.
+    PartiallyDecoded(PartiallyDecodedInstruction),
+}
+
+/// Struct representing an instruction in the `json` encoding.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CompiledInstruction {
+    /// Index of the program in the account keys.
+    pub program_id_index: u8,
+    /// Indexes of the accounts in the account keys.
+    pub accounts: Vec<u8>,
+    /// Base58-encoded instruction data.
+    pub data: String,
+    /// Invocation depth of the instruction.
+    #[serde(default)]
+    pub stack_height: Option<u32>,
+}
+
+/// Struct representing an instruction parsed by the RPC node.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ParsedInstruction {
+    /// Name of the program.
+    pub program: String,
+    /// Address of the program.
+    pub program_id: Pubkey,
+    /// Parsed instruction.
+    pub parsed: serde_json::Value,
+    /// Invocation depth of the instruction.
+    #[serde(default)]
+    pub stack_height: Option<u32>,
+}
+
+/// Struct representing an instruction the RPC node could not parse.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PartiallyDecodedInstruction {
+    /// Address of the program.
+    pub program_id: Pubkey,
+    /// Addresses of the accounts.
+    pub accounts: Vec<Pubkey>,
+    /// Base58-encoded instruction data.
+    pub data: String,
+    /// Invocation depth of the instruction.
+    #[serde(default)]
+    pub stack_height: Option<u32>,
+}
+
+/// Struct representing the inner instructions invoked by a top-level instruction.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct InnerInstructions {
+    /// Index of the top-level instruction.
+    pub index: u8,
+    /// Instructions invoked by the top-level instruction.
+    pub instructions: Vec<Instruction>,
+}
+
+/// Struct representing the status metadata of a transaction.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionMeta {
+    /// Error of the transaction, `None` if it succeeded.
+    pub err: Option<serde_json::Value>,
+    /// Fee paid by the transaction, in lamports.
+    pub fee: u64,
+    /// Lamport balances of the accounts before the transaction.
+    pub pre_balances: Vec<u64>,
+    /// Lamport balances of the accounts after the transaction.
+    pub post_balances: Vec<u64>,
+    /// Inner instructions of the transaction.
+    #[serde(default)]
+    pub inner_instructions: Option<Vec<InnerInstructions>>,
+    /// Token balances of the accounts before the transaction.
+    #[serde(default)]
+    pub pre_token_balances: Option<Vec<TokenBalance>>,
+    /// Token balances of the accounts after the transaction.
+    #[serde(default)]
+    pub post_token_balances: Option<Vec<TokenBalance>>,
+    /// Log messages of the transaction.
+    #[serde(default)]
+    pub log_messages: Option<Vec<String>>,
+    /// Addresses loaded from address lookup tables.
+    #[serde(default)]
+    pub loaded_addresses: Option<LoadedAddresses>,
+    /// Compute units consumed by the transaction.
+    #[serde(default)]
+    pub compute_units_consumed: Option<u64>,
+}
+
+/// Struct representing the addresses loaded from address lookup tables.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct LoadedAddresses {
+    /// Writable loaded addresses.
+    pub writable: Vec<Pubkey>,
+    /// Read-only loaded addresses.
+    pub readonly: Vec<Pubkey>,
+}
+
+/// Struct representing the balance of a token account.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenBalance {
+    /// Index of the token account in the account keys.
+    pub account_index: u8,
+    /// Address of the token.
+    pub mint: Pubkey,
+    /// Owner of the token account.
+    #[serde(default)]
+    pub owner: Option<Pubkey>,
+    /// Token program owning the token account.
+    #[serde(default)]
+    pub program_id: Option<Pubkey>,
+    /// Balance of the token account.
+    pub ui_token_amount: UiTokenAmount,
+}
+
+/// Struct representing a token amount.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct UiTokenAmount {
+    /// Raw amount of tokens.
+    #[serde(deserialize_with = "u64_from_number_or_string")]
+    pub amount: u64,
+    /// Decimals of the token.
+    pub decimals: u8,
+    /// Amount of tokens.
+    pub ui_amount: Option<Decimal>,
+    /// Amount of tokens, as a string.
+    pub ui_amount_string: String,
+}
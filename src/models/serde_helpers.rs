@@ -0,0 +1,26 @@
+//! Serde helpers shared by the model structs.
+
+use serde::{Deserialize, Deserializer};
+
+/// Deserializes a `u64` that may be encoded either as a JSON number or as a string.
+///
+/// Anchor events often encode large integers as strings to avoid precision loss.
+pub(crate) fn u64_from_number_or_string<'de, D>(deserializer: D) -> Result<u64, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    /// Either representation of the integer.
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum NumberOrString {
+        /// Integer encoded as a JSON number.
+        Number(u64),
+        /// Integer encoded as a string.
+        String(String),
+    }
+
+    match NumberOrString::deserialize(deserializer)? {
+        NumberOrString::Number(number) => Ok(number),
+        NumberOrString::String(string) => string.parse().map_err(serde::de::Error::custom),
+    }
+}
@@ -0,0 +1,98 @@
+//! This module provides priority fee estimation for transactions writing to some accounts.
+//!
+//! The compute unit price is estimated from the prioritization fees paid in recent slots by
+//! transactions locking the accounts, as returned by the `getRecentPrioritizationFees` method of
+//! the Shyft RPC. The compute units are estimated from the recent transactions of the accounts,
+//! fetched with their raw details from the transaction history.
+
+use futures::{future, stream, StreamExt};
+use serde_json::json;
+
+use crate::{
+    constants,
+    models::priority_fee::{PriorityFeeEstimate, RecentPrioritizationFee},
+    requests::TransactionHistoryRequest,
+    Pubkey, ShyftApi,
+};
+
+impl ShyftApi {
+    /// Estimates the priority fee of a transaction writing to some accounts.
+    ///
+    /// See [`PriorityFeeEstimate::from_samples`] for how the percentile is applied. The
+    /// estimate is computed from the prioritization fees of recent slots for transactions
+    /// locking the accounts, and the compute units consumed by the latest transactions of each
+    /// account. The histories are fetched a few at a time, and those that fail are left out of
+    /// the compute unit samples.
+    ///
+    /// # Arguments
+    ///
+    /// * `accounts` - The writable accounts of the transaction, at most 128.
+    /// * `percentile` - The percentile to estimate, from 0 to 100.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the arguments are invalid or the prioritization fees
+    /// request fails.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), shyft_rs_sdk::Error> {
+    /// # use shyft_rs_sdk::ShyftApi;
+    /// #
+    /// # let api_key = "your_api_key";
+    /// # let client = ShyftApi::new(api_key, None, None, None, None, None).unwrap();
+    /// let pool = "58oQChx4yWmvKdwLLZzBi4ChoCc2fqCUWBkwMihLYQo2".parse()?;
+    /// let estimate = client.estimate_priority_fee(&[pool], 75).await?;
+    /// println!("Set a compute unit price of {} micro-lamports", estimate.compute_unit_price);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn estimate_priority_fee(
+        &self,
+        accounts: &[Pubkey],
+        percentile: u8,
+    ) -> Result<PriorityFeeEstimate, crate::error::Error> {
+        if accounts.len() > constants::MAX_PRIORITIZATION_FEE_ACCOUNTS {
+            return Err(crate::error::Error::InvalidRequest(format!(
+                "at most {} accounts are allowed, got {}",
+                constants::MAX_PRIORITIZATION_FEE_ACCOUNTS,
+                accounts.len()
+            )));
+        }
+        // Validate the percentile before sending any request
+        PriorityFeeEstimate::from_samples(&[], &[], percentile)?;
+
+        let fees = self
+            .rpc::<Vec<RecentPrioritizationFee>>("getRecentPrioritizationFees", json!([accounts]));
+        let histories = stream::iter(accounts)
+            .map(|account| {
+                let request = TransactionHistoryRequest::new(*account)
+                    .tx_num(constants::COMPUTE_UNIT_SAMPLE_TX_NUM)
+                    .enable_raw(true);
+                async move { self.execute(&request).await }
+            })
+            .buffer_unordered(constants::COMPUTE_UNIT_SAMPLE_CONCURRENCY)
+            .filter_map(|history| future::ready(history.ok()))
+            .collect::<Vec<_>>();
+        let (fees, histories) = future::join(fees, histories).await;
+        let fees = fees?;
+
+        let fees: Vec<u64> = fees.iter().map(|fee| fee.prioritization_fee).collect();
+        let compute_units: Vec<u64> = histories
+            .iter()
+            .flatten()
+            .filter_map(|transaction| {
+                transaction
+                    .raw
+                    .as_ref()?
+                    .meta
+                    .as_ref()?
+                    .compute_units_consumed
+            })
+            .collect();
+
+        PriorityFeeEstimate::from_samples(&fees, &compute_units, percentile)
+    }
+}
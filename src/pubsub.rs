@@ -0,0 +1,793 @@
+/*!
+This module provides Solana PubSub subscriptions over the WebSocket RPC of Shyft.
+
+A [`PubSubClient`] multiplexes any number of subscriptions over a single WebSocket connection.
+Each subscription is a typed [`Subscription`] stream, and dropping it unsubscribes. When the
+connection is lost, the error is emitted on every stream, then the client reconnects with an
+exponential backoff and subscribes again to everything that is still subscribed. Notifications
+sent while disconnected are missed.
+
+[`PubSubClient::parsed_transactions`] turns log notifications into full
+[`ParsedTransactionDetails`], by calling [`ShyftApi::get_transaction_parsed`] for the
+transactions whose logs match a trigger.
+
+# Examples
+
+```no_run
+# #[tokio::main]
+# async fn main() -> Result<(), shyft_rs_sdk::Error> {
+use futures::StreamExt;
+use shyft_rs_sdk::{
+    pubsub::{LogsFilter, PubSubClient},
+    Commitment, ShyftApi,
+};
+
+let api_key = "your_api_key";
+let client = ShyftApi::new(api_key, None, None, None, None, None)?;
+let pubsub = PubSubClient::connect(api_key, None).await?;
+
+// Fetch the details of every swap made with Jupiter
+let jupiter = "JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4".parse()?;
+let mut swaps = Box::pin(pubsub.parsed_transactions(
+    &client,
+    LogsFilter::Mentions(jupiter),
+    Some(Commitment::Confirmed),
+    |logs| logs.err.is_none() && logs.logs.iter().any(|log| log.contains("Instruction: Route")),
+));
+while let Some(swap) = swaps.next().await {
+    match swap {
+        Ok(swap) => println!("{} {}", swap.transaction_type, swap.signatures[0]),
+        Err(error) => eprintln!("{}", error),
+    }
+}
+# Ok(())
+# }
+```
+*/
+
+use std::{
+    collections::HashMap,
+    fmt,
+    marker::PhantomData,
+    pin::Pin,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use base64::Engine;
+use futures::{channel::mpsc, future, FutureExt, SinkExt, Stream, StreamExt};
+use serde::{de::DeserializeOwned, Deserialize, Deserializer};
+use serde_json::{json, Value};
+use tokio_tungstenite::{tungstenite, MaybeTlsStream, WebSocketStream};
+
+use crate::{
+    constants, error::Error, models::parsed_transaction_details::ParsedTransactionDetails,
+    time::Instant, Commitment, Network, Pubkey, ShyftApi, Signature,
+};
+
+/// An open WebSocket connection.
+type Socket = WebSocketStream<MaybeTlsStream<tokio::net::TcpStream>>;
+
+/// Struct representing a client of the Solana PubSub WebSocket API.
+///
+/// Clones share the same connection, which is closed once every clone and subscription is
+/// dropped.
+#[derive(Debug, Clone)]
+pub struct PubSubClient {
+    /// Sender of the commands to the task driving the connection.
+    commands: mpsc::UnboundedSender<Command>,
+    /// Identifier of the next subscription.
+    next_id: Arc<AtomicU64>,
+}
+
+impl PubSubClient {
+    /// Connects to the WebSocket RPC of Shyft.
+    ///
+    /// Must be called from within a tokio runtime, which drives the connection.
+    ///
+    /// # Arguments
+    ///
+    /// * `api_key` - Your Shyft API key.
+    /// * `network` - The network to subscribe to, defaulting to mainnet-beta.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the connection fails.
+    pub async fn connect(api_key: &str, network: Option<Network>) -> Result<Self, Error> {
+        let url = match network.unwrap_or(Network::MainnetBeta) {
+            Network::MainnetBeta => constants::WS_RPC_URL,
+            Network::Devnet => constants::WS_RPC_URL_DEVNET,
+            Network::Testnet => constants::WS_RPC_URL_TESTNET,
+        };
+        let mut url =
+            reqwest::Url::parse(url).map_err(|error| Error::InvalidRequest(error.to_string()))?;
+        url.query_pairs_mut()
+            .append_pair(crate::key_pool::API_KEY_PARAM, api_key);
+        Self::connect_url(url.as_str()).await
+    }
+
+    /// Connects to any Solana PubSub WebSocket endpoint.
+    ///
+    /// Must be called from within a tokio runtime, which drives the connection.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the connection fails.
+    pub async fn connect_url(url: &str) -> Result<Self, Error> {
+        let (socket, _) = tokio_tungstenite::connect_async(url).await?;
+        let (commands, receiver) = mpsc::unbounded();
+
+        let driver = Driver {
+            url: url.to_string(),
+            commands: receiver,
+            entries: HashMap::new(),
+            pending: HashMap::new(),
+            next_request_id: 0,
+            failures: 0,
+        };
+        tokio::spawn(driver.run(socket));
+
+        Ok(Self {
+            commands,
+            next_id: Arc::new(AtomicU64::new(0)),
+        })
+    }
+
+    /// Subscribes to the changes of an account. Equivalent to [`accountSubscribe`].
+    ///
+    /// [`accountSubscribe`]: https://solana.com/docs/rpc/websocket/accountsubscribe
+    ///
+    /// # Arguments
+    ///
+    /// * `account` - The account to watch.
+    /// * `commitment` - An optional commitment level, defaulting to the one of the node.
+    pub fn account_subscribe(
+        &self,
+        account: Pubkey,
+        commitment: Option<Commitment>,
+    ) -> Subscription<RpcResponse<AccountInfo>> {
+        self.subscribe(
+            "accountSubscribe",
+            json!([account, account_config(commitment)]),
+            false,
+        )
+    }
+
+    /// Subscribes to the logs of transactions. Equivalent to [`logsSubscribe`].
+    ///
+    /// [`logsSubscribe`]: https://solana.com/docs/rpc/websocket/logssubscribe
+    ///
+    /// # Arguments
+    ///
+    /// * `filter` - The transactions to receive the logs of.
+    /// * `commitment` - An optional commitment level, defaulting to the one of the node.
+    pub fn logs_subscribe(
+        &self,
+        filter: LogsFilter,
+        commitment: Option<Commitment>,
+    ) -> Subscription<RpcResponse<Logs>> {
+        self.subscribe(
+            "logsSubscribe",
+            json!([filter.to_param(), commitment_config(commitment)]),
+            false,
+        )
+    }
+
+    /// Subscribes to the changes of the accounts owned by a program. Equivalent to
+    /// [`programSubscribe`].
+    ///
+    /// [`programSubscribe`]: https://solana.com/docs/rpc/websocket/programsubscribe
+    ///
+    /// # Arguments
+    ///
+    /// * `program_id` - The program owning the accounts.
+    /// * `commitment` - An optional commitment level, defaulting to the one of the node.
+    pub fn program_subscribe(
+        &self,
+        program_id: Pubkey,
+        commitment: Option<Commitment>,
+    ) -> Subscription<RpcResponse<ProgramAccount>> {
+        self.subscribe(
+            "programSubscribe",
+            json!([program_id, account_config(commitment)]),
+            false,
+        )
+    }
+
+    /// Subscribes to the status of a transaction. Equivalent to [`signatureSubscribe`].
+    ///
+    /// The stream ends after the first notification, once the transaction reached the
+    /// commitment level.
+    ///
+    /// [`signatureSubscribe`]: https://solana.com/docs/rpc/websocket/signaturesubscribe
+    ///
+    /// # Arguments
+    ///
+    /// * `signature` - The signature of the transaction.
+    /// * `commitment` - An optional commitment level, defaulting to the one of the node.
+    pub fn signature_subscribe(
+        &self,
+        signature: Signature,
+        commitment: Option<Commitment>,
+    ) -> Subscription<RpcResponse<SignatureStatus>> {
+        self.subscribe(
+            "signatureSubscribe",
+            json!([signature, commitment_config(commitment)]),
+            true,
+        )
+    }
+
+    /// Subscribes to the slots processed by the node. Equivalent to [`slotSubscribe`].
+    ///
+    /// [`slotSubscribe`]: https://solana.com/docs/rpc/websocket/slotsubscribe
+    pub fn slot_subscribe(&self) -> Subscription<SlotInfo> {
+        self.subscribe("slotSubscribe", json!([]), false)
+    }
+
+    /// Subscribes to the logs of transactions, and fetches the parsed details of the ones
+    /// matching a trigger.
+    ///
+    /// The details are fetched one at a time, in the order of the notifications, with
+    /// [`ShyftApi::get_transaction_parsed`].
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - The client fetching the parsed transaction details.
+    /// * `filter` - The transactions to receive the logs of.
+    /// * `commitment` - An optional commitment level, defaulting to the one of the node.
+    /// * `trigger` - Returns `true` for the logs of the transactions to fetch.
+    pub fn parsed_transactions(
+        &self,
+        client: &ShyftApi,
+        filter: LogsFilter,
+        commitment: Option<Commitment>,
+        mut trigger: impl FnMut(&Logs) -> bool,
+    ) -> impl Stream<Item = Result<ParsedTransactionDetails, Error>> {
+        let client = client.clone();
+        self.logs_subscribe(filter, commitment)
+            .filter(move |logs| {
+                future::ready(match logs {
+                    Ok(logs) => trigger(&logs.value),
+                    Err(_) => true,
+                })
+            })
+            .then(move |logs| {
+                let client = client.clone();
+                async move { client.get_transaction_parsed(&logs?.value.signature).await }
+            })
+    }
+
+    /// Sends a subscription to the task driving the connection.
+    fn subscribe<T>(&self, method: &'static str, params: Value, one_shot: bool) -> Subscription<T> {
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        let (sender, receiver) = mpsc::unbounded();
+
+        // If the task stopped, the sender is dropped and the stream ends
+        let _ = self.commands.unbounded_send(Command::Subscribe {
+            id,
+            entry: Entry {
+                method,
+                params,
+                one_shot,
+                sender,
+                server_id: None,
+            },
+        });
+
+        Subscription {
+            id,
+            receiver,
+            commands: self.commands.clone(),
+            item: PhantomData,
+        }
+    }
+}
+
+/// Returns the configuration of an account subscription, with base64-encoded data.
+fn account_config(commitment: Option<Commitment>) -> Value {
+    let mut config = commitment_config(commitment);
+    config["encoding"] = json!("base64");
+    config
+}
+
+/// Returns the configuration of a subscription with a commitment level.
+fn commitment_config(commitment: Option<Commitment>) -> Value {
+    match commitment {
+        Some(commitment) => json!({ "commitment": commitment.to_string() }),
+        None => json!({}),
+    }
+}
+
+/// Struct representing a subscription, as a stream of notifications.
+///
+/// Dropping the stream unsubscribes.
+pub struct Subscription<T> {
+    /// Identifier of the subscription.
+    id: u64,
+    /// Receiver of the notifications.
+    receiver: mpsc::UnboundedReceiver<Result<Value, Error>>,
+    /// Sender of the commands to the task driving the connection.
+    commands: mpsc::UnboundedSender<Command>,
+    /// The type of the notifications.
+    item: PhantomData<fn() -> T>,
+}
+
+impl<T> fmt::Debug for Subscription<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Subscription")
+            .field("id", &self.id)
+            .finish()
+    }
+}
+
+impl<T: DeserializeOwned> Stream for Subscription<T> {
+    type Item = Result<T, Error>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.receiver
+            .poll_next_unpin(cx)
+            .map(|notification| notification.map(|result| Ok(serde_json::from_value(result?)?)))
+    }
+}
+
+impl<T> Drop for Subscription<T> {
+    fn drop(&mut self) {
+        let _ = self
+            .commands
+            .unbounded_send(Command::Unsubscribe { id: self.id });
+    }
+}
+
+/// Enum representing the transactions to receive the logs of.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogsFilter {
+    /// Every transaction except votes.
+    All,
+    /// Every transaction, including votes.
+    AllWithVotes,
+    /// The transactions mentioning an account.
+    Mentions(Pubkey),
+}
+
+impl LogsFilter {
+    /// Returns the filter as a parameter of `logsSubscribe`.
+    fn to_param(self) -> Value {
+        match self {
+            LogsFilter::All => json!("all"),
+            LogsFilter::AllWithVotes => json!("allWithVotes"),
+            LogsFilter::Mentions(account) => json!({ "mentions": [account] }),
+        }
+    }
+}
+
+/// Struct representing a notification with the slot it was sent at.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct RpcResponse<T> {
+    /// Context of the notification.
+    pub context: RpcContext,
+    /// The notification itself.
+    pub value: T,
+}
+
+/// Struct representing the context of a notification.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub struct RpcContext {
+    /// Slot the notification was sent at.
+    pub slot: u64,
+}
+
+/// Struct representing the state of an account.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountInfo {
+    /// Balance of the account, in lamports.
+    pub lamports: u64,
+    /// Program owning the account.
+    pub owner: Pubkey,
+    /// Data of the account.
+    #[serde(deserialize_with = "base64_data")]
+    pub data: Vec<u8>,
+    /// Whether the account is a program.
+    pub executable: bool,
+    /// Epoch at which the account next owes rent.
+    pub rent_epoch: u64,
+    /// Size of the data of the account.
+    #[serde(default)]
+    pub space: Option<u64>,
+}
+
+/// Struct representing an account owned by a program.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct ProgramAccount {
+    /// Address of the account.
+    pub pubkey: Pubkey,
+    /// State of the account.
+    pub account: AccountInfo,
+}
+
+/// Struct representing the logs of a transaction.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Logs {
+    /// Signature of the transaction.
+    pub signature: Signature,
+    /// Error of the transaction, `None` if it succeeded.
+    pub err: Option<Value>,
+    /// Log messages of the transaction.
+    pub logs: Vec<String>,
+}
+
+/// Struct representing the status of a transaction which reached a commitment level.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct SignatureStatus {
+    /// Error of the transaction, `None` if it succeeded.
+    pub err: Option<Value>,
+}
+
+/// Struct representing a slot processed by the node.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub struct SlotInfo {
+    /// The slot.
+    pub slot: u64,
+    /// Parent of the slot.
+    pub parent: u64,
+    /// Current root slot.
+    pub root: u64,
+}
+
+/// Deserializes base64-encoded account data, sent as `[data, "base64"]`.
+fn base64_data<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+    let (data, _encoding): (String, String) = Deserialize::deserialize(deserializer)?;
+    base64::engine::general_purpose::STANDARD
+        .decode(data)
+        .map_err(serde::de::Error::custom)
+}
+
+/// Command sent to the task driving the connection.
+enum Command {
+    /// Subscribes, now or after the next reconnect.
+    Subscribe {
+        /// Identifier of the subscription.
+        id: u64,
+        /// The subscription.
+        entry: Entry,
+    },
+    /// Unsubscribes, if subscribed.
+    Unsubscribe {
+        /// Identifier of the subscription.
+        id: u64,
+    },
+}
+
+/// State of a subscription in the task driving the connection.
+struct Entry {
+    /// Subscription method, such as `accountSubscribe`.
+    method: &'static str,
+    /// Parameters of the subscription method.
+    params: Value,
+    /// Whether the node ends the subscription after the first notification.
+    one_shot: bool,
+    /// Sender of the notifications.
+    sender: mpsc::UnboundedSender<Result<Value, Error>>,
+    /// Identifier of the subscription on the node, once confirmed.
+    server_id: Option<u64>,
+}
+
+impl Entry {
+    /// Returns the unsubscription method, such as `accountUnsubscribe`.
+    fn unsubscribe_method(&self) -> String {
+        self.method.replace("Subscribe", "Unsubscribe")
+    }
+}
+
+/// Request awaiting a response from the node.
+enum Pending {
+    /// A subscription request.
+    Subscribe {
+        /// Identifier of the subscription.
+        id: u64,
+        /// Method to unsubscribe with, if the subscription was dropped before its response.
+        unsubscribe_method: String,
+    },
+    /// An unsubscription request.
+    Unsubscribe,
+}
+
+/// Message sent by the node, either a response or a notification.
+#[derive(Deserialize)]
+struct RpcMessage {
+    /// Identifier of the request, for a response.
+    id: Option<u64>,
+    /// Result of the request, for a successful response.
+    result: Option<Value>,
+    /// Error of the request, for a failed response.
+    error: Option<Value>,
+    /// Parameters of a notification.
+    params: Option<NotificationParams>,
+}
+
+/// Parameters of a notification.
+#[derive(Deserialize)]
+struct NotificationParams {
+    /// The notification.
+    result: Value,
+    /// Identifier of the subscription on the node.
+    subscription: u64,
+}
+
+/// Task driving the connection, reconnecting and resubscribing when it is lost.
+struct Driver {
+    /// URL of the WebSocket endpoint.
+    url: String,
+    /// Receiver of the commands of the clients and subscriptions.
+    commands: mpsc::UnboundedReceiver<Command>,
+    /// The subscriptions, by identifier.
+    entries: HashMap<u64, Entry>,
+    /// The requests awaiting a response, by request identifier.
+    pending: HashMap<u64, Pending>,
+    /// Identifier of the next request.
+    next_request_id: u64,
+    /// Number of consecutive connection failures, reset when a message is received.
+    failures: u32,
+}
+
+impl Driver {
+    /// Drives the connection until every client and subscription is dropped.
+    async fn run(mut self, socket: Socket) {
+        let mut socket = Some(socket);
+
+        loop {
+            let mut socket = match socket.take() {
+                Some(socket) => socket,
+                None => {
+                    crate::time::sleep(reconnect_interval(self.failures)).await;
+                    if !self.drain_commands() {
+                        return;
+                    }
+                    match tokio_tungstenite::connect_async(&self.url).await {
+                        Ok((socket, _)) => socket,
+                        Err(error) => {
+                            self.failures += 1;
+                            self.broadcast(Arc::new(error));
+                            continue;
+                        }
+                    }
+                }
+            };
+
+            match self.serve(&mut socket).await {
+                Ok(()) => return,
+                Err(error) => {
+                    self.failures += 1;
+                    self.broadcast(Arc::new(error));
+                }
+            }
+        }
+    }
+
+    /// Subscribes to everything again, then relays messages until the connection is lost.
+    ///
+    /// Returns `Ok` once every client and subscription is dropped.
+    async fn serve(&mut self, socket: &mut Socket) -> Result<(), tungstenite::Error> {
+        self.pending.clear();
+        let ids: Vec<u64> = self.entries.keys().copied().collect();
+        for id in ids {
+            self.send_subscribe(socket, id).await?;
+        }
+
+        let mut ping = tokio::time::interval(constants::WS_PING_INTERVAL);
+        let mut last_received = Instant::now();
+
+        loop {
+            tokio::select! {
+                command = self.commands.next() => {
+                    let Some(command) = command else {
+                        let _ = socket.close(None).await;
+                        return Ok(());
+                    };
+                    self.apply(socket, command).await?;
+                }
+                message = socket.next() => {
+                    last_received = Instant::now();
+                    match message {
+                        Some(Ok(tungstenite::Message::Text(text))) => {
+                            self.failures = 0;
+                            self.handle(socket, &text).await?;
+                        }
+                        Some(Ok(tungstenite::Message::Close(_))) | None => {
+                            return Err(tungstenite::Error::ConnectionClosed);
+                        }
+                        Some(Err(error)) => return Err(error),
+                        Some(Ok(_)) => {}
+                    }
+                }
+                _ = ping.tick() => {
+                    // The pongs stopped, the connection is dead
+                    if last_received.elapsed() > 2 * constants::WS_PING_INTERVAL {
+                        return Err(tungstenite::Error::Io(std::io::ErrorKind::TimedOut.into()));
+                    }
+                    socket.send(tungstenite::Message::Ping(Vec::new())).await?;
+                }
+            }
+        }
+    }
+
+    /// Applies a command while connected.
+    async fn apply(
+        &mut self,
+        socket: &mut Socket,
+        command: Command,
+    ) -> Result<(), tungstenite::Error> {
+        match command {
+            Command::Subscribe { id, entry } => {
+                self.entries.insert(id, entry);
+                self.send_subscribe(socket, id).await
+            }
+            Command::Unsubscribe { id } => match self.entries.remove(&id) {
+                Some(entry) => self.send_unsubscribe(socket, &entry).await,
+                None => Ok(()),
+            },
+        }
+    }
+
+    /// Applies the commands received while disconnected.
+    ///
+    /// Returns `false` once every client and subscription is dropped.
+    fn drain_commands(&mut self) -> bool {
+        loop {
+            match self.commands.next().now_or_never() {
+                Some(Some(Command::Subscribe { id, entry })) => {
+                    self.entries.insert(id, entry);
+                }
+                Some(Some(Command::Unsubscribe { id })) => {
+                    self.entries.remove(&id);
+                }
+                Some(None) => return false,
+                None => return true,
+            }
+        }
+    }
+
+    /// Handles a message sent by the node.
+    async fn handle(&mut self, socket: &mut Socket, text: &str) -> Result<(), tungstenite::Error> {
+        let Ok(message) = serde_json::from_str::<RpcMessage>(text) else {
+            return Ok(());
+        };
+
+        if let Some(params) = message.params {
+            let Some((&id, entry)) = self
+                .entries
+                .iter()
+                .find(|(_, entry)| entry.server_id == Some(params.subscription))
+            else {
+                return Ok(());
+            };
+
+            if entry.sender.unbounded_send(Ok(params.result)).is_err() {
+                // The subscription was dropped, its unsubscribe command is on its way
+                return Ok(());
+            }
+            if entry.one_shot {
+                self.entries.remove(&id);
+            }
+            return Ok(());
+        }
+
+        let Some(pending) = message.id.and_then(|id| self.pending.remove(&id)) else {
+            return Ok(());
+        };
+        let Pending::Subscribe {
+            id,
+            unsubscribe_method,
+        } = pending
+        else {
+            return Ok(());
+        };
+
+        match (message.result, message.error) {
+            (_, Some(error)) => {
+                if let Some(entry) = self.entries.remove(&id) {
+                    let _ = entry
+                        .sender
+                        .unbounded_send(Err(Error::Rpc(error.to_string())));
+                }
+            }
+            (Some(result), None) => {
+                let server_id = result.as_u64();
+                match (self.entries.get_mut(&id), server_id) {
+                    (Some(entry), _) => entry.server_id = server_id,
+                    // Unsubscribed before the subscription was confirmed
+                    (None, Some(server_id)) => {
+                        self.send(
+                            socket,
+                            &unsubscribe_method,
+                            json!([server_id]),
+                            Pending::Unsubscribe,
+                        )
+                        .await?;
+                    }
+                    (None, None) => {}
+                }
+            }
+            (None, None) => {}
+        }
+        Ok(())
+    }
+
+    /// Sends the subscription request of a subscription.
+    async fn send_subscribe(
+        &mut self,
+        socket: &mut Socket,
+        id: u64,
+    ) -> Result<(), tungstenite::Error> {
+        let Some(entry) = self.entries.get_mut(&id) else {
+            return Ok(());
+        };
+        entry.server_id = None;
+        let (method, params) = (entry.method, entry.params.clone());
+        let pending = Pending::Subscribe {
+            id,
+            unsubscribe_method: entry.unsubscribe_method(),
+        };
+        self.send(socket, method, params, pending).await
+    }
+
+    /// Sends the unsubscription request of a subscription, if it was confirmed.
+    async fn send_unsubscribe(
+        &mut self,
+        socket: &mut Socket,
+        entry: &Entry,
+    ) -> Result<(), tungstenite::Error> {
+        match entry.server_id {
+            Some(server_id) => {
+                self.send(
+                    socket,
+                    &entry.unsubscribe_method(),
+                    json!([server_id]),
+                    Pending::Unsubscribe,
+                )
+                .await
+            }
+            None => Ok(()),
+        }
+    }
+
+    /// Sends a JSON-RPC request.
+    async fn send(
+        &mut self,
+        socket: &mut Socket,
+        method: &str,
+        params: Value,
+        pending: Pending,
+    ) -> Result<(), tungstenite::Error> {
+        let id = self.next_request_id;
+        self.next_request_id += 1;
+        self.pending.insert(id, pending);
+
+        let request = json!({ "jsonrpc": "2.0", "id": id, "method": method, "params": params });
+        socket
+            .send(tungstenite::Message::Text(request.to_string()))
+            .await
+    }
+
+    /// Emits a connection error on every subscription.
+    fn broadcast(&mut self, error: Arc<tungstenite::Error>) {
+        self.entries.retain(|_, entry| {
+            entry
+                .sender
+                .unbounded_send(Err(Error::WebSocket(error.clone())))
+                .is_ok()
+        });
+    }
+}
+
+/// Returns the delay before a reconnect attempt, doubling after each failure.
+fn reconnect_interval(failures: u32) -> Duration {
+    let factor = 2u32.saturating_pow(failures.saturating_sub(1));
+    constants::WS_MIN_RECONNECT_INTERVAL
+        .saturating_mul(factor)
+        .min(constants::WS_MAX_RECONNECT_INTERVAL)
+}
@@ -0,0 +1,224 @@
+//! This module provides a client-side rate limiter for the Shyft API.
+//!
+//! The [`RateLimiter`] is a token bucket shared by every clone of itself, so a single limiter
+//! can be shared by all the clones of a [`ShyftApi`](crate::ShyftApi) and by several clients
+//! using the same API key.
+//!
+//! # Examples
+//!
+//! ```
+//! use shyft_rs_sdk::{Plan, RateLimiter, ShyftApi};
+//!
+//! let rate_limiter = RateLimiter::for_plan(Plan::Free).with_weight("transaction/parse_selected", 2);
+//! let client = ShyftApi::builder("your_api_key")
+//!     .rate_limiter(rate_limiter)
+//!     .build()?;
+//! # Ok::<(), shyft_rs_sdk::Error>(())
+//! ```
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use http::Extensions;
+use reqwest::{header, Request, Response, StatusCode};
+use reqwest_middleware::{Middleware, Next};
+
+use crate::time::Instant;
+
+/// Enum representing the Shyft plans, used to pick a default request rate.
+///
+/// The rates are conservative defaults for each tier. Check the limits shown in your Shyft
+/// dashboard and use [`RateLimiter::new`] if they differ.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Plan {
+    /// Free plan, 1 request per second.
+    Free,
+    /// Developer plan, 10 requests per second.
+    Dev,
+    /// Pro plan, 30 requests per second.
+    Pro,
+}
+
+impl Plan {
+    /// Returns the number of requests per second allowed by the plan.
+    pub fn requests_per_second(&self) -> u32 {
+        match self {
+            Plan::Free => 1,
+            Plan::Dev => 10,
+            Plan::Pro => 30,
+        }
+    }
+}
+
+/// Struct representing a token bucket rate limiter.
+///
+/// Each request consumes tokens according to the weight of its endpoint, 1 by default, and
+/// tokens are refilled continuously at the configured rate. When Shyft answers with
+/// `429 Too Many Requests`, the bucket is emptied and, if the response has a `Retry-After`
+/// header, requests are paused until then.
+#[derive(Debug, Clone)]
+pub struct RateLimiter {
+    /// The shared state of the bucket.
+    bucket: Arc<Mutex<Bucket>>,
+    /// Weights of the endpoints, by endpoint path such as `transaction/history`.
+    weights: Arc<HashMap<String, u32>>,
+}
+
+/// State of a token bucket.
+#[derive(Debug)]
+struct Bucket {
+    /// Maximum number of tokens.
+    capacity: f64,
+    /// Number of tokens available.
+    tokens: f64,
+    /// Number of tokens refilled per second.
+    refill_rate: f64,
+    /// When the tokens were last refilled.
+    last_refill: Instant,
+    /// Requests are paused until this instant, after a `429 Too Many Requests`.
+    paused_until: Option<Instant>,
+}
+
+impl RateLimiter {
+    /// Creates a new rate limiter allowing the given number of requests per second.
+    ///
+    /// The burst size, the number of requests that can be sent at once, defaults to the
+    /// number of requests per second.
+    pub fn new(requests_per_second: u32) -> Self {
+        let rate = f64::from(requests_per_second.max(1));
+        Self {
+            bucket: Arc::new(Mutex::new(Bucket {
+                capacity: rate,
+                tokens: rate,
+                refill_rate: rate,
+                last_refill: Instant::now(),
+                paused_until: None,
+            })),
+            weights: Arc::new(HashMap::new()),
+        }
+    }
+
+    /// Creates a new rate limiter allowing the requests per second of a Shyft plan.
+    pub fn for_plan(plan: Plan) -> Self {
+        Self::new(plan.requests_per_second())
+    }
+
+    /// Sets the burst size, the number of requests that can be sent at once.
+    pub fn with_burst(self, burst: u32) -> Self {
+        {
+            let mut bucket = self.bucket.lock().unwrap();
+            bucket.capacity = f64::from(burst.max(1));
+            bucket.tokens = bucket.tokens.min(bucket.capacity);
+        }
+        self
+    }
+
+    /// Sets the number of tokens consumed by each request to an endpoint.
+    ///
+    /// # Arguments
+    ///
+    /// * `endpoint` - Path of the endpoint, relative to the API version, such as
+    ///   `transaction/parse_selected`.
+    /// * `weight` - Number of tokens consumed by each request.
+    pub fn with_weight(mut self, endpoint: &str, weight: u32) -> Self {
+        Arc::make_mut(&mut self.weights).insert(endpoint.trim_matches('/').to_owned(), weight);
+        self
+    }
+
+    /// Waits until the given number of tokens is available, then consumes them.
+    ///
+    /// Weights above the burst size are capped to it.
+    pub async fn acquire(&self, weight: u32) {
+        loop {
+            let wait = {
+                let mut bucket = self.bucket.lock().unwrap();
+                let now = Instant::now();
+
+                match bucket.paused_until {
+                    Some(paused_until) if paused_until > now => paused_until - now,
+                    _ => {
+                        bucket.paused_until = None;
+                        bucket.refill(now);
+
+                        let weight = f64::from(weight).min(bucket.capacity);
+                        if bucket.tokens >= weight {
+                            bucket.tokens -= weight;
+                            return;
+                        }
+                        Duration::from_secs_f64((weight - bucket.tokens) / bucket.refill_rate)
+                    }
+                }
+            };
+
+            crate::time::sleep(wait).await;
+        }
+    }
+
+    /// Returns the weight of a request to a URL path.
+    ///
+    /// When several endpoints match the end of the path, the longest one wins, so
+    /// `transaction/parse_selected` takes precedence over `parse_selected`. Paths matching no
+    /// endpoint weigh 1.
+    pub fn weight(&self, path: &str) -> u32 {
+        let path = path.trim_matches('/');
+        self.weights
+            .iter()
+            .filter(|(endpoint, _)| {
+                path.strip_suffix(endpoint.as_str())
+                    .is_some_and(|prefix| prefix.is_empty() || prefix.ends_with('/'))
+            })
+            .max_by_key(|(endpoint, _)| endpoint.len())
+            .map_or(1, |(_, weight)| *weight)
+    }
+
+    /// Empties the bucket after a `429 Too Many Requests`, pausing for `retry_after` if given.
+    fn throttle(&self, retry_after: Option<Duration>) {
+        let mut bucket = self.bucket.lock().unwrap();
+        let now = Instant::now();
+        bucket.refill(now);
+        bucket.tokens = 0.0;
+        if let Some(retry_after) = retry_after {
+            bucket.paused_until = Some(now + retry_after);
+        }
+    }
+}
+
+impl Bucket {
+    /// Refills the tokens accumulated since the last refill.
+    fn refill(&mut self, now: Instant) {
+        let elapsed = now.duration_since(self.last_refill).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.refill_rate).min(self.capacity);
+        self.last_refill = now;
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+impl Middleware for RateLimiter {
+    /// Waits for the rate limiter before sending the request.
+    async fn handle(
+        &self,
+        req: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> reqwest_middleware::Result<Response> {
+        self.acquire(self.weight(req.url().path())).await;
+
+        let response = next.run(req, extensions).await?;
+
+        if response.status() == StatusCode::TOO_MANY_REQUESTS {
+            let retry_after = response
+                .headers()
+                .get(header::RETRY_AFTER)
+                .and_then(|value| value.to_str().ok())
+                .and_then(|value| value.trim().parse().ok())
+                .map(Duration::from_secs);
+            self.throttle(retry_after);
+        }
+
+        Ok(response)
+    }
+}
@@ -0,0 +1,416 @@
+//! This module provides typed requests for the Shyft API endpoints.
+//!
+//! Each request is built from its required parameters, with setters for the optional ones, and
+//! is validated against the constraints of Shyft before being sent with [`ShyftApi::execute`].
+//! Requests are serializable, so they can be queued and persisted as jobs.
+//!
+//! The network and commitment are not part of the requests, they are taken from the client
+//! executing them.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), shyft_rs_sdk::Error> {
+//! use shyft_rs_sdk::{requests::TransactionHistoryRequest, ShyftApi};
+//!
+//! let client = ShyftApi::new("your_api_key", None, None, None, None, None)?;
+//! let request = TransactionHistoryRequest::new("8R5brRqNa1CDMtcQRaLPQfJeLBrtyqpjDPTSKbBvmsna".parse()?)
+//!     .tx_num(10)
+//!     .enable_raw(true);
+//!
+//! // Requests can be persisted and executed later
+//! let job = serde_json::to_string(&request)?;
+//! let request: TransactionHistoryRequest = serde_json::from_str(&job)?;
+//! let history = client.execute(&request).await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [`ShyftApi::execute`]: crate::ShyftApi::execute
+
+use std::collections::HashMap;
+
+use reqwest_middleware::{ClientWithMiddleware, RequestBuilder};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use crate::{
+    constants,
+    models::{domain::Domain, parsed_transaction_details::ParsedTransactionDetails},
+    Pubkey, Signature,
+};
+
+/// Trait for the requests that can be sent with [`ShyftApi::execute`](crate::ShyftApi::execute).
+///
+/// This trait is sealed and implemented by the request types of this module.
+pub trait ShyftRequest: sealed::Sealed + Serialize + DeserializeOwned {
+    /// The type of the result of the response.
+    type Response: DeserializeOwned;
+
+    /// Checks that the request satisfies the constraints of Shyft.
+    ///
+    /// # Errors
+    ///
+    /// This function will return [`Error::InvalidRequest`](crate::Error::InvalidRequest) if a
+    /// parameter is out of range.
+    fn validate(&self) -> Result<(), crate::error::Error> {
+        Ok(())
+    }
+}
+
+/// Private supertrait of [`ShyftRequest`], so it cannot be implemented outside of the crate.
+pub(crate) mod sealed {
+    use super::*;
+
+    /// Trait building the HTTP requests, private to the crate.
+    pub trait Sealed {
+        /// Builds the HTTP request to the API at `base_url`, with the default parameters of the
+        /// client.
+        fn http_request(
+            &self,
+            client: &ClientWithMiddleware,
+            base_url: &str,
+            default_params: &HashMap<String, String>,
+        ) -> RequestBuilder;
+
+        /// Returns `true` if the response never changes once finalized.
+        fn is_immutable(&self) -> bool;
+    }
+}
+
+/// Returns the URL of an endpoint of the API at `base_url`.
+fn url(base_url: &str, endpoint: &str) -> String {
+    format!("{}{}", base_url, endpoint)
+}
+
+/// Request for the transaction history of an account. Equivalent to [GET /transaction/history]
+///
+/// [GET /transaction/history]: https://docs.shyft.to/solana-apis/transactions/transaction-apis#get-transaction-history
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TransactionHistoryRequest {
+    /// The account for which to fetch the transaction history.
+    account: Pubkey,
+    /// Number of transactions to fetch.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    tx_num: Option<u32>,
+    /// Transaction signature to fetch transactions before.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    before_tx_signature: Option<Signature>,
+    /// Transaction signature to fetch transactions until.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    until_tx_signature: Option<Signature>,
+    /// Whether to include raw transaction details.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    enable_raw: Option<bool>,
+    /// Whether to include events in the transaction details.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    enable_events: Option<bool>,
+}
+
+impl TransactionHistoryRequest {
+    /// Creates a new request for the transaction history of an account.
+    pub fn new(account: Pubkey) -> Self {
+        Self {
+            account,
+            tx_num: None,
+            before_tx_signature: None,
+            until_tx_signature: None,
+            enable_raw: None,
+            enable_events: None,
+        }
+    }
+
+    /// Sets the number of transactions to fetch, from 1 to 100.
+    pub fn tx_num(mut self, tx_num: u32) -> Self {
+        self.tx_num = Some(tx_num);
+        self
+    }
+
+    /// Sets the transaction signature to fetch transactions before.
+    pub fn before_tx_signature(mut self, before_tx_signature: Signature) -> Self {
+        self.before_tx_signature = Some(before_tx_signature);
+        self
+    }
+
+    /// Sets the transaction signature to fetch transactions until.
+    pub fn until_tx_signature(mut self, until_tx_signature: Signature) -> Self {
+        self.until_tx_signature = Some(until_tx_signature);
+        self
+    }
+
+    /// Sets whether to include raw transaction details.
+    pub fn enable_raw(mut self, enable_raw: bool) -> Self {
+        self.enable_raw = Some(enable_raw);
+        self
+    }
+
+    /// Sets whether to include events in the transaction details.
+    pub fn enable_events(mut self, enable_events: bool) -> Self {
+        self.enable_events = Some(enable_events);
+        self
+    }
+}
+
+impl ShyftRequest for TransactionHistoryRequest {
+    type Response = Vec<ParsedTransactionDetails>;
+
+    fn validate(&self) -> Result<(), crate::error::Error> {
+        match self.tx_num {
+            Some(tx_num) if !(1..=constants::MAX_TX_NUM).contains(&tx_num) => {
+                Err(crate::error::Error::InvalidRequest(format!(
+                    "tx_num must be between 1 and {}, got {}",
+                    constants::MAX_TX_NUM,
+                    tx_num
+                )))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+impl sealed::Sealed for TransactionHistoryRequest {
+    fn http_request(
+        &self,
+        client: &ClientWithMiddleware,
+        base_url: &str,
+        default_params: &HashMap<String, String>,
+    ) -> RequestBuilder {
+        let mut request = client
+            .get(url(base_url, "transaction/history"))
+            .query(default_params)
+            .query(&[("account", self.account.to_string())]);
+
+        if let Some(tx_num) = self.tx_num {
+            request = request.query(&[("tx_num", tx_num.to_string())]);
+        }
+
+        if let Some(before_tx_signature) = &self.before_tx_signature {
+            request = request.query(&[("before_tx_signature", before_tx_signature.to_string())]);
+        }
+
+        if let Some(until_tx_signature) = &self.until_tx_signature {
+            request = request.query(&[("until_tx_signature", until_tx_signature.to_string())]);
+        }
+
+        if let Some(enable_raw) = self.enable_raw {
+            request = request.query(&[("enable_raw", enable_raw.to_string())]);
+        }
+
+        if let Some(enable_events) = self.enable_events {
+            request = request.query(&[("enable_events", enable_events.to_string())]);
+        }
+
+        request
+    }
+
+    fn is_immutable(&self) -> bool {
+        false
+    }
+}
+
+/// Request for the parsed details of a transaction. Equivalent to [GET /transaction/parsed]
+///
+/// [GET /transaction/parsed]: https://docs.shyft.to/solana-apis/transactions/transaction-apis#parsed-transaction
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ParsedTransactionRequest {
+    /// The transaction signature.
+    txn_signature: Signature,
+}
+
+impl ParsedTransactionRequest {
+    /// Creates a new request for the parsed details of a transaction.
+    pub fn new(txn_signature: Signature) -> Self {
+        Self { txn_signature }
+    }
+}
+
+impl ShyftRequest for ParsedTransactionRequest {
+    type Response = ParsedTransactionDetails;
+}
+
+impl sealed::Sealed for ParsedTransactionRequest {
+    fn http_request(
+        &self,
+        client: &ClientWithMiddleware,
+        base_url: &str,
+        default_params: &HashMap<String, String>,
+    ) -> RequestBuilder {
+        client
+            .get(url(base_url, "transaction/parsed"))
+            .query(default_params)
+            .query(&[("txn_signature", self.txn_signature.to_string())])
+    }
+
+    fn is_immutable(&self) -> bool {
+        true
+    }
+}
+
+/// Request for the parsed details of several transactions. Equivalent to [POST /transaction/parse_selected]
+///
+/// [POST /transaction/parse_selected]: https://docs.shyft.to/solana-apis/transactions/transaction-apis#post-transaction-parse_selected
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ParseSelectedRequest {
+    /// The transaction signatures, from 1 to 100.
+    transaction_signatures: Vec<Signature>,
+    /// Whether to include raw transaction details.
+    #[serde(default)]
+    enable_raw: bool,
+    /// Whether to include events in the transaction details.
+    #[serde(default)]
+    enable_events: bool,
+}
+
+/// Body of a parse_selected HTTP request.
+#[derive(Serialize)]
+struct ParseSelectedBody<'a> {
+    /// Network to interact with.
+    network: &'a str,
+    /// The transaction signatures.
+    transaction_signatures: &'a [Signature],
+    /// Whether to include raw transaction details.
+    enable_raw: bool,
+    /// Whether to include events in the transaction details.
+    enable_events: bool,
+    /// Commitment level for transactions.
+    commitment: &'a str,
+}
+
+impl ParseSelectedRequest {
+    /// Creates a new request for the parsed details of several transactions.
+    pub fn new(transaction_signatures: impl IntoIterator<Item = Signature>) -> Self {
+        Self {
+            transaction_signatures: transaction_signatures.into_iter().collect(),
+            enable_raw: false,
+            enable_events: false,
+        }
+    }
+
+    /// Sets whether to include raw transaction details.
+    pub fn enable_raw(mut self, enable_raw: bool) -> Self {
+        self.enable_raw = enable_raw;
+        self
+    }
+
+    /// Sets whether to include events in the transaction details.
+    pub fn enable_events(mut self, enable_events: bool) -> Self {
+        self.enable_events = enable_events;
+        self
+    }
+}
+
+impl ShyftRequest for ParseSelectedRequest {
+    type Response = Vec<ParsedTransactionDetails>;
+
+    fn validate(&self) -> Result<(), crate::error::Error> {
+        let len = self.transaction_signatures.len();
+        if !(1..=constants::MAX_PARSE_SELECTED_SIGNATURES).contains(&len) {
+            return Err(crate::error::Error::InvalidRequest(format!(
+                "transaction_signatures must contain between 1 and {} signatures, got {}",
+                constants::MAX_PARSE_SELECTED_SIGNATURES,
+                len
+            )));
+        }
+        Ok(())
+    }
+}
+
+impl sealed::Sealed for ParseSelectedRequest {
+    fn http_request(
+        &self,
+        client: &ClientWithMiddleware,
+        base_url: &str,
+        default_params: &HashMap<String, String>,
+    ) -> RequestBuilder {
+        client
+            .post(url(base_url, "transaction/parse_selected"))
+            .json(&ParseSelectedBody {
+                network: &default_params["network"],
+                transaction_signatures: &self.transaction_signatures,
+                enable_raw: self.enable_raw,
+                enable_events: self.enable_events,
+                commitment: &default_params["commitment"],
+            })
+    }
+
+    fn is_immutable(&self) -> bool {
+        // Shyft leaves out the transactions it cannot parse yet, so a batch may still change
+        false
+    }
+}
+
+/// Request for the `.sol` domains owned by a wallet. Equivalent to [GET /wallet/get_domains]
+///
+/// [GET /wallet/get_domains]: https://docs.shyft.to/solana-apis/wallet/wallet-apis#get-domains
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DomainsRequest {
+    /// The wallet address.
+    wallet: Pubkey,
+}
+
+impl DomainsRequest {
+    /// Creates a new request for the `.sol` domains owned by a wallet.
+    pub fn new(wallet: Pubkey) -> Self {
+        Self { wallet }
+    }
+}
+
+impl ShyftRequest for DomainsRequest {
+    type Response = Vec<Domain>;
+}
+
+impl sealed::Sealed for DomainsRequest {
+    fn http_request(
+        &self,
+        client: &ClientWithMiddleware,
+        base_url: &str,
+        default_params: &HashMap<String, String>,
+    ) -> RequestBuilder {
+        client
+            .get(url(base_url, "wallet/get_domains"))
+            .query(&[("network", &default_params["network"])])
+            .query(&[("wallet", self.wallet.to_string())])
+    }
+
+    fn is_immutable(&self) -> bool {
+        false
+    }
+}
+
+/// Request for the primary `.sol` domain of an address. Equivalent to [GET /wallet/resolve_address]
+///
+/// [GET /wallet/resolve_address]: https://docs.shyft.to/solana-apis/wallet/wallet-apis#resolve-address
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ResolveAddressRequest {
+    /// The address to resolve.
+    address: Pubkey,
+}
+
+impl ResolveAddressRequest {
+    /// Creates a new request for the primary `.sol` domain of an address.
+    pub fn new(address: Pubkey) -> Self {
+        Self { address }
+    }
+}
+
+impl ShyftRequest for ResolveAddressRequest {
+    type Response = Option<String>;
+}
+
+impl sealed::Sealed for ResolveAddressRequest {
+    fn http_request(
+        &self,
+        client: &ClientWithMiddleware,
+        base_url: &str,
+        default_params: &HashMap<String, String>,
+    ) -> RequestBuilder {
+        client
+            .get(url(base_url, "wallet/resolve_address"))
+            .query(&[("network", &default_params["network"])])
+            .query(&[("address", self.address.to_string())])
+    }
+
+    fn is_immutable(&self) -> bool {
+        false
+    }
+}
@@ -0,0 +1,26 @@
+//! This module provides the timers used by the crate.
+//!
+//! Timers use tokio by default. With the `wasm` feature, they use `wasm-timer` instead, which
+//! relies on the browser's `performance.now()` and `setTimeout` on `wasm32-unknown-unknown`.
+//! The delays between retries are handled by `reqwest-retry`, which picks the same timer on
+//! wasm32.
+
+use std::time::Duration;
+
+#[cfg(not(feature = "wasm"))]
+pub(crate) use std::time::Instant;
+#[cfg(feature = "wasm")]
+pub(crate) use wasm_timer::Instant;
+
+/// Waits until the given duration has elapsed.
+pub(crate) async fn sleep(duration: Duration) {
+    #[cfg(not(feature = "wasm"))]
+    tokio::time::sleep(duration).await;
+
+    #[cfg(feature = "wasm")]
+    {
+        // The timer only fails if its background task was dropped, in which case waking up
+        // early is harmless.
+        let _ = wasm_timer::Delay::new(duration).await;
+    }
+}
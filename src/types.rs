@@ -0,0 +1,113 @@
+//! This module defines the base58-encoded Solana types used throughout the Shyft Rust SDK.
+//!
+//! Both [`Pubkey`] and [`Signature`] are validated when parsed, so malformed inputs are
+//! rejected before any request is sent.
+//!
+//! # Examples
+//!
+//! ```
+//! use shyft_rs_sdk::{Pubkey, Signature};
+//!
+//! let account: Pubkey = "8R5brRqNa1CDMtcQRaLPQfJeLBrtyqpjDPTSKbBvmsna".parse()?;
+//! let signature: Signature =
+//!     "uZz2qDvLzndsTEY31YkxgRe1rYQ8MsCtS3DwhPYP1Md7u7dnUK4HW3vYzsxE6GSxFhSG5zpvqSQnSUn1sPmzTBu"
+//!         .parse()?;
+//!
+//! assert_eq!(account.to_string(), "8R5brRqNa1CDMtcQRaLPQfJeLBrtyqpjDPTSKbBvmsna");
+//! assert!("not-a-pubkey".parse::<Pubkey>().is_err());
+//! # Ok::<(), shyft_rs_sdk::Error>(())
+//! ```
+
+use std::{fmt, str::FromStr};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Implements parsing, formatting and serde support for a fixed-size base58 newtype.
+macro_rules! base58_type {
+    ($name:ident, $len:literal, $max_encoded_len:literal, $error:ident) => {
+        impl $name {
+            /// Creates a new instance from its raw bytes.
+            pub const fn new(bytes: [u8; $len]) -> Self {
+                Self(bytes)
+            }
+
+            /// Returns the raw bytes.
+            pub const fn to_bytes(&self) -> [u8; $len] {
+                self.0
+            }
+        }
+
+        impl AsRef<[u8]> for $name {
+            fn as_ref(&self) -> &[u8] {
+                &self.0
+            }
+        }
+
+        impl From<[u8; $len]> for $name {
+            fn from(bytes: [u8; $len]) -> Self {
+                Self(bytes)
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = crate::error::Error;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                if s.len() > $max_encoded_len {
+                    return Err(crate::error::Error::$error(s.to_owned()));
+                }
+
+                let mut bytes = [0u8; $len];
+                match bs58::decode(s).onto(&mut bytes) {
+                    Ok($len) => Ok(Self(bytes)),
+                    _ => Err(crate::error::Error::$error(s.to_owned())),
+                }
+            }
+        }
+
+        impl TryFrom<&str> for $name {
+            type Error = crate::error::Error;
+
+            fn try_from(s: &str) -> Result<Self, Self::Error> {
+                s.parse()
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(&bs58::encode(self.0).into_string())
+            }
+        }
+
+        impl fmt::Debug for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{}({})", stringify!($name), self)
+            }
+        }
+
+        impl Serialize for $name {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.collect_str(self)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let s = <std::borrow::Cow<'de, str>>::deserialize(deserializer)?;
+                s.parse().map_err(serde::de::Error::custom)
+            }
+        }
+    };
+}
+
+/// A Solana account address, a 32-byte public key encoded as base58.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Pubkey([u8; 32]);
+
+base58_type!(Pubkey, 32, 44, InvalidPubkey);
+
+/// A Solana transaction signature, 64 bytes encoded as base58.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Signature([u8; 64]);
+
+base58_type!(Signature, 64, 88, InvalidSignature);
@@ -0,0 +1,255 @@
+//! This module provides polling-based watchers of account transactions.
+//!
+//! A watcher polls [`ShyftApi::get_transaction_history`](crate::ShyftApi::get_transaction_history)
+//! with `until_tx_signature` set to the last transaction it has seen, and emits only the
+//! transactions made after it started watching, in chronological order. When more transactions
+//! were made since the last poll than fit in a page, the older pages are fetched too, so no
+//! transaction is missed.
+//!
+//! Each poll fetches at most [`MAX_WATCH_PAGES`](crate::constants::MAX_WATCH_PAGES) pages, which
+//! can be changed with [`RequestOptions::max_watch_pages`](crate::RequestOptions::max_watch_pages).
+//! If the last transaction seen is not found within them, for example because it was rolled
+//! back, the watcher resumes from the newest transaction fetched and the older ones are skipped.
+//!
+//! Several accounts can be watched at once with [`ShyftApi::watch_accounts`]. Every watcher of a
+//! client shares its [`RateLimiter`](crate::RateLimiter), if any, so polling many accounts stays
+//! within the limits of a Shyft plan.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), shyft_rs_sdk::Error> {
+//! use std::time::Duration;
+//!
+//! use futures::StreamExt;
+//! use shyft_rs_sdk::{Plan, RateLimiter, ShyftApi};
+//!
+//! let client = ShyftApi::builder("your_api_key")
+//!     .rate_limiter(RateLimiter::for_plan(Plan::Free))
+//!     .build()?;
+//! let account = "8R5brRqNa1CDMtcQRaLPQfJeLBrtyqpjDPTSKbBvmsna".parse()?;
+//!
+//! let mut transactions = Box::pin(client.watch_account(account, Duration::from_secs(5), None, None));
+//! while let Some(transaction) = transactions.next().await {
+//!     match transaction {
+//!         Ok(transaction) => println!("{}", transaction.transaction_type),
+//!         Err(error) => eprintln!("{}", error),
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{collections::VecDeque, time::Duration};
+
+use futures::{stream, Stream, StreamExt};
+
+use crate::{
+    constants, models::parsed_transaction_details::ParsedTransactionDetails,
+    requests::TransactionHistoryRequest, Pubkey, ShyftApi, Signature,
+};
+
+/// State of an account watcher.
+struct Watcher {
+    /// The client polling the transaction history.
+    client: ShyftApi,
+    /// The watched account.
+    account: Pubkey,
+    /// Delay between two polls.
+    poll_interval: Duration,
+    /// Whether to include raw transaction details.
+    enable_raw: Option<bool>,
+    /// Whether to include events in the transaction details.
+    enable_events: Option<bool>,
+    /// Whether the latest transaction at the start of the watch was fetched.
+    started: bool,
+    /// The latest transaction seen, if any.
+    last_seen: Option<Signature>,
+    /// New transactions not yet emitted, in chronological order.
+    pending: VecDeque<ParsedTransactionDetails>,
+}
+
+impl Watcher {
+    /// Returns the next new transaction, polling until there is one.
+    async fn next(mut self) -> (Result<ParsedTransactionDetails, crate::error::Error>, Self) {
+        loop {
+            if let Some(transaction) = self.pending.pop_front() {
+                return (Ok(transaction), self);
+            }
+
+            if !self.started {
+                match self.start().await {
+                    Ok(()) => self.started = true,
+                    Err(error) => {
+                        crate::time::sleep(self.poll_interval).await;
+                        return (Err(error), self);
+                    }
+                }
+            }
+
+            crate::time::sleep(self.poll_interval).await;
+            if let Err(error) = self.poll().await {
+                return (Err(error), self);
+            }
+        }
+    }
+
+    /// Fetches the latest transaction of the account, which new transactions come after.
+    async fn start(&mut self) -> Result<(), crate::error::Error> {
+        let request = TransactionHistoryRequest::new(self.account).tx_num(1);
+        let latest = self.client.execute(&request).await?;
+        self.last_seen = latest.first().and_then(|tx| tx.signatures.first().copied());
+        Ok(())
+    }
+
+    /// Fetches the transactions made since the last poll, page by page, newest first.
+    async fn poll(&mut self) -> Result<(), crate::error::Error> {
+        let mut new_transactions = Vec::new();
+        let mut before = None;
+        let max_pages = self
+            .client
+            .max_watch_pages
+            .unwrap_or(constants::MAX_WATCH_PAGES)
+            .max(1);
+        let mut pages = 0;
+
+        loop {
+            let mut request =
+                TransactionHistoryRequest::new(self.account).tx_num(constants::MAX_TX_NUM);
+            if let Some(last_seen) = self.last_seen {
+                request = request.until_tx_signature(last_seen);
+            }
+            if let Some(before) = before {
+                request = request.before_tx_signature(before);
+            }
+            if let Some(enable_raw) = self.enable_raw {
+                request = request.enable_raw(enable_raw);
+            }
+            if let Some(enable_events) = self.enable_events {
+                request = request.enable_events(enable_events);
+            }
+
+            let page = self.client.execute(&request).await?;
+            let full = page.len() >= constants::MAX_TX_NUM as usize;
+            pages += 1;
+
+            let page: Vec<_> = page
+                .into_iter()
+                .take_while(|tx| {
+                    self.last_seen.is_none() || tx.signatures.first() != self.last_seen.as_ref()
+                })
+                .collect();
+            before = page.last().and_then(|tx| tx.signatures.first().copied());
+            new_transactions.extend(page);
+
+            // A page shorter than the maximum is the last one
+            if !full || before.is_none() {
+                break;
+            }
+            if pages >= max_pages {
+                #[cfg(feature = "tracing")]
+                tracing::warn!(
+                    account = %self.account,
+                    pages,
+                    "Last seen transaction not found, skipping older transactions"
+                );
+                break;
+            }
+        }
+
+        if let Some(latest) = new_transactions.first() {
+            self.last_seen = latest.signatures.first().copied();
+        }
+        self.pending.extend(new_transactions.into_iter().rev());
+        Ok(())
+    }
+}
+
+impl ShyftApi {
+    /// Watches an account for new transactions, by polling its transaction history.
+    ///
+    /// The returned stream never ends. Only the transactions made after the watch started are
+    /// emitted, oldest first. Errors are emitted without ending the stream, and the next poll
+    /// resumes from the last transaction seen.
+    ///
+    /// A poll fetches at most [`RequestOptions::max_watch_pages`](crate::RequestOptions::max_watch_pages)
+    /// pages of new transactions. If the last transaction seen is not found within them, for
+    /// example because it was rolled back, older transactions are skipped, with a warning if the
+    /// `tracing` feature is enabled.
+    ///
+    /// # Arguments
+    ///
+    /// * `account` - The account to watch.
+    /// * `poll_interval` - Delay between two polls of the transaction history.
+    /// * `enable_raw` - An optional flag to enable raw transaction details.
+    /// * `enable_events` - An optional flag to enable events in the transaction details.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), shyft_rs_sdk::Error> {
+    /// # use std::time::Duration;
+    /// # use futures::StreamExt;
+    /// # use shyft_rs_sdk::ShyftApi;
+    /// #
+    /// # let api_key = "your_api_key";
+    /// # let client = ShyftApi::new(api_key, None, None, None, None, None).unwrap();
+    /// let account = "8R5brRqNa1CDMtcQRaLPQfJeLBrtyqpjDPTSKbBvmsna".parse()?;
+    /// let mut transactions = Box::pin(client.watch_account(account, Duration::from_secs(5), None, None));
+    /// let transaction = transactions.next().await;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn watch_account(
+        &self,
+        account: Pubkey,
+        poll_interval: Duration,
+        enable_raw: Option<bool>,
+        enable_events: Option<bool>,
+    ) -> impl Stream<Item = Result<ParsedTransactionDetails, crate::error::Error>> {
+        let watcher = Watcher {
+            client: self.clone(),
+            account,
+            poll_interval,
+            enable_raw,
+            enable_events,
+            started: false,
+            last_seen: None,
+            pending: VecDeque::new(),
+        };
+        stream::unfold(watcher, |watcher| async move { Some(watcher.next().await) })
+    }
+
+    /// Watches several accounts for new transactions, by polling their transaction histories.
+    ///
+    /// Each item is paired with the account it concerns. See [`ShyftApi::watch_account`] for
+    /// how each account is watched. The watchers share the rate limiter of the client, if any.
+    ///
+    /// # Arguments
+    ///
+    /// * `accounts` - The accounts to watch.
+    /// * `poll_interval` - Delay between two polls of each transaction history.
+    /// * `enable_raw` - An optional flag to enable raw transaction details.
+    /// * `enable_events` - An optional flag to enable events in the transaction details.
+    pub fn watch_accounts(
+        &self,
+        accounts: impl IntoIterator<Item = Pubkey>,
+        poll_interval: Duration,
+        enable_raw: Option<bool>,
+        enable_events: Option<bool>,
+    ) -> impl Stream<
+        Item = (
+            Pubkey,
+            Result<ParsedTransactionDetails, crate::error::Error>,
+        ),
+    > {
+        stream::select_all(accounts.into_iter().map(|account| {
+            Box::pin(
+                self.watch_account(account, poll_interval, enable_raw, enable_events)
+                    .map(move |result| (account, result)),
+            )
+        }))
+    }
+}
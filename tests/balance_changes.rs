@@ -0,0 +1,119 @@
+use shyft_rs_sdk::{models::parsed_transaction_details::ParsedTransactionDetails, Decimal, Pubkey};
+
+#[test]
+fn test_balance_changes() {
+    let wallet: Pubkey = "8R5brRqNa1CDMtcQRaLPQfJeLBrtyqpjDPTSKbBvmsna"
+        .parse()
+        .expect("Invalid address");
+    let pool: Pubkey = "5Q544fKrFoe6tsEbD7S8EmxGTJYAKtTVhAW5Q5pge4j1"
+        .parse()
+        .expect("Invalid address");
+    let mint: Pubkey = "EWFka4M5XZrKzAq54eTXxN5K1H3u2es1HVqvr6hsqdSk"
+        .parse()
+        .expect("Invalid address");
+
+    // Sample transaction where the wallet sells tokens to a pool for SOL
+    let transaction: ParsedTransactionDetails = serde_json::from_value(serde_json::json!({
+        "timestamp": "2024-08-26T10:38:46.000Z",
+        "fee": 0.000005,
+        "fee_payer": wallet.to_string(),
+        "signers": [wallet.to_string()],
+        "signatures": [
+            "uZz2qDvLzndsTEY31YkxgRe1rYQ8MsCtS3DwhPYP1Md7u7dnUK4HW3vYzsxE6GSxFhSG5zpvqSQnSUn1sPmzTBu"
+        ],
+        "protocol": {
+            "address": "675kPX9MHTjS2zt1qfr1NYHuzeLXfQM9H24wFSUt1Mp8",
+            "name": "RAYDIUM_LIQUIDITY_POOL_V4"
+        },
+        "type": "SWAP",
+        "status": "Success",
+        "actions": [],
+        "raw": {
+            "blockTime": 1724668726,
+            "slot": 284512345,
+            "meta": {
+                "err": null,
+                "fee": 5000,
+                "preBalances": [1000000000, 5000000000u64, 1],
+                "postBalances": [1999995000, 4000000000u64, 1],
+                "preTokenBalances": [
+                    {
+                        "accountIndex": 3,
+                        "mint": mint.to_string(),
+                        "owner": wallet.to_string(),
+                        "uiTokenAmount": { "amount": "1500000", "decimals": 6, "uiAmount": 1.5, "uiAmountString": "1.5" }
+                    },
+                    {
+                        "accountIndex": 4,
+                        "mint": mint.to_string(),
+                        "owner": pool.to_string(),
+                        "uiTokenAmount": { "amount": "0", "decimals": 6, "uiAmount": null, "uiAmountString": "0" }
+                    },
+                    {
+                        "accountIndex": 3,
+                        "mint": "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v",
+                        "uiTokenAmount": { "amount": "7", "decimals": 6, "uiAmount": 0.000007, "uiAmountString": "0.000007" }
+                    }
+                ],
+                "postTokenBalances": [
+                    {
+                        "accountIndex": 4,
+                        "mint": mint.to_string(),
+                        "owner": pool.to_string(),
+                        "uiTokenAmount": { "amount": "1500000", "decimals": 6, "uiAmount": 1.5, "uiAmountString": "1.5" }
+                    }
+                ]
+            },
+            "transaction": {
+                "signatures": [
+                    "uZz2qDvLzndsTEY31YkxgRe1rYQ8MsCtS3DwhPYP1Md7u7dnUK4HW3vYzsxE6GSxFhSG5zpvqSQnSUn1sPmzTBu"
+                ],
+                "message": {
+                    "accountKeys": [
+                        wallet.to_string(),
+                        pool.to_string(),
+                        "11111111111111111111111111111111",
+                        "3t4BGaZCYCUHLkmEzcdqxqmjvPCxsHwzdLyf7qJi1bwW",
+                        "CoQFjx8fMMTmRKq3MDy7XyacHsp2pgFfUBGNNkLExU4W"
+                    ],
+                    "recentBlockhash": "EkSnNWid2cvwEVnVx9aBqawnmiCNiDgp3gUdkDPTKN1N",
+                    "instructions": []
+                }
+            },
+            "version": "legacy"
+        },
+        "events": null
+    }))
+    .expect("Failed to parse transaction");
+
+    let changes = transaction
+        .balance_changes()
+        .expect("Transaction has raw details");
+
+    // Assert that only the accounts whose SOL balance changed are reported
+    assert_eq!(changes.sol.len(), 2);
+    assert_eq!(changes.sol[0].account, wallet);
+    assert_eq!(changes.sol[0].change, 999_995_000);
+    assert_eq!(changes.sol[1].account, pool);
+    assert_eq!(changes.sol[1].ui_change(), Decimal::from(-1));
+
+    // Assert that the token balance without an owner is left out
+    assert_eq!(changes.tokens.len(), 2);
+
+    // Assert that the closed token account counts as a zero post balance
+    let wallet_change = changes
+        .tokens
+        .iter()
+        .find(|change| change.owner == wallet)
+        .expect("Missing wallet token change");
+    assert_eq!(wallet_change.mint, mint);
+    assert_eq!(wallet_change.change, -1_500_000);
+    assert_eq!(wallet_change.ui_change(), Some(Decimal::new(-15, 1)));
+
+    let pool_change = changes
+        .tokens
+        .iter()
+        .find(|change| change.owner == pool)
+        .expect("Missing pool token change");
+    assert_eq!(pool_change.post_balance, 1_500_000);
+}
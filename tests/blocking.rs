@@ -0,0 +1,147 @@
+#![cfg(feature = "blocking")]
+
+mod common;
+
+use std::time::{Duration, Instant};
+
+use shyft_rs_sdk::{blocking::ShyftApi, RateLimiter, Signature};
+use wiremock::{
+    matchers::{method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+/// Starts a stub server on a runtime of its own, as the blocking client cannot run inside one.
+fn stub_server(mocks: impl FnOnce() -> Vec<Mock>) -> (tokio::runtime::Runtime, MockServer) {
+    let runtime = tokio::runtime::Runtime::new().expect("Failed to build runtime");
+    let server = runtime.block_on(async {
+        let server = MockServer::start().await;
+        for mock in mocks() {
+            mock.mount(&server).await;
+        }
+        server
+    });
+    (runtime, server)
+}
+
+#[test]
+fn test_blocking_get_parsed_transaction_details() {
+    common::setup();
+
+    // Retrieve the API key from environment variables
+    let api_key = std::env::var("SHYFT_API_KEY").expect("SHYFT_API_KEY must be set");
+
+    // Sample transaction signature
+    let tx_signature: Signature =
+        "uZz2qDvLzndsTEY31YkxgRe1rYQ8MsCtS3DwhPYP1Md7u7dnUK4HW3vYzsxE6GSxFhSG5zpvqSQnSUn1sPmzTBu"
+            .parse()
+            .expect("Invalid transaction signature");
+
+    // Create a blocking ShyftApi instance
+    let shyft_api =
+        ShyftApi::new(&api_key, None, None, None, None, None).expect("Failed to create ShyftApi");
+
+    // Fetch parsed transaction details without an async runtime
+    let parsed_transaction_details = shyft_api.get_transaction_parsed(&tx_signature);
+
+    // Assert that the parsed transaction details are fetched successfully
+    assert!(
+        parsed_transaction_details.is_ok(),
+        "Failed to fetch parsed transaction details: {:?}",
+        parsed_transaction_details.err()
+    );
+    assert_eq!(
+        parsed_transaction_details.unwrap().signatures,
+        vec![tx_signature]
+    );
+}
+
+#[test]
+fn test_blocking_middleware() {
+    let signature = common::signatures(1)[0];
+    let (_runtime, server) = stub_server(|| {
+        vec![
+            Mock::given(method("GET"))
+                .and(path("/sol/v1/transaction/parsed"))
+                .respond_with(ResponseTemplate::new(500))
+                .up_to_n_times(1),
+            Mock::given(method("GET"))
+                .and(path("/sol/v1/transaction/parsed"))
+                .respond_with(
+                    ResponseTemplate::new(200)
+                        .set_body_json(common::response(common::transaction(&signature))),
+                ),
+        ]
+    });
+
+    // Allow a request every 100ms, and retry after 50ms
+    let shyft_api = shyft_rs_sdk::ShyftApi::builder("test_api_key")
+        .base_url(&common::base_url(&server))
+        .min_retry_interval(50)
+        .max_retry_interval(50)
+        .rate_limiter(RateLimiter::new(10).with_burst(1))
+        .build_blocking()
+        .expect("Failed to create ShyftApi");
+
+    // Assert that the retry and rate limiter sleeps complete on the runtime of the client
+    let start = Instant::now();
+    for _ in 0..2 {
+        let transaction = shyft_api
+            .get_transaction_parsed(&signature)
+            .expect("Failed to fetch parsed transaction");
+        assert_eq!(transaction.signatures, vec![signature]);
+    }
+    assert!(
+        start.elapsed() >= Duration::from_millis(200),
+        "{:?}",
+        start.elapsed()
+    );
+}
+
+#[test]
+fn test_blocking_watch_account() {
+    let signatures = common::signatures(3);
+    let history = |signatures: &[Signature]| {
+        let transactions: Vec<_> = signatures.iter().map(common::transaction).collect();
+        ResponseTemplate::new(200).set_body_json(common::response(transactions.into()))
+    };
+    let (_runtime, server) = stub_server(|| {
+        vec![
+            // Latest transaction when a watch starts
+            Mock::given(method("GET"))
+                .and(path("/sol/v1/transaction/history"))
+                .and(query_param("tx_num", "1"))
+                .respond_with(history(&signatures[..1])),
+            // Two new transactions since then, newest first
+            Mock::given(method("GET"))
+                .and(path("/sol/v1/transaction/history"))
+                .respond_with(history(&[signatures[2], signatures[1]])),
+        ]
+    });
+
+    let shyft_api = shyft_rs_sdk::ShyftApi::builder("test_api_key")
+        .base_url(&common::base_url(&server))
+        .build_blocking()
+        .expect("Failed to create ShyftApi");
+
+    // Assert that the new transactions are yielded oldest first
+    let account = "8R5brRqNa1CDMtcQRaLPQfJeLBrtyqpjDPTSKbBvmsna"
+        .parse()
+        .unwrap();
+    let transactions: Vec<Signature> = shyft_api
+        .watch_account(account, Duration::from_millis(10), None, None)
+        .take(2)
+        .map(|transaction| transaction.expect("Failed to watch").signatures[0])
+        .collect();
+    assert_eq!(transactions, signatures[1..]);
+
+    // Assert that items of several accounts are paired with their account
+    let (watched, transaction) = shyft_api
+        .watch_accounts([account], Duration::from_millis(10), None, None)
+        .next()
+        .unwrap();
+    assert_eq!(watched, account);
+    assert_eq!(
+        transaction.expect("Failed to watch").signatures[0],
+        signatures[1]
+    );
+}
@@ -0,0 +1,202 @@
+mod common;
+
+use std::{
+    num::NonZeroUsize,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use shyft_rs_sdk::{Cache, Commitment, LruCache, ShyftApi, ShyftApiBuilder};
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+/// Endpoint and time to live of the values stored in a cache.
+type Sets = Vec<(String, Option<Duration>)>;
+
+/// Cache recording the time to live of the values it stores, by endpoint.
+#[derive(Debug)]
+struct RecordingCache {
+    /// The underlying cache.
+    inner: LruCache,
+    /// Endpoint and time to live of each stored value.
+    sets: Arc<Mutex<Sets>>,
+}
+
+#[async_trait::async_trait]
+impl Cache for RecordingCache {
+    async fn get(&self, key: &str) -> Option<String> {
+        self.inner.get(key).await
+    }
+
+    async fn set(&self, key: &str, value: String, ttl: Option<Duration>) {
+        // Keys start with the method and URL of the request
+        let endpoint = key
+            .split(['?', ' '])
+            .nth(1)
+            .and_then(|url| url.split("/sol/v1/").nth(1))
+            .unwrap_or_default();
+        self.sets.lock().unwrap().push((endpoint.to_string(), ttl));
+        self.inner.set(key, value, ttl).await;
+    }
+}
+
+/// Starts a stub server for the parsed transaction, history and parse_selected endpoints.
+async fn stub_server() -> MockServer {
+    let signature = common::signatures(1)[0];
+    let server = MockServer::start().await;
+    for (http_method, endpoint, result) in [
+        ("GET", "transaction/parsed", common::transaction(&signature)),
+        (
+            "GET",
+            "transaction/history",
+            vec![common::transaction(&signature)].into(),
+        ),
+        (
+            "POST",
+            "transaction/parse_selected",
+            vec![common::transaction(&signature)].into(),
+        ),
+    ] {
+        Mock::given(method(http_method))
+            .and(path(format!("/sol/v1/{}", endpoint)))
+            .respond_with(ResponseTemplate::new(200).set_body_json(common::response(result)))
+            .mount(&server)
+            .await;
+    }
+    server
+}
+
+/// Calls each stubbed endpoint twice, and returns the number of requests received by endpoint
+/// and the values stored in the cache.
+async fn call_twice(builder: ShyftApiBuilder) -> (Vec<(String, usize)>, Sets) {
+    let server = stub_server().await;
+    let sets = Arc::default();
+    let shyft_api = builder
+        .base_url(&common::base_url(&server))
+        .cache(RecordingCache {
+            inner: LruCache::new(NonZeroUsize::new(10).unwrap()),
+            sets: Arc::clone(&sets),
+        })
+        .build()
+        .expect("Failed to create ShyftApi");
+
+    let signature = common::signatures(1)[0];
+    let account = "8R5brRqNa1CDMtcQRaLPQfJeLBrtyqpjDPTSKbBvmsna"
+        .parse()
+        .unwrap();
+    for _ in 0..2 {
+        shyft_api
+            .get_transaction_parsed(&signature)
+            .await
+            .expect("Failed to fetch parsed transaction");
+        shyft_api
+            .get_transaction_history(&account, None, None, None, None, None)
+            .await
+            .expect("Failed to fetch history");
+        shyft_api
+            .get_transaction_parse_selected(&[signature], None, None)
+            .await
+            .expect("Failed to fetch selected transactions");
+    }
+
+    let requests = server.received_requests().await.unwrap();
+    let counts = [
+        "transaction/parsed",
+        "transaction/history",
+        "transaction/parse_selected",
+    ]
+    .into_iter()
+    .map(|endpoint| {
+        let count = requests
+            .iter()
+            .filter(|request| request.url.path().ends_with(endpoint))
+            .count();
+        (endpoint.to_string(), count)
+    })
+    .collect();
+    let sets = sets.lock().unwrap().clone();
+    (counts, sets)
+}
+
+#[tokio::test]
+async fn test_lru_cache() {
+    let cache = LruCache::new(NonZeroUsize::new(2).unwrap());
+
+    cache.set("a", "1".to_owned(), None).await;
+    cache
+        .set("b", "2".to_owned(), Some(Duration::from_millis(100)))
+        .await;
+
+    assert_eq!(cache.get("a").await.as_deref(), Some("1"));
+    assert_eq!(cache.get("b").await.as_deref(), Some("2"));
+
+    // Assert that entries expire after their TTL
+    tokio::time::sleep(Duration::from_millis(150)).await;
+    assert_eq!(cache.get("b").await, None);
+    assert_eq!(cache.get("a").await.as_deref(), Some("1"));
+
+    // Assert that the least recently used entry is evicted
+    cache.set("c", "3".to_owned(), None).await;
+    cache.set("d", "4".to_owned(), None).await;
+    assert_eq!(cache.get("a").await, None);
+    assert_eq!(cache.get("c").await.as_deref(), Some("3"));
+    assert_eq!(cache.get("d").await.as_deref(), Some("4"));
+}
+
+#[tokio::test]
+async fn test_client_cache_policies() {
+    let counts = |parsed, history, parse_selected| {
+        vec![
+            ("transaction/parsed".to_string(), parsed),
+            ("transaction/history".to_string(), history),
+            ("transaction/parse_selected".to_string(), parse_selected),
+        ]
+    };
+    let ttl = Duration::from_secs(30);
+
+    // Assert that nothing is cached with the confirmed commitment and no TTL
+    let (requests, sets) = call_twice(ShyftApi::builder("test_api_key")).await;
+    assert_eq!(requests, counts(2, 2, 2));
+    assert!(sets.is_empty(), "{:?}", sets);
+
+    // Assert that only mutable responses are cached, with the TTL, when confirmed
+    let (requests, sets) = call_twice(
+        ShyftApi::builder("test_api_key")
+            .commitment(Commitment::Confirmed)
+            .cache_ttl(ttl),
+    )
+    .await;
+    assert_eq!(requests, counts(2, 1, 1));
+    assert_eq!(
+        sets,
+        vec![
+            ("transaction/history".to_string(), Some(ttl)),
+            ("transaction/parse_selected".to_string(), Some(ttl)),
+        ]
+    );
+
+    // Assert that finalized transactions are cached without expiry, but not their batches
+    let (requests, sets) =
+        call_twice(ShyftApi::builder("test_api_key").commitment(Commitment::Finalized)).await;
+    assert_eq!(requests, counts(1, 2, 2));
+    assert_eq!(sets, vec![("transaction/parsed".to_string(), None)]);
+
+    // Assert that both are cached when finalized with a TTL
+    let (requests, sets) = call_twice(
+        ShyftApi::builder("test_api_key")
+            .commitment(Commitment::Finalized)
+            .cache_ttl(ttl),
+    )
+    .await;
+    assert_eq!(requests, counts(1, 1, 1));
+    assert_eq!(
+        sets,
+        vec![
+            ("transaction/parsed".to_string(), None),
+            ("transaction/history".to_string(), Some(ttl)),
+            ("transaction/parse_selected".to_string(), Some(ttl)),
+        ]
+    );
+}
@@ -1,4 +1,54 @@
+// Each test binary only uses some of the helpers
+#![allow(dead_code)]
+
+use shyft_rs_sdk::Signature;
+
 /// Sets up the test environment by loading environment variables from the .env.test file.
 pub fn setup() {
     dotenvy::from_filename(".env.test").ok();
 }
+
+/// Returns `count` distinct transaction signatures.
+pub fn signatures(count: usize) -> Vec<Signature> {
+    (0..count)
+        .map(|i| {
+            let mut bytes = [1u8; 64];
+            bytes[..8].copy_from_slice(&(i as u64).to_be_bytes());
+            Signature::new(bytes)
+        })
+        .collect()
+}
+
+/// Returns a successful SOL transfer with the given signature, as returned by Shyft.
+pub fn transaction(signature: &Signature) -> serde_json::Value {
+    serde_json::json!({
+        "timestamp": "2024-08-26T10:38:46.000Z",
+        "fee": 0.000005,
+        "fee_payer": "8R5brRqNa1CDMtcQRaLPQfJeLBrtyqpjDPTSKbBvmsna",
+        "signers": ["8R5brRqNa1CDMtcQRaLPQfJeLBrtyqpjDPTSKbBvmsna"],
+        "signatures": [signature.to_string()],
+        "protocol": {
+            "address": "11111111111111111111111111111111",
+            "name": "SYSTEM_PROGRAM"
+        },
+        "type": "SOL_TRANSFER",
+        "status": "Success",
+        "actions": [],
+        "raw": null,
+        "events": null
+    })
+}
+
+/// Wraps a result in a successful response of the Shyft API.
+pub fn response(result: serde_json::Value) -> serde_json::Value {
+    serde_json::json!({
+        "success": true,
+        "message": "",
+        "result": result
+    })
+}
+
+/// Returns the base URL of the Shyft API served by a stub server.
+pub fn base_url(server: &wiremock::MockServer) -> String {
+    format!("{}/sol/v1/", server.uri())
+}
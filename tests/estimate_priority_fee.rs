@@ -0,0 +1,173 @@
+mod common;
+
+use std::time::Duration;
+
+use shyft_rs_sdk::{
+    models::priority_fee::PriorityFeeEstimate, Error, Pubkey, RequestOptions, ShyftApi,
+};
+use wiremock::{
+    matchers::{method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+#[tokio::test]
+async fn test_estimate_priority_fee() {
+    common::setup();
+
+    // Retrieve the API key from environment variables
+    let api_key = std::env::var("SHYFT_API_KEY").expect("SHYFT_API_KEY must be set");
+
+    // Sample writable account, the Raydium SOL-USDC pool
+    let account: Pubkey = "58oQChx4yWmvKdwLLZzBi4ChoCc2fqCUWBkwMihLYQo2"
+        .parse()
+        .expect("Invalid account");
+
+    // Create ShyftApi instance
+    let shyft_api =
+        ShyftApi::new(&api_key, None, None, None, None, None).expect("Failed to create ShyftApi");
+
+    // Estimate the priority fee at the 75th percentile
+    let estimate = shyft_api.estimate_priority_fee(&[account], 75).await;
+
+    // Assert that the priority fee is estimated successfully
+    assert!(
+        estimate.is_ok(),
+        "Failed to estimate priority fee: {:?}",
+        estimate.err()
+    );
+}
+
+#[test]
+fn test_priority_fee_estimate_from_samples() {
+    // Assert that percentiles use the nearest rank
+    let fees = [50, 10, 40, 20, 30];
+    let low = PriorityFeeEstimate::from_samples(&fees, &[], 0).unwrap();
+    assert_eq!(low.compute_unit_price, 10);
+    let median = PriorityFeeEstimate::from_samples(&fees, &[], 50).unwrap();
+    assert_eq!(median.compute_unit_price, 30);
+    let high = PriorityFeeEstimate::from_samples(&fees, &[], 100).unwrap();
+    assert_eq!(high.compute_unit_price, 50);
+
+    // Assert that missing samples don't fail the estimate
+    let empty = PriorityFeeEstimate::from_samples(&[], &[], 75).unwrap();
+    assert_eq!(empty.compute_unit_price, 0);
+    assert_eq!(empty.compute_units, None);
+    assert_eq!(empty.priority_fee, None);
+
+    // Assert that the priority fee is rounded up to the next lamport
+    let estimate = PriorityFeeEstimate::from_samples(&[1], &[1], 50).unwrap();
+    assert_eq!(estimate.priority_fee, Some(1));
+    let estimate = PriorityFeeEstimate::from_samples(&[u64::MAX], &[u64::MAX], 50).unwrap();
+    assert_eq!(estimate.priority_fee, Some(u64::MAX));
+
+    // Assert that invalid percentiles are rejected
+    assert!(matches!(
+        PriorityFeeEstimate::from_samples(&fees, &[], 101),
+        Err(Error::InvalidRequest(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_estimate_priority_fee_error_hides_api_key() {
+    // A secret API key, sent in the query string of the RPC call
+    let api_key = "secret_api_key_in_query";
+    let account: Pubkey = "58oQChx4yWmvKdwLLZzBi4ChoCc2fqCUWBkwMihLYQo2"
+        .parse()
+        .expect("Invalid account");
+
+    // Time out every request before it is sent
+    let shyft_api = ShyftApi::new(api_key, None, None, None, None, None)
+        .expect("Failed to create ShyftApi")
+        .with_options(
+            RequestOptions::new()
+                .timeout(Duration::from_nanos(1))
+                .max_retries(0),
+        );
+    let error = shyft_api
+        .estimate_priority_fee(&[account], 75)
+        .await
+        .expect_err("Request did not time out");
+
+    // Assert that the API key is not printed with the error
+    assert!(!error.to_string().contains(api_key), "{}", error);
+    assert!(!format!("{:?}", error).contains(api_key), "{:?}", error);
+}
+
+#[tokio::test]
+async fn test_estimate_priority_fee_skips_failed_histories() {
+    let failing: Pubkey = "58oQChx4yWmvKdwLLZzBi4ChoCc2fqCUWBkwMihLYQo2"
+        .parse()
+        .unwrap();
+    let sampled: Pubkey = "8R5brRqNa1CDMtcQRaLPQfJeLBrtyqpjDPTSKbBvmsna"
+        .parse()
+        .unwrap();
+
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/rpc"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "result": [
+                { "slot": 1, "prioritizationFee": 1000 },
+                { "slot": 2, "prioritizationFee": 3000 }
+            ]
+        })))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/sol/v1/transaction/history"))
+        .and(query_param("account", failing.to_string()))
+        .respond_with(ResponseTemplate::new(400).set_body_string("Invalid account"))
+        .mount(&server)
+        .await;
+    let mut transaction = common::transaction(&common::signatures(1)[0]);
+    transaction["raw"] = serde_json::json!({
+        "blockTime": 1724668726,
+        "slot": 2,
+        "meta": {
+            "err": null,
+            "fee": 5000,
+            "preBalances": [1000000000],
+            "postBalances": [999995000],
+            "computeUnitsConsumed": 150000
+        },
+        "transaction": {
+            "signatures": [common::signatures(1)[0].to_string()],
+            "message": {
+                "header": {
+                    "numRequiredSignatures": 1,
+                    "numReadonlySignedAccounts": 0,
+                    "numReadonlyUnsignedAccounts": 0
+                },
+                "accountKeys": [sampled.to_string()],
+                "recentBlockhash": "EkSnNWid2cvwEVnVx9aBqawnmiCNiDgp3gUdkDPTKN1N",
+                "instructions": []
+            }
+        }
+    });
+    Mock::given(method("GET"))
+        .and(path("/sol/v1/transaction/history"))
+        .and(query_param("account", sampled.to_string()))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(common::response(vec![transaction].into())),
+        )
+        .mount(&server)
+        .await;
+
+    let shyft_api = ShyftApi::builder("test_api_key")
+        .base_url(&common::base_url(&server))
+        .rpc_url(&format!("{}/rpc", server.uri()))
+        .max_retries(0)
+        .build()
+        .expect("Failed to create ShyftApi");
+
+    // Assert that the failed history is left out of the estimate instead of failing it
+    let estimate = shyft_api
+        .estimate_priority_fee(&[failing, sampled], 100)
+        .await
+        .expect("Failed to estimate priority fee");
+    assert_eq!(estimate.compute_unit_price, 3000);
+    assert_eq!(estimate.compute_units, Some(150000));
+    assert_eq!(estimate.priority_fee, Some(450));
+}
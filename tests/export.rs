@@ -0,0 +1,153 @@
+#![cfg(feature = "export")]
+
+use shyft_rs_sdk::{export, models::parsed_transaction_details::ParsedTransactionDetails, Decimal};
+
+/// Builds a sample transaction with a swap and a token transfer action.
+fn sample_transaction() -> ParsedTransactionDetails {
+    serde_json::from_value(serde_json::json!({
+        "timestamp": "2024-08-26T10:38:46.000Z",
+        "fee": 0.000005734,
+        "fee_payer": "8R5brRqNa1CDMtcQRaLPQfJeLBrtyqpjDPTSKbBvmsna",
+        "signers": ["8R5brRqNa1CDMtcQRaLPQfJeLBrtyqpjDPTSKbBvmsna"],
+        "signatures": [
+            "uZz2qDvLzndsTEY31YkxgRe1rYQ8MsCtS3DwhPYP1Md7u7dnUK4HW3vYzsxE6GSxFhSG5zpvqSQnSUn1sPmzTBu"
+        ],
+        "protocol": {
+            "address": "JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4",
+            "name": "JUPITER_V_6"
+        },
+        "type": "SWAP",
+        "status": "Success",
+        "actions": [
+            {
+                "info": {
+                    "swapper": "8R5brRqNa1CDMtcQRaLPQfJeLBrtyqpjDPTSKbBvmsna",
+                    "tokens_swapped": {
+                        "in": {
+                            "token_address": "EWFka4M5XZrKzAq54eTXxN5K1H3u2es1HVqvr6hsqdSk",
+                            "name": "DUROV TOKEN",
+                            "symbol": "DUROV",
+                            "image_uri": "",
+                            "amount": 877265344778.8137,
+                            "amount_raw": 877265344778813700u64
+                        },
+                        "out": {
+                            "token_address": "So11111111111111111111111111111111111111112",
+                            "name": "Wrapped SOL",
+                            "symbol": "SOL",
+                            "image_uri": "",
+                            "amount": 80.249704804,
+                            "amount_raw": 80249704804u64
+                        }
+                    },
+                    "swaps": []
+                },
+                "source_protocol": {
+                    "address": "JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4",
+                    "name": "JUPITER_V_6"
+                },
+                "type": "SWAP",
+                "ix_index": 7
+            },
+            {
+                "info": {
+                    "amount": 80.249704804,
+                    "amount_raw": 80249704804u64,
+                    "sender": "5Q544fKrFoe6tsEbD7S8EmxGTJYAKtTVhAW5Q5pge4j1",
+                    "receiver_associated_account": "CoQFjx8fMMTmRKq3MDy7XyacHsp2pgFfUBGNNkLExU4W",
+                    "receiver": "8R5brRqNa1CDMtcQRaLPQfJeLBrtyqpjDPTSKbBvmsna",
+                    "token_address": "So11111111111111111111111111111111111111112"
+                },
+                "source_protocol": {
+                    "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
+                    "name": "Token Program"
+                },
+                "type": "TOKEN_TRANSFER",
+                "parent_protocol": "JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4",
+                "ix_index": 10
+            }
+        ],
+        "raw": null,
+        "events": null
+    }))
+    .expect("Failed to parse transaction")
+}
+
+#[test]
+fn test_export() {
+    let transaction = sample_transaction();
+    let rows = export::flatten([&transaction]);
+
+    // Assert that there is one row per action, with the swap amounts extracted
+    assert_eq!(rows.len(), 2);
+    assert_eq!(rows[0].action_index, Some(0));
+    assert_eq!(rows[0].token_out_amount, Some(Decimal::new(80249704804, 9)));
+    assert_eq!(rows[1].receiver, Some(transaction.fee_payer));
+
+    // Assert that the CSV output has a header and a line per row, with exact amounts
+    let mut csv = Vec::new();
+    export::write_csv(&mut csv, &rows).expect("Failed to write CSV");
+    let csv = String::from_utf8(csv).expect("Invalid UTF-8");
+    assert_eq!(csv.lines().count(), 3);
+    assert!(csv
+        .lines()
+        .next()
+        .unwrap()
+        .starts_with("signature,timestamp,"));
+    assert!(csv.contains(",0.000005734,"));
+
+    // Assert that the JSON Lines output round-trips
+    let mut ndjson = Vec::new();
+    export::write_ndjson(&mut ndjson, &rows).expect("Failed to write JSON Lines");
+    let parsed: Vec<export::ActionRow> = String::from_utf8(ndjson)
+        .expect("Invalid UTF-8")
+        .lines()
+        .map(|line| serde_json::from_str(line).expect("Failed to parse row"))
+        .collect();
+    assert_eq!(parsed, rows);
+
+    // Assert that the Parquet output is a Parquet file
+    #[cfg(feature = "parquet")]
+    {
+        let mut parquet = Vec::new();
+        export::write_parquet(&mut parquet, &rows).expect("Failed to write Parquet");
+        assert!(parquet.starts_with(b"PAR1") && parquet.ends_with(b"PAR1"));
+    }
+}
+
+#[cfg(feature = "parquet")]
+#[test]
+fn test_parquet_columns() {
+    use parquet::file::metadata::ParquetMetaDataReader;
+
+    let transaction = sample_transaction();
+    let rows = export::flatten([&transaction]);
+
+    let mut csv = Vec::new();
+    export::write_csv(&mut csv, &rows).expect("Failed to write CSV");
+    let csv = String::from_utf8(csv).expect("Invalid UTF-8");
+    let header: Vec<&str> = csv.lines().next().unwrap().split(',').collect();
+
+    // Decode the metadata from the footer of the Parquet file
+    let mut parquet = Vec::new();
+    export::write_parquet(&mut parquet, &rows).expect("Failed to write Parquet");
+    let footer: &[u8; 8] = parquet[parquet.len() - 8..].try_into().unwrap();
+    let metadata_len = ParquetMetaDataReader::decode_footer_tail(footer)
+        .expect("Failed to decode footer")
+        .metadata_length();
+    let metadata_start = parquet.len() - 8 - metadata_len;
+    let metadata =
+        ParquetMetaDataReader::decode_metadata(&parquet[metadata_start..parquet.len() - 8])
+            .expect("Failed to decode metadata");
+
+    // Assert that the Parquet columns match the CSV header, in order
+    let columns: Vec<&str> = metadata
+        .file_metadata()
+        .schema_descr()
+        .columns()
+        .iter()
+        .map(|column| column.name())
+        .collect();
+    assert_eq!(columns, header);
+    assert_eq!(metadata.file_metadata().num_rows(), rows.len() as i64);
+}
@@ -1,6 +1,8 @@
 mod common;
 
-use shyft_rs_sdk::{models::parsed_transaction_details::ParsedTransactionDetails, ShyftApi};
+use shyft_rs_sdk::{
+    models::parsed_transaction_details::ParsedTransactionDetails, ShyftApi, Signature,
+};
 
 #[tokio::test]
 async fn test_get_parsed_transaction_details() {
@@ -10,15 +12,17 @@ async fn test_get_parsed_transaction_details() {
     let api_key = std::env::var("SHYFT_API_KEY").expect("SHYFT_API_KEY must be set");
 
     // Sample transaction signature
-    let tx_signature =
-        "uZz2qDvLzndsTEY31YkxgRe1rYQ8MsCtS3DwhPYP1Md7u7dnUK4HW3vYzsxE6GSxFhSG5zpvqSQnSUn1sPmzTBu";
+    let tx_signature: Signature =
+        "uZz2qDvLzndsTEY31YkxgRe1rYQ8MsCtS3DwhPYP1Md7u7dnUK4HW3vYzsxE6GSxFhSG5zpvqSQnSUn1sPmzTBu"
+            .parse()
+            .expect("Invalid transaction signature");
 
     // Create ShyftApi instance
     let shyft_api =
         ShyftApi::new(&api_key, None, None, None, None, None).expect("Failed to create ShyftApi");
 
     // Fetch parsed transaction details
-    let parsed_transaction_details = shyft_api.get_transaction_parsed(tx_signature).await;
+    let parsed_transaction_details = shyft_api.get_transaction_parsed(&tx_signature).await;
 
     // Assert that the parsed transaction details are fetched successfully
     assert!(
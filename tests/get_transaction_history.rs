@@ -1,6 +1,6 @@
 mod common;
 
-use shyft_rs_sdk::ShyftApi;
+use shyft_rs_sdk::{Pubkey, ShyftApi, Signature};
 
 #[tokio::test]
 async fn test_get_transaction_history() {
@@ -9,9 +9,13 @@ async fn test_get_transaction_history() {
     let api_key = std::env::var("SHYFT_API_KEY").expect("SHYFT_API_KEY must be set");
 
     // Sample account and transaction signature
-    let test_account = "8R5brRqNa1CDMtcQRaLPQfJeLBrtyqpjDPTSKbBvmsna";
-    let before_tx_signature =
-        "uZz2qDvLzndsTEY31YkxgRe1rYQ8MsCtS3DwhPYP1Md7u7dnUK4HW3vYzsxE6GSxFhSG5zpvqSQnSUn1sPmzTBu";
+    let test_account: Pubkey = "8R5brRqNa1CDMtcQRaLPQfJeLBrtyqpjDPTSKbBvmsna"
+        .parse()
+        .expect("Invalid account");
+    let before_tx_signature: Signature =
+        "uZz2qDvLzndsTEY31YkxgRe1rYQ8MsCtS3DwhPYP1Md7u7dnUK4HW3vYzsxE6GSxFhSG5zpvqSQnSUn1sPmzTBu"
+            .parse()
+            .expect("Invalid transaction signature");
 
     // Create ShyftApi instance
     let shyft_api =
@@ -20,9 +24,9 @@ async fn test_get_transaction_history() {
     // Fetch transaction history
     let transaction_history = shyft_api
         .get_transaction_history(
-            test_account,
+            &test_account,
             Some(1),
-            Some(before_tx_signature),
+            Some(&before_tx_signature),
             None,
             Some(true),
             None,
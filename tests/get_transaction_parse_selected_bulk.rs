@@ -0,0 +1,179 @@
+mod common;
+
+use std::{
+    collections::HashSet,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use shyft_rs_sdk::{Error, ShyftApi, Signature};
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, Request, Respond, ResponseTemplate,
+};
+
+/// Delay of the stub responses, long enough to observe the concurrency of the batches.
+const RESPONSE_DELAY: Duration = Duration::from_millis(200);
+
+/// Arrival time and signatures of the batches received by the stub.
+type Batches = Arc<Mutex<Vec<(Instant, Vec<Signature>)>>>;
+
+/// Stub of the parse_selected endpoint, recording the batches it receives.
+struct ParseSelectedStub {
+    /// Batches received.
+    batches: Batches,
+    /// Signature whose batch fails.
+    failing: Signature,
+    /// Signature left out of the response of its batch.
+    missing: Signature,
+}
+
+impl Respond for ParseSelectedStub {
+    fn respond(&self, request: &Request) -> ResponseTemplate {
+        let body: serde_json::Value = request.body_json().expect("Invalid body");
+        let signatures: Vec<Signature> =
+            serde_json::from_value(body["transaction_signatures"].clone())
+                .expect("Invalid signatures");
+        self.batches
+            .lock()
+            .unwrap()
+            .push((Instant::now(), signatures.clone()));
+
+        if signatures.contains(&self.failing) {
+            return ResponseTemplate::new(400)
+                .set_body_string("Invalid transaction")
+                .set_delay(RESPONSE_DELAY);
+        }
+        let transactions: Vec<serde_json::Value> = signatures
+            .iter()
+            .filter(|signature| **signature != self.missing)
+            .map(common::transaction)
+            .collect();
+        ResponseTemplate::new(200)
+            .set_body_json(common::response(transactions.into()))
+            .set_delay(RESPONSE_DELAY)
+    }
+}
+
+#[tokio::test]
+async fn test_get_transaction_parse_selected_bulk() {
+    common::setup();
+
+    // Retrieve the API key from environment variables
+    let api_key = std::env::var("SHYFT_API_KEY").expect("SHYFT_API_KEY must be set");
+
+    // Sample transaction signatures
+    let transaction_signatures: Vec<Signature> = [
+        "2WjUUuMHciuwLXPgSmddyHF9qPfZ15wcja9G1hprSKfk3JLhrhsyGL2VjVfA8apEMmpskvfwBX34nJ3btihGn2rH",
+        "3uYZVwazovut7WrgLC7VXbhfcysX1VDo8ugDfx2SJ4YHBZMkmieh4A7b2hPZghqDJr15ZUeCpVvBUEr8FE3nzz4U",
+    ]
+    .iter()
+    .map(|signature| signature.parse().expect("Invalid transaction signature"))
+    .collect();
+
+    // Create ShyftApi instance
+    let shyft_api =
+        ShyftApi::new(&api_key, None, None, None, None, None).expect("Failed to create ShyftApi");
+
+    // Fetch parsed transaction details for the given signatures
+    let results = shyft_api
+        .get_transaction_parse_selected_bulk(transaction_signatures.clone(), 2, None, None)
+        .await;
+
+    // Assert that the results are returned in the order of the input signatures
+    let signatures: Vec<Signature> = results.iter().map(|(signature, _)| *signature).collect();
+    assert_eq!(signatures, transaction_signatures);
+
+    // Assert that every transaction is fetched successfully
+    for (signature, result) in results {
+        assert!(
+            result.is_ok(),
+            "Failed to fetch parsed transaction details for {}: {:?}",
+            signature,
+            result.err()
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_get_transaction_parse_selected_bulk_batches() {
+    let transaction_signatures = common::signatures(450);
+    let batches = Batches::default();
+
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/sol/v1/transaction/parse_selected"))
+        .respond_with(ParseSelectedStub {
+            batches: batches.clone(),
+            failing: transaction_signatures[250],
+            missing: transaction_signatures[420],
+        })
+        .mount(&server)
+        .await;
+
+    let shyft_api = ShyftApi::builder("test_api_key")
+        .base_url(&common::base_url(&server))
+        .max_retries(0)
+        .build()
+        .expect("Failed to create ShyftApi");
+
+    let results = shyft_api
+        .get_transaction_parse_selected_bulk(transaction_signatures.clone(), 2, None, None)
+        .await;
+
+    // Assert that the signatures are split into batches of at most 100, each sent once
+    let mut batches = batches.lock().unwrap().clone();
+    batches.sort_by_key(|(arrival, _)| *arrival);
+    let mut sizes: Vec<usize> = batches.iter().map(|(_, batch)| batch.len()).collect();
+    sizes.sort_unstable();
+    assert_eq!(sizes, vec![50, 100, 100, 100, 100]);
+    let sent: HashSet<Signature> = batches
+        .iter()
+        .flat_map(|(_, batch)| batch.iter().copied())
+        .collect();
+    assert_eq!(sent.len(), transaction_signatures.len());
+
+    // Assert that no more than 2 batches were in flight at once
+    for window in batches.windows(3) {
+        assert!(
+            window[2].0 - window[0].0 >= RESPONSE_DELAY,
+            "3 batches were sent within {:?}",
+            window[2].0 - window[0].0
+        );
+    }
+
+    // Assert that the results are returned in the order of the input signatures
+    let signatures: Vec<Signature> = results.iter().map(|(signature, _)| *signature).collect();
+    assert_eq!(signatures, transaction_signatures);
+
+    // Assert that the error of the failed batch is shared by all its signatures
+    let failed: Vec<&Arc<Error>> = results[200..300]
+        .iter()
+        .map(|(signature, result)| match result {
+            Err(Error::Batch(error)) => error,
+            other => panic!("Unexpected result for {}: {:?}", signature, other),
+        })
+        .collect();
+    assert!(matches!(failed[0].as_ref(), Error::StatusNot200(_)));
+    assert!(failed.iter().all(|error| Arc::ptr_eq(error, failed[0])));
+
+    // Assert that the other batches succeed, except for the transaction missing from its batch
+    for (i, (signature, result)) in results.iter().enumerate() {
+        match i {
+            200..300 => {}
+            420 => assert!(
+                matches!(result, Err(Error::TransactionNotFound(s)) if s == signature),
+                "Unexpected result for {}: {:?}",
+                signature,
+                result
+            ),
+            _ => assert_eq!(
+                result
+                    .as_ref()
+                    .map(|transaction| transaction.signatures[0])
+                    .ok(),
+                Some(*signature)
+            ),
+        }
+    }
+}
@@ -0,0 +1,300 @@
+#![cfg(feature = "grpc")]
+
+use std::{
+    convert::Infallible,
+    sync::{Arc, Mutex},
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use futures::{channel::mpsc, StreamExt};
+use shyft_rs_sdk::{
+    grpc::{
+        proto::{self, subscribe_update::UpdateOneof},
+        CommitmentLevel, GrpcClient, SlotStatus, SubscribeFilters, TransactionFilter, UpdateKind,
+    },
+    models::raw_transaction::{AccountKey, Instruction},
+    Error, Pubkey, Signature,
+};
+use tonic::{
+    codec::ProstCodec,
+    codegen::{http, BoxFuture, Service},
+    server::{Grpc, NamedService, StreamingService},
+    Request, Response, Status, Streaming,
+};
+
+/// A stub `Geyser` service, recording the requests of each connection.
+#[derive(Clone, Default)]
+struct StubGeyser {
+    requests: Arc<Mutex<Vec<Vec<proto::SubscribeRequest>>>>,
+}
+
+impl NamedService for StubGeyser {
+    const NAME: &'static str = "geyser.Geyser";
+}
+
+impl Service<http::Request<tonic::body::BoxBody>> for StubGeyser {
+    type Response = http::Response<tonic::body::BoxBody>;
+    type Error = Infallible;
+    type Future = BoxFuture<Self::Response, Self::Error>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, req: http::Request<tonic::body::BoxBody>) -> Self::Future {
+        let service = self.clone();
+        Box::pin(async move {
+            let codec = ProstCodec::<proto::SubscribeUpdate, proto::SubscribeRequest>::default();
+            Ok(Grpc::new(codec).streaming(service, req).await)
+        })
+    }
+}
+
+impl StreamingService<proto::SubscribeRequest> for StubGeyser {
+    type Response = proto::SubscribeUpdate;
+    type ResponseStream = mpsc::UnboundedReceiver<Result<proto::SubscribeUpdate, Status>>;
+    type Future = BoxFuture<Response<Self::ResponseStream>, Status>;
+
+    fn call(&mut self, request: Request<Streaming<proto::SubscribeRequest>>) -> Self::Future {
+        assert_eq!(
+            request
+                .metadata()
+                .get("x-token")
+                .map(|token| token.to_str().unwrap()),
+            Some("stub_token")
+        );
+
+        let connection = {
+            let mut requests = self.requests.lock().unwrap();
+            requests.push(Vec::new());
+            requests.len() - 1
+        };
+        let requests = self.requests.clone();
+        let mut incoming = request.into_inner();
+        let (updates, receiver) = mpsc::unbounded();
+
+        tokio::spawn(async move {
+            let record = |request: proto::SubscribeRequest| {
+                let is_ping = request.ping.is_some();
+                requests.lock().unwrap()[connection].push(request);
+                is_ping
+            };
+
+            // Wait for the subscription request
+            let request = incoming.message().await.unwrap().unwrap();
+            record(request);
+
+            if connection == 0 {
+                // Send a transaction and a ping, then fail once the ping is answered
+                updates.unbounded_send(Ok(transaction_update())).unwrap();
+                let ping = proto::SubscribeUpdate {
+                    filters: vec![],
+                    update_oneof: Some(UpdateOneof::Ping(proto::SubscribeUpdatePing {})),
+                };
+                updates.unbounded_send(Ok(ping)).unwrap();
+                while let Ok(Some(request)) = incoming.message().await {
+                    if record(request) {
+                        break;
+                    }
+                }
+                updates
+                    .unbounded_send(Err(Status::unavailable("stub restarting")))
+                    .unwrap();
+            } else {
+                // Send a slot update, then keep the stream open
+                let slot = proto::SubscribeUpdate {
+                    filters: vec!["slots".to_string()],
+                    update_oneof: Some(UpdateOneof::Slot(proto::SubscribeUpdateSlot {
+                        slot: 101,
+                        parent: Some(100),
+                        status: SlotStatus::Confirmed.into(),
+                    })),
+                };
+                updates.unbounded_send(Ok(slot)).unwrap();
+                while let Ok(Some(request)) = incoming.message().await {
+                    record(request);
+                }
+            }
+        });
+
+        Box::pin(async move { Ok(Response::new(receiver)) })
+    }
+}
+
+const SIGNATURE: &str =
+    "uZz2qDvLzndsTEY31YkxgRe1rYQ8MsCtS3DwhPYP1Md7u7dnUK4HW3vYzsxE6GSxFhSG5zpvqSQnSUn1sPmzTBu";
+const FEE_PAYER: &str = "8R5brRqNa1CDMtcQRaLPQfJeLBrtyqpjDPTSKbBvmsna";
+const SYSTEM_PROGRAM: &str = "11111111111111111111111111111111";
+const USDC: &str = "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v";
+
+/// Returns a transfer from the fee payer to the system program, in slot 100.
+fn transaction_update() -> proto::SubscribeUpdate {
+    let signature = SIGNATURE.parse::<Signature>().unwrap().to_bytes().to_vec();
+    let token_balance = proto::TokenBalance {
+        account_index: 0,
+        mint: USDC.to_string(),
+        ui_token_amount: Some(proto::UiTokenAmount {
+            ui_amount: 1.5,
+            decimals: 6,
+            amount: "1500000".to_string(),
+            ui_amount_string: "1.5".to_string(),
+        }),
+        owner: FEE_PAYER.to_string(),
+        program_id: String::new(),
+    };
+
+    proto::SubscribeUpdate {
+        filters: vec!["transfers".to_string()],
+        update_oneof: Some(UpdateOneof::Transaction(Box::new(
+            proto::SubscribeUpdateTransaction {
+                transaction: Some(proto::SubscribeUpdateTransactionInfo {
+                    signature: signature.clone(),
+                    is_vote: false,
+                    transaction: Some(proto::Transaction {
+                        signatures: vec![signature],
+                        message: Some(proto::Message {
+                            header: Some(proto::MessageHeader {
+                                num_required_signatures: 1,
+                                num_readonly_signed_accounts: 0,
+                                num_readonly_unsigned_accounts: 1,
+                            }),
+                            account_keys: vec![
+                                FEE_PAYER.parse::<Pubkey>().unwrap().to_bytes().to_vec(),
+                                SYSTEM_PROGRAM
+                                    .parse::<Pubkey>()
+                                    .unwrap()
+                                    .to_bytes()
+                                    .to_vec(),
+                            ],
+                            recent_blockhash: vec![1; 32],
+                            instructions: vec![proto::CompiledInstruction {
+                                program_id_index: 1,
+                                accounts: vec![0],
+                                data: vec![2, 0, 0, 0],
+                            }],
+                            versioned: false,
+                            address_table_lookups: vec![],
+                        }),
+                    }),
+                    meta: Some(proto::TransactionStatusMeta {
+                        err: None,
+                        fee: 5000,
+                        pre_balances: vec![1_000_000_000, 1],
+                        post_balances: vec![999_995_000, 1],
+                        log_messages: vec![format!("Program {} invoke [1]", SYSTEM_PROGRAM)],
+                        pre_token_balances: vec![token_balance.clone()],
+                        post_token_balances: vec![token_balance],
+                        compute_units_consumed: Some(150),
+                        ..Default::default()
+                    }),
+                    index: 7,
+                }),
+                slot: 100,
+            },
+        ))),
+    }
+}
+
+#[tokio::test]
+async fn test_grpc_subscribe() {
+    // Start the stub server on a random port
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+    let stub = StubGeyser::default();
+    tokio::spawn(
+        tonic::transport::Server::builder()
+            .add_service(stub.clone())
+            .serve_with_incoming(tokio_stream::wrappers::TcpListenerStream::new(listener)),
+    );
+
+    let builder = GrpcClient::builder(&format!("http://{}", address))
+        .x_token("stub_token")
+        .min_reconnect_interval(Duration::from_millis(10));
+
+    // Assert that the token is not printed
+    assert!(!format!("{:?}", builder).contains("stub_token"));
+    let client = builder.build().expect("Failed to create GrpcClient");
+    assert!(!format!("{:?}", client).contains("stub_token"));
+    let fee_payer = FEE_PAYER.parse().unwrap();
+    let filters = SubscribeFilters::new()
+        .transactions(
+            "transfers",
+            TransactionFilter::new()
+                .account_include(fee_payer)
+                .account_exclude(USDC.parse().unwrap())
+                .vote(false),
+        )
+        .commitment(CommitmentLevel::Confirmed);
+    let mut updates = Box::pin(client.subscribe(filters));
+
+    // Assert that the transaction is converted into the raw transaction model
+    let update = updates
+        .next()
+        .await
+        .unwrap()
+        .expect("Failed to receive update");
+    assert_eq!(update.filters, vec!["transfers"]);
+    let UpdateKind::Transaction(transaction) = update.kind else {
+        panic!("Expected a transaction update, got {:?}", update.kind);
+    };
+    assert_eq!(transaction.slot, 100);
+    assert_eq!(transaction.index, 7);
+    assert_eq!(transaction.signature.to_string(), SIGNATURE);
+    let raw = &transaction.transaction;
+    assert_eq!(raw.signature(), Some(&transaction.signature));
+    assert_eq!(
+        raw.account_keys(),
+        vec![fee_payer, SYSTEM_PROGRAM.parse().unwrap()]
+    );
+    assert_eq!(
+        raw.transaction.message.account_keys[0],
+        AccountKey::Plain(fee_payer)
+    );
+    let Instruction::Compiled(instruction) = &raw.transaction.message.instructions[0] else {
+        panic!("Expected a compiled instruction");
+    };
+    assert_eq!(instruction.data, bs58::encode([2, 0, 0, 0]).into_string());
+    let meta = raw.meta.as_ref().unwrap();
+    assert_eq!(meta.fee, 5000);
+    assert_eq!(meta.compute_units_consumed, Some(150));
+    let token_balance = &meta.pre_token_balances.as_ref().unwrap()[0];
+    assert_eq!(token_balance.ui_token_amount.amount, 1_500_000);
+    assert_eq!(token_balance.owner, Some(fee_payer));
+    assert_eq!(token_balance.program_id, None);
+
+    // Assert that the stream error is reported without ending the stream
+    let error = updates.next().await.unwrap().unwrap_err();
+    assert!(matches!(error, Error::GrpcStatus(_)), "{:?}", error);
+
+    // Assert that the client reconnects
+    let update = updates
+        .next()
+        .await
+        .unwrap()
+        .expect("Failed to receive update");
+    let UpdateKind::Slot(slot) = update.kind else {
+        panic!("Expected a slot update, got {:?}", update.kind);
+    };
+    assert_eq!(slot.status, SlotStatus::Confirmed);
+
+    let requests = stub.requests.lock().unwrap().clone();
+    assert_eq!(requests.len(), 2);
+
+    // Assert that the filters were sent
+    let request = &requests[0][0];
+    let filter = &request.transactions["transfers"];
+    assert_eq!(filter.account_include, vec![FEE_PAYER]);
+    assert_eq!(filter.account_exclude, vec![USDC]);
+    assert_eq!(filter.vote, Some(false));
+    assert_eq!(request.commitment, Some(CommitmentLevel::Confirmed.into()));
+    assert_eq!(request.from_slot, None);
+
+    // Assert that the ping of the server was answered
+    assert!(requests[0][1].ping.is_some());
+
+    // Assert that the subscription resumed from the last slot received
+    let request = &requests[1][0];
+    assert_eq!(request.from_slot, Some(100));
+    assert!(request.transactions.contains_key("transfers"));
+}
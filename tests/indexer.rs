@@ -0,0 +1,478 @@
+#![cfg(feature = "indexer")]
+
+mod common;
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use shyft_rs_sdk::{
+    indexer::{Checkpoint, IndexQuery, Indexer},
+    models::parsed_transaction_details::{ParsedTransactionDetails, TxType},
+    Error, Pubkey, ShyftApi, Signature,
+};
+use wiremock::{
+    matchers::{method, path, query_param},
+    Mock, MockServer, Request, Respond, ResponseTemplate,
+};
+
+const WALLET: &str = "8R5brRqNa1CDMtcQRaLPQfJeLBrtyqpjDPTSKbBvmsna";
+const TOKEN: &str = "EWFka4M5XZrKzAq54eTXxN5K1H3u2es1HVqvr6hsqdSk";
+const SOL: &str = "So11111111111111111111111111111111111111112";
+const SWAP_SIGNATURE: &str =
+    "uZz2qDvLzndsTEY31YkxgRe1rYQ8MsCtS3DwhPYP1Md7u7dnUK4HW3vYzsxE6GSxFhSG5zpvqSQnSUn1sPmzTBu";
+const TRANSFER_SIGNATURE: &str =
+    "3cR2p4mKF5XaHpbsHJdCg8EXK3W1RR9kZqNoWN3S3wzr4fA9uW2VGzvt8Vx4nuW79osDfuS7tXDomzxpxWEXgdvF";
+
+/// Path of the stubbed transaction history endpoint.
+const HISTORY_PATH: &str = "/sol/v1/transaction/history";
+
+/// Signatures of the history of each wallet, newest first.
+type Histories = Arc<Mutex<HashMap<String, Vec<Signature>>>>;
+
+/// Stub of the transaction history endpoint, serving the histories of the wallets.
+struct HistoryStub {
+    /// Histories served, which can change between syncs.
+    histories: Histories,
+}
+
+impl Respond for HistoryStub {
+    fn respond(&self, request: &Request) -> ResponseTemplate {
+        let signature = |name: &str| query(request, name).map(|value| value.parse().unwrap());
+        let account = query(request, "account").expect("Missing account");
+        let tx_num: usize = query(request, "tx_num").map_or(10, |value| value.parse().unwrap());
+        let before: Option<Signature> = signature("before_tx_signature");
+        let until: Option<Signature> = signature("until_tx_signature");
+
+        // Like Shyft, serve the transactions strictly between `before` and `until`
+        let histories = self.histories.lock().unwrap();
+        let history = histories.get(&account).map_or(&[][..], Vec::as_slice);
+        let start = before.map_or(0, |before| {
+            history
+                .iter()
+                .position(|signature| *signature == before)
+                .map_or(history.len(), |i| i + 1)
+        });
+        let transactions: Vec<serde_json::Value> = history[start..]
+            .iter()
+            .take_while(|signature| Some(**signature) != until)
+            .take(tx_num)
+            .map(common::transaction)
+            .collect();
+        ResponseTemplate::new(200).set_body_json(common::response(transactions.into()))
+    }
+}
+
+/// Returns the value of a query parameter of a request.
+fn query(request: &Request, name: &str) -> Option<String> {
+    request
+        .url
+        .query_pairs()
+        .find(|(key, _)| key == name)
+        .map(|(_, value)| value.into_owned())
+}
+
+/// Returns the history requests received by the stub server since the first `skip` ones, as
+/// their `before_tx_signature` and `until_tx_signature` parameters.
+async fn history_requests(
+    server: &MockServer,
+    skip: usize,
+) -> Vec<(Option<String>, Option<String>)> {
+    server.received_requests().await.unwrap()[skip..]
+        .iter()
+        .map(|request| {
+            (
+                query(request, "before_tx_signature"),
+                query(request, "until_tx_signature"),
+            )
+        })
+        .collect()
+}
+
+/// Returns signatures newest first, as served by the history.
+fn newest_first(signatures: &[Signature]) -> Vec<Signature> {
+    signatures.iter().rev().copied().collect()
+}
+
+/// Builds a swap of the token for SOL, with a token transfer action.
+fn swap() -> ParsedTransactionDetails {
+    serde_json::from_value(serde_json::json!({
+        "timestamp": "2024-08-26T10:38:46.000Z",
+        "fee": 0.000005734,
+        "fee_payer": WALLET,
+        "signers": [WALLET],
+        "signatures": [SWAP_SIGNATURE],
+        "protocol": {
+            "address": "JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4",
+            "name": "JUPITER_V_6"
+        },
+        "type": "SWAP",
+        "status": "Success",
+        "actions": [
+            {
+                "info": {
+                    "swapper": WALLET,
+                    "tokens_swapped": {
+                        "in": {
+                            "token_address": TOKEN,
+                            "name": "DUROV TOKEN",
+                            "symbol": "DUROV",
+                            "image_uri": "",
+                            "amount": 877265344778.8137,
+                            "amount_raw": 877265344778813700u64
+                        },
+                        "out": {
+                            "token_address": SOL,
+                            "name": "Wrapped SOL",
+                            "symbol": "SOL",
+                            "image_uri": "",
+                            "amount": 80.249704804,
+                            "amount_raw": 80249704804u64
+                        }
+                    },
+                    "swaps": []
+                },
+                "source_protocol": {
+                    "address": "JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4",
+                    "name": "JUPITER_V_6"
+                },
+                "type": "SWAP",
+                "ix_index": 7
+            },
+            {
+                "info": {
+                    "amount": 80.249704804,
+                    "amount_raw": 80249704804u64,
+                    "sender": "5Q544fKrFoe6tsEbD7S8EmxGTJYAKtTVhAW5Q5pge4j1",
+                    "receiver_associated_account": "CoQFjx8fMMTmRKq3MDy7XyacHsp2pgFfUBGNNkLExU4W",
+                    "receiver": WALLET,
+                    "token_address": SOL
+                },
+                "source_protocol": {
+                    "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
+                    "name": "Token Program"
+                },
+                "type": "TOKEN_TRANSFER",
+                "parent_protocol": "JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4",
+                "ix_index": 10
+            }
+        ],
+        "raw": null,
+        "events": null
+    }))
+    .expect("Failed to parse transaction")
+}
+
+/// Builds a SOL transfer from the wallet, a day after the swap.
+fn transfer() -> ParsedTransactionDetails {
+    serde_json::from_value(serde_json::json!({
+        "timestamp": "2024-08-27T08:00:00.000Z",
+        "fee": 0.000005,
+        "fee_payer": WALLET,
+        "signers": [WALLET],
+        "signatures": [TRANSFER_SIGNATURE],
+        "protocol": {
+            "address": "11111111111111111111111111111111",
+            "name": "SYSTEM_PROGRAM"
+        },
+        "type": "SOL_TRANSFER",
+        "status": "Success",
+        "actions": [
+            {
+                "info": {
+                    "sender": WALLET,
+                    "receiver": "5Q544fKrFoe6tsEbD7S8EmxGTJYAKtTVhAW5Q5pge4j1",
+                    "amount": 0.5,
+                    "amount_raw": 500000000u64
+                },
+                "source_protocol": {
+                    "address": "11111111111111111111111111111111",
+                    "name": "SYSTEM_PROGRAM"
+                },
+                "type": "SOL_TRANSFER"
+            }
+        ],
+        "raw": null,
+        "events": null
+    }))
+    .expect("Failed to parse transaction")
+}
+
+#[test]
+fn test_indexer_insert() {
+    let mut indexer = Indexer::open_in_memory().expect("Failed to open indexer");
+    let wallet: Pubkey = WALLET.parse().expect("Invalid address");
+    let other: Pubkey = TOKEN.parse().expect("Invalid address");
+
+    // Assert that transactions are deduplicated by signature
+    let inserted = indexer
+        .insert(&wallet, &[swap(), transfer()])
+        .expect("Failed to insert");
+    assert_eq!(inserted, 2);
+    let inserted = indexer
+        .insert(&wallet, &[transfer()])
+        .expect("Failed to insert");
+    assert_eq!(inserted, 0);
+    let inserted = indexer.insert(&other, &[swap()]).expect("Failed to insert");
+    assert_eq!(inserted, 0);
+
+    // Assert that transactions are returned whole, newest first
+    let transactions = indexer
+        .transactions(&IndexQuery::new())
+        .expect("Failed to query");
+    assert_eq!(transactions, vec![transfer(), swap()]);
+    let transactions = indexer
+        .transactions(&IndexQuery::new().wallet(other))
+        .expect("Failed to query");
+    assert_eq!(transactions, vec![swap()]);
+
+    // Assert that inserting leaves the checkpoints untouched
+    assert_eq!(indexer.checkpoint(&wallet).expect("Failed to read"), None);
+}
+
+#[test]
+fn test_indexer_queries() {
+    let mut indexer = Indexer::open_in_memory().expect("Failed to open indexer");
+    let wallet: Pubkey = WALLET.parse().expect("Invalid address");
+    indexer
+        .insert(&wallet, &[swap(), transfer()])
+        .expect("Failed to insert");
+
+    // Assert that actions are flattened with their transaction
+    let actions = indexer
+        .actions(&IndexQuery::new().wallet(wallet))
+        .expect("Failed to query");
+    let types: Vec<_> = actions
+        .iter()
+        .map(|row| row.action_type.clone().unwrap())
+        .collect();
+    assert_eq!(
+        types,
+        vec![TxType::SolTransfer, TxType::Swap, TxType::TokenTransfer]
+    );
+
+    // Assert that actions are filtered by type
+    let actions = indexer
+        .actions(&IndexQuery::new().action_type(TxType::Swap))
+        .expect("Failed to query");
+    assert_eq!(actions.len(), 1);
+    assert_eq!(actions[0].signature, Some(SWAP_SIGNATURE.parse().unwrap()));
+    assert_eq!(actions[0].token_out_address, Some(SOL.parse().unwrap()));
+
+    // Assert that actions are filtered by mint, sent or received
+    let token: Pubkey = TOKEN.parse().unwrap();
+    let actions = indexer
+        .actions(&IndexQuery::new().mint(token))
+        .expect("Failed to query");
+    assert_eq!(actions.len(), 1);
+    let actions = indexer
+        .actions(&IndexQuery::new().mint(SOL.parse().unwrap()))
+        .expect("Failed to query");
+    assert_eq!(actions.len(), 3);
+
+    // Assert that transactions are filtered by their actions
+    let transactions = indexer
+        .transactions(&IndexQuery::new().mint(token))
+        .expect("Failed to query");
+    assert_eq!(transactions, vec![swap()]);
+
+    // Assert that transactions are filtered by time range
+    let day = |day: &str| {
+        chrono::DateTime::parse_from_rfc3339(day)
+            .unwrap()
+            .with_timezone(&chrono::Utc)
+    };
+    let transactions = indexer
+        .transactions(
+            &IndexQuery::new()
+                .from(day("2024-08-26T00:00:00Z"))
+                .until(day("2024-08-27T00:00:00Z")),
+        )
+        .expect("Failed to query");
+    assert_eq!(transactions, vec![swap()]);
+    let transactions = indexer
+        .transactions(&IndexQuery::new().from(day("2024-08-27T08:00:00Z")))
+        .expect("Failed to query");
+    assert_eq!(transactions, vec![transfer()]);
+
+    // Assert that results are limited
+    let actions = indexer
+        .actions(&IndexQuery::new().limit(2))
+        .expect("Failed to query");
+    assert_eq!(actions.len(), 2);
+}
+
+#[tokio::test]
+async fn test_indexer_sync() {
+    common::setup();
+
+    // Retrieve the API key from environment variables
+    let api_key = std::env::var("SHYFT_API_KEY").expect("SHYFT_API_KEY must be set");
+    let wallet: Pubkey = WALLET.parse().expect("Invalid address");
+
+    // Create ShyftApi instance
+    let shyft_api =
+        ShyftApi::new(&api_key, None, None, None, None, None).expect("Failed to create ShyftApi");
+
+    // Sync the wallet history twice
+    let mut indexer = Indexer::open_in_memory().expect("Failed to open indexer");
+    let inserted = indexer
+        .sync_wallet(&shyft_api, wallet, None, None)
+        .await
+        .expect("Failed to sync");
+    let stored = indexer
+        .transactions(&IndexQuery::new().wallet(wallet))
+        .expect("Failed to query");
+    assert_eq!(inserted, stored.len());
+
+    // Assert that the history was backfilled up to the newest transaction
+    let checkpoint = indexer
+        .checkpoint(&wallet)
+        .expect("Failed to read")
+        .expect("Wallet not tracked");
+    assert!(checkpoint.backfilled);
+    assert_eq!(checkpoint.newest, stored[0].signatures.first().copied());
+
+    // Assert that a second sync only fetches new transactions
+    let inserted = indexer
+        .sync(&shyft_api, None, None)
+        .await
+        .expect("Failed to sync");
+    let total = indexer
+        .transactions(&IndexQuery::new())
+        .expect("Failed to query")
+        .len();
+    assert_eq!(total, stored.len() + inserted);
+}
+
+#[tokio::test]
+async fn test_indexer_sync_resume() {
+    let wallet: Pubkey = WALLET.parse().expect("Invalid address");
+    let other: Pubkey = TOKEN.parse().expect("Invalid address");
+    let signatures = common::signatures(371);
+    let histories = Histories::default();
+    histories
+        .lock()
+        .unwrap()
+        .insert(wallet.to_string(), newest_first(&signatures[..250]));
+
+    // Fail the second page of the backfill once
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(HISTORY_PATH))
+        .and(query_param(
+            "before_tx_signature",
+            signatures[150].to_string(),
+        ))
+        .respond_with(ResponseTemplate::new(500))
+        .up_to_n_times(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(HISTORY_PATH))
+        .respond_with(HistoryStub {
+            histories: histories.clone(),
+        })
+        .mount(&server)
+        .await;
+    let shyft_api = ShyftApi::builder("test_api_key")
+        .base_url(&common::base_url(&server))
+        .max_retries(0)
+        .build()
+        .expect("Failed to create ShyftApi");
+    let mut indexer = Indexer::open_in_memory().expect("Failed to open indexer");
+    let stored = |indexer: &Indexer, wallet: Pubkey| {
+        indexer
+            .transactions(&IndexQuery::new().wallet(wallet))
+            .expect("Failed to query")
+            .len()
+    };
+
+    // Assert that the interrupted backfill keeps the pages stored before the failure
+    let result = indexer.sync_wallet(&shyft_api, wallet, None, None).await;
+    assert!(
+        matches!(result, Err(Error::StatusNot200(_))),
+        "{:?}",
+        result
+    );
+    assert_eq!(stored(&indexer, wallet), 100);
+    assert_eq!(
+        indexer.checkpoint(&wallet).expect("Failed to read"),
+        Some(Checkpoint {
+            newest: Some(signatures[249]),
+            oldest: Some(signatures[150]),
+            backfilled: false,
+        })
+    );
+
+    // Assert that the next sync resumes the backfill from the oldest transaction stored
+    let inserted = indexer
+        .sync_wallet(&shyft_api, wallet, None, None)
+        .await
+        .expect("Failed to sync");
+    assert_eq!(inserted, 150);
+    assert_eq!(stored(&indexer, wallet), 250);
+    assert_eq!(
+        history_requests(&server, 2).await,
+        vec![
+            (None, Some(signatures[249].to_string())),
+            (Some(signatures[150].to_string()), None),
+            (Some(signatures[50].to_string()), None),
+        ]
+    );
+    assert_eq!(
+        indexer.checkpoint(&wallet).expect("Failed to read"),
+        Some(Checkpoint {
+            newest: Some(signatures[249]),
+            oldest: Some(signatures[0]),
+            backfilled: true,
+        })
+    );
+
+    // Assert that a sync of the backfilled wallet only fetches the pages of new transactions
+    histories
+        .lock()
+        .unwrap()
+        .insert(wallet.to_string(), newest_first(&signatures[..370]));
+    let inserted = indexer
+        .sync_wallet(&shyft_api, wallet, None, None)
+        .await
+        .expect("Failed to sync");
+    assert_eq!(inserted, 120);
+    assert_eq!(
+        history_requests(&server, 5).await,
+        vec![
+            (None, Some(signatures[249].to_string())),
+            (
+                Some(signatures[270].to_string()),
+                Some(signatures[249].to_string())
+            ),
+        ]
+    );
+    assert_eq!(
+        indexer
+            .checkpoint(&wallet)
+            .expect("Failed to read")
+            .and_then(|checkpoint| checkpoint.newest),
+        Some(signatures[369])
+    );
+
+    // Assert that a transaction shared by two wallets is stored once, for both
+    histories.lock().unwrap().insert(
+        other.to_string(),
+        newest_first(&[signatures[10], signatures[370]]),
+    );
+    let inserted = indexer
+        .sync_wallet(&shyft_api, other, None, None)
+        .await
+        .expect("Failed to sync");
+    assert_eq!(inserted, 1);
+    assert_eq!(stored(&indexer, other), 2);
+    assert_eq!(stored(&indexer, wallet), 370);
+    let total = indexer
+        .transactions(&IndexQuery::new())
+        .expect("Failed to query")
+        .len();
+    assert_eq!(total, 371);
+}
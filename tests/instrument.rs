@@ -0,0 +1,244 @@
+#![cfg(any(feature = "tracing", feature = "metrics"))]
+
+mod common;
+
+use shyft_rs_sdk::ShyftApi;
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+/// Starts a stub server whose parsed transaction endpoint fails once before succeeding.
+async fn stub_server() -> MockServer {
+    let signature = common::signatures(1)[0];
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/sol/v1/transaction/parsed"))
+        .respond_with(ResponseTemplate::new(500))
+        .up_to_n_times(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/sol/v1/transaction/parsed"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(common::response(common::transaction(&signature))),
+        )
+        .mount(&server)
+        .await;
+    server
+}
+
+/// Fetches the parsed transaction from the stub server, retrying once.
+async fn fetch_with_retry(server: &MockServer) {
+    let shyft_api = ShyftApi::builder("test_api_key")
+        .base_url(&common::base_url(server))
+        .min_retry_interval(1)
+        .max_retry_interval(1)
+        .build()
+        .expect("Failed to create ShyftApi");
+    shyft_api
+        .get_transaction_parsed(&common::signatures(1)[0])
+        .await
+        .expect("Failed to fetch parsed transaction");
+}
+
+#[cfg(feature = "tracing")]
+mod tracing_output {
+    use std::{
+        io,
+        sync::{Arc, Mutex},
+    };
+
+    use tracing_subscriber::fmt::format::FmtSpan;
+
+    /// Writer appending the formatted spans and events to a shared buffer.
+    #[derive(Clone, Default)]
+    pub struct Capture(Arc<Mutex<Vec<u8>>>);
+
+    impl Capture {
+        /// Installs a subscriber writing to the buffer on the current thread, printing the
+        /// fields of spans when they close.
+        pub fn set_default(&self) -> tracing::subscriber::DefaultGuard {
+            let capture = self.clone();
+            let subscriber = tracing_subscriber::fmt()
+                .with_writer(move || capture.clone())
+                .with_ansi(false)
+                .with_max_level(tracing::Level::DEBUG)
+                .with_span_events(FmtSpan::CLOSE)
+                .finish();
+            tracing::subscriber::set_default(subscriber)
+        }
+
+        /// Returns the lines written by the spans of the SDK.
+        pub fn lines(&self) -> Vec<String> {
+            String::from_utf8(self.0.lock().unwrap().clone())
+                .expect("Invalid UTF-8")
+                .lines()
+                .filter(|line| line.contains("shyft_request{"))
+                .map(str::to_owned)
+                .collect()
+        }
+    }
+
+    impl io::Write for Capture {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+}
+
+#[cfg(feature = "tracing")]
+#[tokio::test]
+async fn test_tracing_span_fields() {
+    let server = stub_server().await;
+    let capture = tracing_output::Capture::default();
+    let _guard = capture.set_default();
+
+    fetch_with_retry(&server).await;
+
+    // Assert that the call is logged once, in a span recording its request and outcome
+    let lines = capture.lines();
+    let completed: Vec<&String> = lines
+        .iter()
+        .filter(|line| line.contains("Shyft API call completed"))
+        .collect();
+    assert_eq!(completed.len(), 1, "{:#?}", lines);
+    let closed = lines
+        .iter()
+        .find(|line| line.contains(" close time.busy="))
+        .unwrap_or_else(|| panic!("Span not closed: {:#?}", lines));
+    let signature = common::signatures(1)[0];
+    for field in [
+        "endpoint=transaction/parsed".to_string(),
+        "method=GET".to_string(),
+        "network=mainnet-beta".to_string(),
+        "commitment=confirmed".to_string(),
+        format!("txn_signature={}", signature),
+        "status=\"200\"".to_string(),
+        "retries=1".to_string(),
+        "latency_ms=".to_string(),
+    ] {
+        assert!(closed.contains(&field), "{} missing from {}", field, closed);
+    }
+    assert!(!closed.contains("test_api_key"), "{}", closed);
+}
+
+#[cfg(feature = "tracing")]
+#[tokio::test]
+async fn test_tracing_redacts_api_key() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/rpc"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "result": [{ "slot": 1, "prioritizationFee": 1000 }]
+        })))
+        .mount(&server)
+        .await;
+
+    // Use an RPC URL carrying a key of its own, on top of the one added by the client
+    let shyft_api = ShyftApi::builder("test_api_key")
+        .rpc_url(&format!("{}/rpc?api_key=url_api_key", server.uri()))
+        .build()
+        .expect("Failed to create ShyftApi");
+    let capture = tracing_output::Capture::default();
+    let _guard = capture.set_default();
+    shyft_api
+        .estimate_priority_fee(&[], 50)
+        .await
+        .expect("Failed to estimate priority fee");
+
+    // Assert that the key reached the server
+    let requests = server.received_requests().await.unwrap();
+    let keys: Vec<String> = requests[0]
+        .url
+        .query_pairs()
+        .filter(|(key, _)| key == "api_key")
+        .map(|(_, value)| value.into_owned())
+        .collect();
+    assert_eq!(keys, vec!["url_api_key", "test_api_key"]);
+
+    // Assert that neither key is recorded, only the redacted parameter
+    let output = capture.lines().join("\n");
+    assert!(output.contains("params=api_key=[REDACTED]"), "{}", output);
+    assert!(!output.contains("url_api_key"), "{}", output);
+    assert!(!output.contains("test_api_key"), "{}", output);
+}
+
+#[cfg(feature = "metrics")]
+#[test]
+fn test_metrics_labels() {
+    use metrics_util::{
+        debugging::{DebugValue, DebuggingRecorder},
+        MetricKind,
+    };
+
+    let recorder = DebuggingRecorder::new();
+    let snapshotter = recorder.snapshotter();
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .unwrap();
+    metrics::with_local_recorder(&recorder, || {
+        runtime.block_on(async {
+            let server = stub_server().await;
+            fetch_with_retry(&server).await;
+        })
+    });
+
+    let mut metrics: Vec<(MetricKind, String, Vec<String>, DebugValue)> = snapshotter
+        .snapshot()
+        .into_vec()
+        .into_iter()
+        .map(|(key, _, _, value)| {
+            let (kind, key) = key.into_parts();
+            let labels = key
+                .labels()
+                .map(|label| format!("{}={}", label.key(), label.value()))
+                .collect();
+            (kind, key.name().to_owned(), labels, value)
+        })
+        .collect();
+    metrics.sort_by(|a, b| a.1.cmp(&b.1));
+
+    // Assert that the call is counted once, with its retry, and its latency recorded
+    let labels = |status: Option<&str>| {
+        let mut labels = vec![
+            "endpoint=transaction/parsed".to_string(),
+            "network=mainnet-beta".to_string(),
+            "commitment=confirmed".to_string(),
+        ];
+        labels.extend(status.map(|status| format!("status={}", status)));
+        labels
+    };
+    assert_eq!(metrics.len(), 3, "{:#?}", metrics);
+    assert_eq!(metrics[0].0, MetricKind::Histogram);
+    assert_eq!(metrics[0].1, "shyft_request_duration_seconds");
+    assert_eq!(metrics[0].2, labels(None));
+    assert!(matches!(&metrics[0].3, DebugValue::Histogram(values) if values.len() == 1));
+    assert_eq!(
+        metrics[1],
+        (
+            MetricKind::Counter,
+            "shyft_requests_total".to_string(),
+            labels(Some("200")),
+            DebugValue::Counter(1)
+        )
+    );
+    assert_eq!(
+        metrics[2],
+        (
+            MetricKind::Counter,
+            "shyft_retries_total".to_string(),
+            labels(None),
+            DebugValue::Counter(1)
+        )
+    );
+}
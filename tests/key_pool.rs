@@ -0,0 +1,257 @@
+mod common;
+
+use std::time::Duration;
+
+use shyft_rs_sdk::{ApiKeyPool, Error, KeyRotation, ShyftApi};
+use wiremock::{
+    matchers::{header, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+/// Path of the stubbed parsed transaction endpoint.
+const PARSED_PATH: &str = "/sol/v1/transaction/parsed";
+
+/// Starts a stub server answering the requests sent with each key with the given response,
+/// and the other requests with a parsed transaction.
+async fn stub_server(responses: Vec<(&str, ResponseTemplate)>) -> MockServer {
+    let server = MockServer::start().await;
+    for (key, response) in responses {
+        Mock::given(method("GET"))
+            .and(path(PARSED_PATH))
+            .and(header("x-api-key", key))
+            .respond_with(response)
+            .mount(&server)
+            .await;
+    }
+    Mock::given(method("GET"))
+        .and(path(PARSED_PATH))
+        .respond_with(ResponseTemplate::new(200).set_body_json(common::response(
+            common::transaction(&common::signatures(1)[0]),
+        )))
+        .mount(&server)
+        .await;
+    server
+}
+
+/// Creates a client of the stub server using a pool of keys.
+fn client(server: &MockServer, keys: ApiKeyPool, max_retries: u32) -> ShyftApi {
+    ShyftApi::builder_with_api_key_pool(keys)
+        .base_url(&common::base_url(server))
+        .min_retry_interval(1)
+        .max_retry_interval(1)
+        .max_retries(max_retries)
+        .build()
+        .expect("Failed to create ShyftApi")
+}
+
+/// Fetches the parsed transaction `count` times, returning the result of the last call.
+async fn fetch(
+    client: &ShyftApi,
+    count: usize,
+) -> Result<shyft_rs_sdk::models::parsed_transaction_details::ParsedTransactionDetails, Error> {
+    let mut result = Err(Error::NoApiKey);
+    for _ in 0..count {
+        result = client
+            .get_transaction_parsed(&common::signatures(1)[0])
+            .await;
+    }
+    result
+}
+
+/// Returns the key sent with each request received by the stub server, in order.
+async fn keys_used(server: &MockServer) -> Vec<String> {
+    server
+        .received_requests()
+        .await
+        .unwrap()
+        .iter()
+        .map(|request| {
+            request.headers["x-api-key"]
+                .to_str()
+                .expect("Invalid key")
+                .to_owned()
+        })
+        .collect()
+}
+
+#[test]
+fn test_api_key_pool() {
+    let keys = ApiKeyPool::new(["first_api_key", "second_api_key"]).expect("Invalid API keys");
+    let shared = keys.clone();
+    let client = ShyftApi::builder("first_api_key")
+        .api_key_pool(keys.clone())
+        .build()
+        .expect("Failed to create ShyftApi");
+
+    // Assert that keys are never printed
+    for debug in [format!("{:?}", keys), format!("{:?}", client)] {
+        assert!(!debug.contains("first_api_key"), "Key printed: {}", debug);
+        assert!(!debug.contains("second_api_key"), "Key printed: {}", debug);
+    }
+
+    // Assert that clones share the same keys
+    keys.add_key("third_api_key").expect("Invalid API key");
+    assert_eq!(shared.len(), 3);
+    assert!(shared.remove_key("first_api_key"));
+    assert!(!shared.remove_key("first_api_key"));
+    assert_eq!(keys.len(), 2);
+    assert_eq!(keys.available(), 2);
+
+    // Assert that invalid keys are rejected and leave the keys unchanged
+    assert!(matches!(
+        keys.set_keys(["valid_api_key", "invalid\napi_key"]),
+        Err(Error::InvalidApiKey)
+    ));
+    assert_eq!(keys.len(), 2);
+    keys.set_keys(["fourth_api_key"]).expect("Invalid API key");
+    assert_eq!(shared.len(), 1);
+
+    // Assert that an invalid key fails to build a client
+    assert!(matches!(
+        ShyftApi::builder("invalid\napi_key").build(),
+        Err(Error::InvalidApiKey)
+    ));
+}
+
+#[tokio::test]
+async fn test_api_key_pool_failover() {
+    let server = stub_server(vec![
+        ("revoked_api_key", ResponseTemplate::new(401)),
+        (
+            "limited_api_key",
+            ResponseTemplate::new(429).insert_header("retry-after", "1"),
+        ),
+    ])
+    .await;
+    let keys = ApiKeyPool::new(["revoked_api_key", "limited_api_key", "valid_api_key"])
+        .expect("Invalid API keys");
+    let client = client(&server, keys.clone(), 0);
+
+    // Assert that the request is sent again with the next keys, without retries
+    fetch(&client, 1).await.expect("Failed to fetch");
+    assert_eq!(
+        keys_used(&server).await,
+        vec!["revoked_api_key", "limited_api_key", "valid_api_key"]
+    );
+
+    // Assert that the rejected keys are skipped by the next request
+    assert_eq!(keys.available(), 1);
+    fetch(&client, 1).await.expect("Failed to fetch");
+    assert_eq!(keys_used(&server).await[3..], ["valid_api_key"]);
+
+    // Assert that the rate limited key is available again after its cooldown, unlike the
+    // revoked key
+    tokio::time::sleep(Duration::from_millis(1100)).await;
+    assert_eq!(keys.available(), 2);
+}
+
+#[tokio::test]
+async fn test_api_key_pool_retries() {
+    // Rate limit both keys for longer than the test
+    let limited = || ResponseTemplate::new(429).insert_header("retry-after", "60");
+    let server = stub_server(vec![
+        ("first_api_key", limited()),
+        ("second_api_key", limited()),
+    ])
+    .await;
+    let keys = ApiKeyPool::new(["first_api_key", "second_api_key"]).expect("Invalid API keys");
+    let client = client(&server, keys.clone(), 1);
+
+    // Assert that the retry uses the key whose cooldown ends first, and gives up once every
+    // key is rate limited
+    let result = fetch(&client, 1).await;
+    assert!(
+        matches!(result, Err(Error::StatusNot200(_))),
+        "{:?}",
+        result
+    );
+    assert_eq!(
+        keys_used(&server).await,
+        vec!["first_api_key", "second_api_key", "first_api_key"]
+    );
+    assert_eq!(keys.available(), 0);
+}
+
+#[tokio::test]
+async fn test_api_key_pool_retry_next_key() {
+    // Fail the first request, whatever its key
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(PARSED_PATH))
+        .respond_with(ResponseTemplate::new(500))
+        .up_to_n_times(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(PARSED_PATH))
+        .respond_with(ResponseTemplate::new(200).set_body_json(common::response(
+            common::transaction(&common::signatures(1)[0]),
+        )))
+        .mount(&server)
+        .await;
+    let keys = ApiKeyPool::new(["first_api_key", "second_api_key"]).expect("Invalid API keys");
+    let client = client(&server, keys.clone(), 1);
+
+    // Assert that the retry of a server error is sent with the next key
+    fetch(&client, 1).await.expect("Failed to fetch");
+    assert_eq!(
+        keys_used(&server).await,
+        vec!["first_api_key", "second_api_key"]
+    );
+    assert_eq!(keys.available(), 2);
+}
+
+#[tokio::test]
+async fn test_api_key_pool_rotation() {
+    let with_quota = |remaining: &str| {
+        ResponseTemplate::new(200)
+            .insert_header("x-ratelimit-remaining", remaining)
+            .set_body_json(common::response(common::transaction(
+                &common::signatures(1)[0],
+            )))
+    };
+    let responses = || {
+        vec![
+            ("first_api_key", with_quota("5")),
+            ("second_api_key", with_quota("50")),
+            ("third_api_key", with_quota("10")),
+        ]
+    };
+    let keys = ["first_api_key", "second_api_key", "third_api_key"];
+
+    // Assert that round-robin rotation uses the keys in turn
+    let server = stub_server(responses()).await;
+    let pool = ApiKeyPool::new(keys).expect("Invalid API keys");
+    fetch(&client(&server, pool, 0), 4)
+        .await
+        .expect("Failed to fetch");
+    assert_eq!(
+        keys_used(&server).await,
+        vec![
+            "first_api_key",
+            "second_api_key",
+            "third_api_key",
+            "first_api_key"
+        ]
+    );
+
+    // Assert that remaining quota rotation uses the keys of unknown quota first, then the key
+    // with the most requests remaining
+    let server = stub_server(responses()).await;
+    let pool = ApiKeyPool::new(keys)
+        .expect("Invalid API keys")
+        .with_rotation(KeyRotation::RemainingQuota);
+    fetch(&client(&server, pool, 0), 5)
+        .await
+        .expect("Failed to fetch");
+    assert_eq!(
+        keys_used(&server).await,
+        vec![
+            "first_api_key",
+            "second_api_key",
+            "third_api_key",
+            "second_api_key",
+            "second_api_key"
+        ]
+    );
+}
@@ -0,0 +1,198 @@
+use std::collections::HashMap;
+
+use shyft_rs_sdk::{
+    analytics::pnl::{CostBasisMethod, PnlTracker},
+    models::parsed_transaction_details::ParsedTransactionDetails,
+    Decimal, Pubkey,
+};
+
+const WALLET: &str = "8R5brRqNa1CDMtcQRaLPQfJeLBrtyqpjDPTSKbBvmsna";
+const TOKEN: &str = "EWFka4M5XZrKzAq54eTXxN5K1H3u2es1HVqvr6hsqdSk";
+const SOL: &str = "So11111111111111111111111111111111111111112";
+
+/// Builds a successful swap transaction of the sample wallet, paying a 0.01 SOL fee.
+fn swap(
+    token_in: &str,
+    amount_in: &str,
+    token_out: &str,
+    amount_out: &str,
+) -> ParsedTransactionDetails {
+    let token_info = |token_address: &str, amount: &str| {
+        serde_json::json!({
+            "token_address": token_address,
+            "name": "",
+            "symbol": "",
+            "image_uri": "",
+            "amount": amount.parse::<f64>().unwrap(),
+            "amount_raw": 0
+        })
+    };
+
+    serde_json::from_value(serde_json::json!({
+        "timestamp": "2024-08-26T10:38:46.000Z",
+        "fee": 0.01,
+        "fee_payer": WALLET,
+        "signers": [WALLET],
+        "signatures": [
+            "uZz2qDvLzndsTEY31YkxgRe1rYQ8MsCtS3DwhPYP1Md7u7dnUK4HW3vYzsxE6GSxFhSG5zpvqSQnSUn1sPmzTBu"
+        ],
+        "protocol": {
+            "address": "JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4",
+            "name": "JUPITER_V_6"
+        },
+        "type": "SWAP",
+        "status": "Success",
+        "actions": [
+            {
+                "info": {
+                    "swapper": WALLET,
+                    "tokens_swapped": {
+                        "in": token_info(token_in, amount_in),
+                        "out": token_info(token_out, amount_out)
+                    },
+                    "swaps": []
+                },
+                "source_protocol": {
+                    "address": "JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4",
+                    "name": "JUPITER_V_6"
+                },
+                "type": "SWAP"
+            }
+        ],
+        "raw": null,
+        "events": null
+    }))
+    .expect("Failed to parse transaction")
+}
+
+#[test]
+fn test_pnl_cost_basis_methods() {
+    let wallet: Pubkey = WALLET.parse().expect("Invalid address");
+    let token: Pubkey = TOKEN.parse().expect("Invalid address");
+
+    // Buy 100 tokens for 1 SOL, 100 tokens for 3 SOL, then sell 100 tokens for 4 SOL
+    let transactions = [
+        swap(SOL, "1", TOKEN, "100"),
+        swap(SOL, "3", TOKEN, "100"),
+        swap(TOKEN, "100", SOL, "4"),
+    ];
+    let prices = HashMap::from([(token, Decimal::new(5, 2))]);
+
+    let expected = [
+        // (method, realized PnL, unrealized PnL)
+        (CostBasisMethod::Fifo, "2.98", "1.99"),
+        (CostBasisMethod::Lifo, "0.98", "3.99"),
+        (CostBasisMethod::Average, "1.98", "2.99"),
+    ];
+
+    for (method, realized_pnl, unrealized_pnl) in expected {
+        let mut tracker = PnlTracker::new(wallet, method);
+        tracker
+            .process_all(&transactions)
+            .expect("Failed to process transactions");
+
+        let report = tracker.report(&prices);
+
+        // Assert that fees are allocated to the swaps and the PnL matches the method
+        assert_eq!(report.tokens.len(), 1, "{:?}", method);
+        assert_eq!(
+            report.tokens[0].quantity,
+            Decimal::from(100),
+            "{:?}",
+            method
+        );
+        assert_eq!(
+            report.realized_pnl,
+            realized_pnl.parse().unwrap(),
+            "{:?}",
+            method
+        );
+        assert_eq!(
+            report.unrealized_pnl,
+            unrealized_pnl.parse().unwrap(),
+            "{:?}",
+            method
+        );
+        assert_eq!(report.unallocated_fees, Decimal::ZERO, "{:?}", method);
+    }
+}
+
+#[test]
+fn test_pnl_skips_unpriced_swaps() {
+    let wallet: Pubkey = WALLET.parse().expect("Invalid address");
+
+    // Swap between two tokens other than SOL
+    let transaction = swap(
+        TOKEN,
+        "100",
+        "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v",
+        "10",
+    );
+
+    let mut tracker = PnlTracker::new(wallet, CostBasisMethod::Fifo);
+    tracker
+        .process(&transaction)
+        .expect("Failed to process transaction");
+
+    // Assert that the swap is skipped and its fee left unallocated
+    assert_eq!(tracker.skipped(), &transaction.signatures[..]);
+    let report = tracker.report(&HashMap::new());
+    assert!(report.tokens.is_empty());
+    assert_eq!(report.unallocated_fees, Decimal::new(1, 2));
+}
+
+#[test]
+fn test_pnl_ignores_malformed_other_actions() {
+    let wallet: Pubkey = WALLET.parse().expect("Invalid address");
+    let token: Pubkey = TOKEN.parse().expect("Invalid address");
+
+    // Add a token transfer missing most of its information to the first swap
+    let mut buy = serde_json::to_value(swap(SOL, "1", TOKEN, "100")).unwrap();
+    buy["actions"]
+        .as_array_mut()
+        .unwrap()
+        .push(serde_json::json!({
+            "info": { "amount": 100 },
+            "source_protocol": {
+                "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
+                "name": "Token Program"
+            },
+            "type": "TOKEN_TRANSFER"
+        }));
+    let buy: ParsedTransactionDetails =
+        serde_json::from_value(buy).expect("Failed to parse transaction");
+    let transactions = [buy, swap(TOKEN, "50", SOL, "1")];
+
+    let mut tracker = PnlTracker::new(wallet, CostBasisMethod::Fifo);
+    tracker
+        .process_all(&transactions)
+        .expect("Failed to process transactions");
+
+    // Assert that the swaps are still processed
+    let report = tracker.report(&HashMap::from([(token, Decimal::new(1, 2))]));
+    assert_eq!(report.tokens.len(), 1);
+    assert_eq!(report.tokens[0].quantity, Decimal::from(50));
+    assert_eq!(report.realized_pnl, "0.485".parse().unwrap());
+}
+
+#[test]
+fn test_pnl_skips_malformed_swaps() {
+    let wallet: Pubkey = WALLET.parse().expect("Invalid address");
+
+    // Remove the swapped tokens from the information of a swap
+    let mut malformed = serde_json::to_value(swap(SOL, "1", TOKEN, "100")).unwrap();
+    malformed["actions"][0]["info"] = serde_json::json!({ "swapper": WALLET });
+    let malformed: ParsedTransactionDetails =
+        serde_json::from_value(malformed).expect("Failed to parse transaction");
+
+    let mut tracker = PnlTracker::new(wallet, CostBasisMethod::Fifo);
+    tracker
+        .process(&malformed)
+        .expect("Failed to process transaction");
+
+    // Assert that the swap is skipped and its fee left unallocated
+    assert_eq!(tracker.skipped(), &malformed.signatures[..]);
+    let report = tracker.report(&HashMap::new());
+    assert!(report.tokens.is_empty());
+    assert_eq!(report.unallocated_fees, Decimal::new(1, 2));
+}
@@ -0,0 +1,224 @@
+#![cfg(feature = "pubsub")]
+
+use futures::{channel::mpsc, SinkExt, StreamExt};
+use serde_json::{json, Value};
+use shyft_rs_sdk::{
+    pubsub::{LogsFilter, PubSubClient},
+    Commitment, Error, Pubkey,
+};
+use tokio::net::{TcpListener, TcpStream};
+use tokio_tungstenite::{tungstenite::Message, WebSocketStream};
+
+const ACCOUNT: &str = "8R5brRqNa1CDMtcQRaLPQfJeLBrtyqpjDPTSKbBvmsna";
+const SIGNATURE: &str =
+    "uZz2qDvLzndsTEY31YkxgRe1rYQ8MsCtS3DwhPYP1Md7u7dnUK4HW3vYzsxE6GSxFhSG5zpvqSQnSUn1sPmzTBu";
+
+/// Returns the next JSON-RPC request sent by the client.
+async fn next_request(socket: &mut WebSocketStream<TcpStream>) -> Value {
+    loop {
+        match socket.next().await.unwrap().unwrap() {
+            Message::Text(text) => return serde_json::from_str(&text).unwrap(),
+            _ => continue,
+        }
+    }
+}
+
+/// Answers the subscription requests of a connection, with identifiers offset by `base_id`.
+async fn accept_subscriptions(
+    socket: &mut WebSocketStream<TcpStream>,
+    count: usize,
+    base_id: u64,
+    requests: &mpsc::UnboundedSender<Value>,
+) {
+    for _ in 0..count {
+        let request = next_request(socket).await;
+        let server_id = base_id
+            + match request["method"].as_str().unwrap() {
+                "slotSubscribe" => 0,
+                "logsSubscribe" => 1,
+                "signatureSubscribe" => 2,
+                "accountSubscribe" => 3,
+                method => panic!("Unexpected method {}", method),
+            };
+        let response = json!({ "jsonrpc": "2.0", "result": server_id, "id": request["id"] });
+        socket
+            .send(Message::Text(response.to_string()))
+            .await
+            .unwrap();
+        requests.unbounded_send(request).unwrap();
+    }
+}
+
+/// Sends a notification to a subscription.
+async fn notify(socket: &mut WebSocketStream<TcpStream>, method: &str, id: u64, result: Value) {
+    let notification = json!({
+        "jsonrpc": "2.0",
+        "method": method,
+        "params": { "result": result, "subscription": id },
+    });
+    socket
+        .send(Message::Text(notification.to_string()))
+        .await
+        .unwrap();
+}
+
+/// A stub PubSub server, which drops the first connection after a few notifications.
+async fn stub(listener: TcpListener, requests: mpsc::UnboundedSender<Value>) {
+    let context = json!({ "slot": 100 });
+
+    // First connection: notify every subscription, then drop the connection
+    let (stream, _) = listener.accept().await.unwrap();
+    let mut socket = tokio_tungstenite::accept_async(stream).await.unwrap();
+    accept_subscriptions(&mut socket, 4, 10, &requests).await;
+    notify(
+        &mut socket,
+        "slotNotification",
+        10,
+        json!({ "slot": 100, "parent": 99, "root": 68 }),
+    )
+    .await;
+    notify(
+        &mut socket,
+        "logsNotification",
+        11,
+        json!({
+            "context": context,
+            "value": { "signature": SIGNATURE, "err": null, "logs": ["Program log: hello"] },
+        }),
+    )
+    .await;
+    notify(
+        &mut socket,
+        "signatureNotification",
+        12,
+        json!({ "context": context, "value": { "err": null } }),
+    )
+    .await;
+    notify(
+        &mut socket,
+        "accountNotification",
+        13,
+        json!({
+            "context": context,
+            "value": {
+                "lamports": 1_000_000,
+                "owner": "11111111111111111111111111111111",
+                "data": ["AQID", "base64"],
+                "executable": false,
+                "rentEpoch": 18446744073709551615u64,
+                "space": 3,
+            },
+        }),
+    )
+    .await;
+    // Close gracefully, so the notifications are not lost to a connection reset
+    socket.close(None).await.unwrap();
+    while let Some(Ok(_)) = socket.next().await {}
+
+    // Second connection: the remaining subscriptions are sent again
+    let (stream, _) = listener.accept().await.unwrap();
+    let mut socket = tokio_tungstenite::accept_async(stream).await.unwrap();
+    accept_subscriptions(&mut socket, 3, 20, &requests).await;
+    notify(
+        &mut socket,
+        "slotNotification",
+        20,
+        json!({ "slot": 101, "parent": 100, "root": 69 }),
+    )
+    .await;
+    loop {
+        let request = next_request(&mut socket).await;
+        requests.unbounded_send(request).unwrap();
+    }
+}
+
+#[tokio::test]
+async fn test_pubsub_subscriptions() {
+    // Start the stub server on a random port
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+    let (requests, mut received) = mpsc::unbounded();
+    tokio::spawn(stub(listener, requests));
+
+    let client = PubSubClient::connect_url(&format!("ws://{}", address))
+        .await
+        .expect("Failed to connect");
+    let account: Pubkey = ACCOUNT.parse().unwrap();
+    let mut slots = client.slot_subscribe();
+    let mut logs =
+        client.logs_subscribe(LogsFilter::Mentions(account), Some(Commitment::Confirmed));
+    let mut signature = client.signature_subscribe(SIGNATURE.parse().unwrap(), None);
+    let mut accounts = client.account_subscribe(account, None);
+
+    // Assert that the subscriptions were sent
+    let mut methods = Vec::new();
+    for _ in 0..4 {
+        let request = received.next().await.unwrap();
+        if request["method"] == "logsSubscribe" {
+            assert_eq!(
+                request["params"],
+                json!([{ "mentions": [ACCOUNT] }, { "commitment": "confirmed" }])
+            );
+        }
+        methods.push(request["method"].as_str().unwrap().to_string());
+    }
+    assert_eq!(
+        methods,
+        [
+            "slotSubscribe",
+            "logsSubscribe",
+            "signatureSubscribe",
+            "accountSubscribe"
+        ]
+    );
+
+    // Assert that the notifications are typed
+    let slot = slots.next().await.unwrap().expect("Failed to receive slot");
+    assert_eq!((slot.slot, slot.parent, slot.root), (100, 99, 68));
+    let log = logs.next().await.unwrap().expect("Failed to receive logs");
+    assert_eq!(log.context.slot, 100);
+    assert_eq!(log.value.signature.to_string(), SIGNATURE);
+    assert_eq!(log.value.logs, vec!["Program log: hello"]);
+    let status = signature
+        .next()
+        .await
+        .unwrap()
+        .expect("Failed to receive status");
+    assert_eq!(status.value.err, None);
+    let info = accounts
+        .next()
+        .await
+        .unwrap()
+        .expect("Failed to receive account");
+    assert_eq!(info.value.data, vec![1, 2, 3]);
+    assert_eq!(info.value.rent_epoch, u64::MAX);
+
+    // Assert that a signature subscription ends after its notification
+    assert!(signature.next().await.is_none());
+
+    // Assert that the lost connection is reported, then the subscriptions resumed
+    let error = slots.next().await.unwrap().unwrap_err();
+    assert!(matches!(error, Error::WebSocket(_)), "{:?}", error);
+    let slot = slots.next().await.unwrap().expect("Failed to receive slot");
+    assert_eq!(slot.slot, 101);
+    let mut methods = Vec::new();
+    for _ in 0..3 {
+        methods.push(received.next().await.unwrap()["method"].clone());
+    }
+    methods.sort_by_key(|method| method.to_string());
+    assert_eq!(
+        methods,
+        vec![
+            json!("accountSubscribe"),
+            json!("logsSubscribe"),
+            json!("slotSubscribe")
+        ]
+    );
+
+    // Assert that dropping a subscription unsubscribes
+    assert!(logs.next().await.unwrap().is_err());
+    drop(logs);
+    let request = received.next().await.unwrap();
+    assert_eq!(request["method"], "logsUnsubscribe");
+    assert_eq!(request["params"], json!([21]));
+}
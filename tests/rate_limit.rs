@@ -0,0 +1,46 @@
+use std::time::{Duration, Instant};
+
+use shyft_rs_sdk::RateLimiter;
+
+#[tokio::test]
+async fn test_rate_limiter() {
+    // Allow 2 requests per second, with a burst of 2
+    let rate_limiter = RateLimiter::new(2);
+    let shared = rate_limiter.clone();
+
+    let start = Instant::now();
+    rate_limiter.acquire(1).await;
+    shared.acquire(1).await;
+
+    // Assert that the burst is not throttled
+    assert!(start.elapsed() < Duration::from_millis(100));
+
+    // Assert that clones share the same bucket, so the third request waits for a refill
+    rate_limiter.acquire(1).await;
+    let elapsed = start.elapsed();
+    assert!(
+        elapsed >= Duration::from_millis(400),
+        "Request was not throttled: {:?}",
+        elapsed
+    );
+}
+
+#[test]
+fn test_rate_limiter_weights() {
+    let rate_limiter = RateLimiter::new(10)
+        .with_weight("parse_selected", 2)
+        .with_weight("transaction/parse_selected", 5)
+        .with_weight("/history/", 3);
+
+    // Assert that the longest matching endpoint wins, whatever the order of the weights
+    assert_eq!(rate_limiter.weight("/sol/v1/transaction/parse_selected"), 5);
+    assert_eq!(rate_limiter.weight("/sol/v1/callback/parse_selected/"), 2);
+    assert_eq!(rate_limiter.weight("/sol/v1/transaction/history"), 3);
+
+    // Assert that endpoints only match whole path segments
+    assert_eq!(
+        rate_limiter.weight("/sol/v1/transaction/bulk_parse_selected"),
+        1
+    );
+    assert_eq!(rate_limiter.weight("/sol/v1/wallet/balance"), 1);
+}
@@ -0,0 +1,48 @@
+use shyft_rs_sdk::{
+    requests::{ParseSelectedRequest, ShyftRequest, TransactionHistoryRequest},
+    Error, Pubkey, Signature,
+};
+
+#[test]
+fn test_transaction_history_request() {
+    let account: Pubkey = "8R5brRqNa1CDMtcQRaLPQfJeLBrtyqpjDPTSKbBvmsna"
+        .parse()
+        .expect("Invalid account");
+
+    // Assert that tx_num is validated
+    let request = TransactionHistoryRequest::new(account).tx_num(10);
+    assert!(request.validate().is_ok());
+    for tx_num in [0, 101] {
+        let request = TransactionHistoryRequest::new(account).tx_num(tx_num);
+        assert!(matches!(request.validate(), Err(Error::InvalidRequest(_))));
+    }
+
+    // Assert that requests can be persisted
+    let request = request.enable_raw(true);
+    let json = serde_json::to_string(&request).expect("Failed to serialize request");
+    assert_eq!(
+        json,
+        r#"{"account":"8R5brRqNa1CDMtcQRaLPQfJeLBrtyqpjDPTSKbBvmsna","tx_num":10,"enable_raw":true}"#
+    );
+    let deserialized: TransactionHistoryRequest =
+        serde_json::from_str(&json).expect("Failed to deserialize request");
+    assert_eq!(deserialized, request);
+}
+
+#[test]
+fn test_parse_selected_request() {
+    let signature: Signature =
+        "uZz2qDvLzndsTEY31YkxgRe1rYQ8MsCtS3DwhPYP1Md7u7dnUK4HW3vYzsxE6GSxFhSG5zpvqSQnSUn1sPmzTBu"
+            .parse()
+            .expect("Invalid transaction signature");
+
+    // Assert that the number of signatures is validated
+    assert!(ParseSelectedRequest::new([signature]).validate().is_ok());
+    assert!(ParseSelectedRequest::new(vec![signature; 100])
+        .validate()
+        .is_ok());
+    for signatures in [vec![], vec![signature; 101]] {
+        let request = ParseSelectedRequest::new(signatures);
+        assert!(matches!(request.validate(), Err(Error::InvalidRequest(_))));
+    }
+}
@@ -0,0 +1,30 @@
+mod common;
+
+use shyft_rs_sdk::{Pubkey, ShyftApi};
+
+#[tokio::test]
+async fn test_resolve_address() {
+    common::setup();
+
+    // Retrieve the API key from environment variables
+    let api_key = std::env::var("SHYFT_API_KEY").expect("SHYFT_API_KEY must be set");
+
+    // Sample address
+    let address: Pubkey = "8R5brRqNa1CDMtcQRaLPQfJeLBrtyqpjDPTSKbBvmsna"
+        .parse()
+        .expect("Invalid address");
+
+    // Create ShyftApi instance
+    let shyft_api =
+        ShyftApi::new(&api_key, None, None, None, None, None).expect("Failed to create ShyftApi");
+
+    // Resolve the address to its primary domain
+    let domain = shyft_api.resolve_address(&address).await;
+
+    // Assert that the address is resolved successfully
+    assert!(
+        domain.is_ok(),
+        "Failed to resolve address: {:?}",
+        domain.err()
+    );
+}
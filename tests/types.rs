@@ -0,0 +1,100 @@
+use shyft_rs_sdk::{
+    models::parsed_transaction_details::ParsedTransactionDetails, Error, Pubkey, Signature,
+};
+
+const ACCOUNT: &str = "8R5brRqNa1CDMtcQRaLPQfJeLBrtyqpjDPTSKbBvmsna";
+const SIGNATURE: &str =
+    "uZz2qDvLzndsTEY31YkxgRe1rYQ8MsCtS3DwhPYP1Md7u7dnUK4HW3vYzsxE6GSxFhSG5zpvqSQnSUn1sPmzTBu";
+
+#[test]
+fn test_parse_wrong_length() {
+    // Assert that valid base58 of the wrong number of bytes is rejected
+    let short = bs58::encode([1u8; 31]).into_string();
+    let long = bs58::encode([1u8; 33]).into_string();
+    assert!(matches!(short.parse::<Pubkey>(), Err(Error::InvalidPubkey(s)) if s == short));
+    assert!(matches!(
+        long.parse::<Pubkey>(),
+        Err(Error::InvalidPubkey(_))
+    ));
+    assert!("".parse::<Pubkey>().is_err());
+
+    // Assert that a public key is not a valid signature, and the other way around
+    assert!(matches!(
+        ACCOUNT.parse::<Signature>(),
+        Err(Error::InvalidSignature(_))
+    ));
+    assert!(matches!(
+        SIGNATURE.parse::<Pubkey>(),
+        Err(Error::InvalidPubkey(_))
+    ));
+
+    // Assert that overlong strings are rejected before decoding
+    let overlong = "1".repeat(100);
+    assert!(overlong.parse::<Pubkey>().is_err());
+    assert!(overlong.parse::<Signature>().is_err());
+}
+
+#[test]
+fn test_parse_non_base58() {
+    // Assert that the characters left out of the base58 alphabet are rejected
+    for invalid in ['0', 'O', 'I', 'l', '-', ' '] {
+        let account = format!("{}{}", &ACCOUNT[..43], invalid);
+        assert!(
+            matches!(account.parse::<Pubkey>(), Err(Error::InvalidPubkey(_))),
+            "{:?} was accepted",
+            account
+        );
+    }
+}
+
+#[test]
+fn test_serde_round_trip() {
+    let account: Pubkey = ACCOUNT.parse().expect("Invalid address");
+    let signature: Signature = SIGNATURE.parse().expect("Invalid signature");
+
+    // Assert that both types serialize as base58 strings
+    let json = serde_json::to_string(&(account, signature)).unwrap();
+    assert_eq!(json, format!("[\"{}\",\"{}\"]", ACCOUNT, SIGNATURE));
+
+    // Assert that they deserialize back to the same values
+    let (decoded_account, decoded_signature): (Pubkey, Signature) =
+        serde_json::from_str(&json).unwrap();
+    assert_eq!(decoded_account, account);
+    assert_eq!(decoded_signature, signature);
+    assert_eq!(Pubkey::new(account.to_bytes()), account);
+}
+
+#[test]
+fn test_models_reject_invalid_strings() {
+    let transaction = |fee_payer: &str, signature: &str| {
+        serde_json::from_value::<ParsedTransactionDetails>(serde_json::json!({
+            "timestamp": "2024-08-26T10:38:46.000Z",
+            "fee": 0.000005,
+            "fee_payer": fee_payer,
+            "signers": [ACCOUNT],
+            "signatures": [signature],
+            "protocol": {
+                "address": "11111111111111111111111111111111",
+                "name": "SYSTEM_PROGRAM"
+            },
+            "type": "SOL_TRANSFER",
+            "status": "Success",
+            "actions": [],
+            "raw": null,
+            "events": null
+        }))
+    };
+
+    // Assert that a valid transaction deserializes
+    assert!(transaction(ACCOUNT, SIGNATURE).is_ok());
+
+    // Assert that invalid addresses and signatures fail the whole model
+    let error = transaction("not-an-address", SIGNATURE).unwrap_err();
+    assert!(error.to_string().contains("Invalid address"), "{}", error);
+    let error = transaction(ACCOUNT, ACCOUNT).unwrap_err();
+    assert!(
+        error.to_string().contains("Invalid transaction signature"),
+        "{}",
+        error
+    );
+}
@@ -0,0 +1,212 @@
+mod common;
+
+use std::{collections::HashSet, sync::Mutex, time::Duration};
+
+use futures::{Stream, StreamExt};
+use shyft_rs_sdk::{
+    models::parsed_transaction_details::ParsedTransactionDetails, Error, Pubkey, RequestOptions,
+    ShyftApi, Signature,
+};
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, Request, Respond, ResponseTemplate,
+};
+
+const ACCOUNT: &str = "8R5brRqNa1CDMtcQRaLPQfJeLBrtyqpjDPTSKbBvmsna";
+
+/// Stub of the transaction history endpoint, serving a history that changes once the watch has
+/// started.
+struct HistoryStub {
+    /// Signatures of the history, newest first, and of the history after the first request.
+    histories: Mutex<(Vec<Signature>, Option<Vec<Signature>>)>,
+}
+
+impl HistoryStub {
+    /// Creates a stub serving `before` to the first request, and `after` to the next ones.
+    fn new(before: Vec<Signature>, after: Vec<Signature>) -> Self {
+        Self {
+            histories: Mutex::new((before, Some(after))),
+        }
+    }
+}
+
+impl Respond for HistoryStub {
+    fn respond(&self, request: &Request) -> ResponseTemplate {
+        let param = |name: &str| {
+            request
+                .url
+                .query_pairs()
+                .find(|(key, _)| key == name)
+                .map(|(_, value)| value.into_owned())
+        };
+        let signature = |name: &str| param(name).map(|value| value.parse::<Signature>().unwrap());
+        let tx_num: usize = param("tx_num").map_or(10, |value| value.parse().unwrap());
+        let before = signature("before_tx_signature");
+        let until = signature("until_tx_signature");
+
+        let mut histories = self.histories.lock().unwrap();
+        let history = histories.0.clone();
+        if let Some(after) = histories.1.take() {
+            histories.0 = after;
+        }
+
+        // Like Shyft, serve the transactions strictly between `before` and `until`
+        let start = before.map_or(0, |before| {
+            history
+                .iter()
+                .position(|signature| *signature == before)
+                .map_or(history.len(), |i| i + 1)
+        });
+        let transactions: Vec<serde_json::Value> = history[start..]
+            .iter()
+            .take_while(|signature| Some(**signature) != until)
+            .take(tx_num)
+            .map(common::transaction)
+            .collect();
+        ResponseTemplate::new(200).set_body_json(common::response(transactions.into()))
+    }
+}
+
+/// Starts a stub server serving the history of the account.
+async fn stub_server(stub: HistoryStub) -> MockServer {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/sol/v1/transaction/history"))
+        .respond_with(stub)
+        .mount(&server)
+        .await;
+    server
+}
+
+/// Collects the next `count` transactions of a watcher, then asserts that it has no other.
+async fn next_transactions(
+    transactions: &mut (impl Stream<Item = Result<ParsedTransactionDetails, Error>> + Unpin),
+    count: usize,
+) -> Vec<Signature> {
+    let mut signatures = Vec::new();
+    for _ in 0..count {
+        let transaction = tokio::time::timeout(Duration::from_secs(5), transactions.next())
+            .await
+            .expect("No new transaction")
+            .expect("Stream ended")
+            .expect("Failed to watch");
+        signatures.push(transaction.signatures[0]);
+    }
+    let next = tokio::time::timeout(Duration::from_millis(100), transactions.next()).await;
+    assert!(next.is_err(), "Unexpected transaction: {:?}", next);
+    signatures
+}
+
+#[tokio::test]
+async fn test_watch_account() {
+    common::setup();
+
+    // Retrieve the API key from environment variables
+    let api_key = std::env::var("SHYFT_API_KEY").expect("SHYFT_API_KEY must be set");
+
+    // A busy account, the Jupiter v6 program
+    let account: Pubkey = "JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4"
+        .parse()
+        .expect("Invalid account");
+
+    // Create ShyftApi instance
+    let shyft_api =
+        ShyftApi::new(&api_key, None, None, None, None, None).expect("Failed to create ShyftApi");
+
+    // Watch the account for new transactions
+    let mut transactions =
+        Box::pin(shyft_api.watch_account(account, Duration::from_secs(2), None, None));
+    let mut previous: Option<String> = None;
+    for _ in 0..3 {
+        let transaction = tokio::time::timeout(Duration::from_secs(60), transactions.next())
+            .await
+            .expect("No new transaction")
+            .expect("Stream ended");
+
+        // Assert that new transactions are fetched successfully, oldest first
+        assert!(
+            transaction.is_ok(),
+            "Failed to fetch new transaction: {:?}",
+            transaction.err()
+        );
+        let timestamp = transaction.unwrap().timestamp;
+        if let Some(previous) = previous {
+            assert!(previous <= timestamp, "{} after {}", previous, timestamp);
+        }
+        previous = Some(timestamp);
+    }
+}
+
+#[tokio::test]
+async fn test_watch_account_catch_up() {
+    // 150 transactions are made after the watch starts, more than fit in a page
+    let signatures = common::signatures(151);
+    let newest_first = |signatures: &[Signature]| signatures.iter().rev().copied().collect();
+    let server = stub_server(HistoryStub::new(
+        newest_first(&signatures[..1]),
+        newest_first(&signatures),
+    ))
+    .await;
+    let shyft_api = ShyftApi::builder("test_api_key")
+        .base_url(&common::base_url(&server))
+        .build()
+        .expect("Failed to create ShyftApi");
+
+    let account: Pubkey = ACCOUNT.parse().unwrap();
+    let mut transactions =
+        Box::pin(shyft_api.watch_account(account, Duration::from_millis(10), None, None));
+
+    // Assert that every new transaction is emitted once, oldest first
+    let emitted = next_transactions(&mut transactions, 150).await;
+    assert_eq!(emitted, signatures[1..]);
+    assert_eq!(emitted.iter().collect::<HashSet<_>>().len(), emitted.len());
+}
+
+#[tokio::test]
+async fn test_watch_account_max_pages() {
+    // The last transaction seen is rolled back, and 250 other transactions are made
+    let signatures = common::signatures(251);
+    let newest_first = |signatures: &[Signature]| signatures.iter().rev().copied().collect();
+    let server = stub_server(HistoryStub::new(
+        newest_first(&signatures[..1]),
+        newest_first(&signatures[1..]),
+    ))
+    .await;
+    let shyft_api = ShyftApi::builder("test_api_key")
+        .base_url(&common::base_url(&server))
+        .build()
+        .expect("Failed to create ShyftApi")
+        .with_options(RequestOptions::new().max_watch_pages(2));
+
+    let account: Pubkey = ACCOUNT.parse().unwrap();
+    let mut transactions =
+        Box::pin(shyft_api.watch_account(account, Duration::from_millis(10), None, None));
+
+    // Assert that the poll stops after 2 pages, emitting the newest transactions oldest first
+    let emitted = next_transactions(&mut transactions, 200).await;
+    assert_eq!(emitted, signatures[51..]);
+
+    // Assert that the next polls resume from the newest transaction
+    let requests = server.received_requests().await.unwrap();
+    let until: Vec<Option<String>> = requests
+        .iter()
+        .map(|request| {
+            request
+                .url
+                .query_pairs()
+                .find(|(key, _)| key == "until_tx_signature")
+                .map(|(_, value)| value.into_owned())
+        })
+        .collect();
+    assert_eq!(
+        until[..3],
+        [
+            None,
+            Some(signatures[0].to_string()),
+            Some(signatures[0].to_string())
+        ]
+    );
+    assert!(until[3..]
+        .iter()
+        .all(|until| *until == Some(signatures[250].to_string())));
+}
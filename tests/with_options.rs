@@ -0,0 +1,48 @@
+mod common;
+
+use std::time::Duration;
+
+use shyft_rs_sdk::{Commitment, Network, RequestOptions, ShyftApi, Signature};
+
+#[tokio::test]
+async fn test_with_options() {
+    common::setup();
+
+    // Retrieve the API key from environment variables
+    let api_key = std::env::var("SHYFT_API_KEY").expect("SHYFT_API_KEY must be set");
+
+    // Sample transaction signature
+    let tx_signature: Signature =
+        "uZz2qDvLzndsTEY31YkxgRe1rYQ8MsCtS3DwhPYP1Md7u7dnUK4HW3vYzsxE6GSxFhSG5zpvqSQnSUn1sPmzTBu"
+            .parse()
+            .expect("Invalid transaction signature");
+
+    // Create a devnet ShyftApi instance
+    let shyft_api = ShyftApi::builder(&api_key)
+        .network(Network::Devnet)
+        .build()
+        .expect("Failed to create ShyftApi");
+
+    // Fetch the mainnet transaction with overridden options
+    let parsed_transaction_details = shyft_api
+        .with_options(
+            RequestOptions::new()
+                .network(Network::MainnetBeta)
+                .commitment(Commitment::Finalized)
+                .timeout(Duration::from_secs(30))
+                .max_retries(1),
+        )
+        .get_transaction_parsed(&tx_signature)
+        .await;
+
+    // Assert that the parsed transaction details are fetched successfully
+    assert!(
+        parsed_transaction_details.is_ok(),
+        "Failed to fetch parsed transaction details: {:?}",
+        parsed_transaction_details.err()
+    );
+    assert_eq!(
+        parsed_transaction_details.unwrap().signatures,
+        vec![tx_signature]
+    );
+}